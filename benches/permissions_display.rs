@@ -0,0 +1,111 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! Compares `Permissions` formatting through `write_to` against the
+//! previous `Vec` + `join` implementation.
+//!
+//! Run with `cargo bench --bench permissions_display`. Allocation counts
+//! per call are printed before the timings.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Write,
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering}
+};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use revelation_user::Permissions;
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Formatting as it was done before `Permissions::write_to`.
+fn legacy(perms: Permissions, out: &mut String) {
+    if perms.is_empty() {
+        out.push_str("none");
+        return;
+    }
+
+    let parts: Vec<&str> = Permissions::all_flags()
+        .into_iter()
+        .zip([
+            "read",
+            "write",
+            "delete",
+            "admin",
+            "manage_users",
+            "manage_roles",
+            "billing",
+            "audit",
+            "export",
+            "import",
+            "api_access",
+            "premium"
+        ])
+        .filter(|(flag, _)| perms.contains(*flag))
+        .map(|(_, name)| name)
+        .collect();
+
+    out.push_str(&parts.join(", "));
+}
+
+fn allocations(mut f: impl FnMut()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn permissions_display(c: &mut Criterion) {
+    let perms = Permissions::all();
+    let mut buf = String::with_capacity(128);
+
+    let legacy_allocs = allocations(|| {
+        buf.clear();
+        legacy(black_box(perms), &mut buf);
+    });
+    let write_to_allocs = allocations(|| {
+        buf.clear();
+        black_box(perms).write_to(&mut buf).unwrap();
+    });
+    println!("allocations per call: legacy = {legacy_allocs}, write_to = {write_to_allocs}");
+
+    let mut group = c.benchmark_group("permissions_display");
+    group.bench_function("legacy_vec_join", |b| {
+        b.iter(|| {
+            buf.clear();
+            legacy(black_box(perms), &mut buf);
+        });
+    });
+    group.bench_function("write_to", |b| {
+        b.iter(|| {
+            buf.clear();
+            black_box(perms).write_to(&mut buf).unwrap();
+        });
+    });
+    group.bench_function("display", |b| {
+        b.iter(|| {
+            buf.clear();
+            write!(buf, "{}", black_box(perms)).unwrap();
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, permissions_display);
+criterion_main!(benches);
@@ -0,0 +1,176 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! Confession (denomination) identifier.
+//!
+//! This module provides [`ConfessionId`], a typed wrapper around the
+//! UUID of a confession record so it cannot be confused with user IDs
+//! or other UUIDs at call sites, and [`ConfessionCount`] for per-confession
+//! user statistics.
+//!
+//! # Serialization
+//!
+//! [`ConfessionId`] is transparent: it serializes exactly like the
+//! inner [`Uuid`], so existing JSON and database columns are unchanged.
+//!
+//! ```rust
+//! use revelation_user::ConfessionId;
+//! use uuid::Uuid;
+//!
+//! let id = ConfessionId(Uuid::nil());
+//! let json = serde_json::to_string(&id).unwrap();
+//! assert_eq!(json, "\"00000000-0000-0000-0000-000000000000\"");
+//! ```
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Identifier of a confession/denomination.
+///
+/// # Conversions
+///
+/// - `From<Uuid>` - wrap a raw UUID
+/// - `From<ConfessionId> for Uuid` - unwrap back to a raw UUID
+///
+/// # Database
+///
+/// With `db` feature, maps transparently to a PostgreSQL `uuid` column.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::ConfessionId;
+/// use uuid::Uuid;
+///
+/// let raw = Uuid::now_v7();
+/// let id = ConfessionId::from(raw);
+///
+/// assert_eq!(Uuid::from(id), raw);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(transparent))]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ConfessionId(pub Uuid);
+
+impl ConfessionId {
+    /// Returns the inner UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::ConfessionId;
+    /// use uuid::Uuid;
+    ///
+    /// assert!(ConfessionId(Uuid::nil()).as_uuid().is_nil());
+    /// ```
+    #[must_use]
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl From<Uuid> for ConfessionId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl From<ConfessionId> for Uuid {
+    fn from(id: ConfessionId) -> Self {
+        id.0
+    }
+}
+
+impl core::fmt::Display for ConfessionId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Number of users belonging to a confession.
+///
+/// Response DTO for reporting endpoints fed by
+/// [`ConfessionStatsRepository`](crate::ports::ConfessionStatsRepository).
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{ConfessionCount, ConfessionId};
+/// use uuid::Uuid;
+///
+/// let count = ConfessionCount::from((ConfessionId(Uuid::nil()), 42));
+/// let json = serde_json::to_value(&count).unwrap();
+///
+/// assert_eq!(json["count"], 42);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
+pub struct ConfessionCount {
+    /// Confession the users belong to.
+    pub confession_id: ConfessionId,
+
+    /// Number of users with this confession.
+    pub count: u64
+}
+
+impl From<(ConfessionId, u64)> for ConfessionCount {
+    fn from((confession_id, count): (ConfessionId, u64)) -> Self {
+        Self {
+            confession_id,
+            count
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_as_bare_uuid() {
+        let id = ConfessionId(Uuid::nil());
+        let json = serde_json::to_string(&id).unwrap();
+
+        assert_eq!(json, "\"00000000-0000-0000-0000-000000000000\"");
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let id = ConfessionId(Uuid::now_v7());
+        let json = serde_json::to_string(&id).unwrap();
+        let parsed: ConfessionId = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, id);
+    }
+
+    #[test]
+    fn uuid_conversions() {
+        let raw = Uuid::now_v7();
+        let id: ConfessionId = raw.into();
+        let back: Uuid = id.into();
+
+        assert_eq!(back, raw);
+        assert_eq!(id.as_uuid(), &raw);
+    }
+
+    #[test]
+    fn display_matches_uuid() {
+        let raw = Uuid::now_v7();
+
+        assert_eq!(ConfessionId(raw).to_string(), raw.to_string());
+    }
+
+    #[test]
+    fn count_from_tuple() {
+        let id = ConfessionId(Uuid::now_v7());
+        let count = ConfessionCount::from((id, 7));
+
+        assert_eq!(count.confession_id, id);
+        assert_eq!(count.count, 7);
+    }
+}
@@ -11,8 +11,9 @@
 //! | DTO | Purpose | Validation |
 //! |-----|---------|------------|
 //! | [`BindTelegram`] | Bind Telegram account | ID ≥ 1 |
-//! | [`BindEmail`] | Bind email address | Valid email format |
+//! | [`BindEmail`] | Bind email address | Valid email, ≤ 254 chars |
 //! | [`BindPhone`] | Bind phone number | E.164 format regex |
+//! | [`BindRequest`] | Any of the above, tagged by `method` | Inner DTO |
 //!
 //! # Examples
 //!
@@ -40,7 +41,7 @@
 //! ```
 
 use serde::{Deserialize, Serialize};
-use validator::Validate;
+use validator::{Validate, ValidationError, ValidationErrors};
 
 /// Request to bind Telegram account to user.
 ///
@@ -69,14 +70,58 @@ use validator::Validate;
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct BindTelegram {
     /// Telegram user ID from bot callback.
     ///
-    /// Must be a positive integer (Telegram IDs start from 1).
+    /// Must be a positive integer (Telegram IDs start from 1). Numeric
+    /// strings such as `"123"` are accepted too.
     #[validate(range(min = 1))]
+    #[serde(with = "crate::telegram_id")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub telegram_id: i64
 }
 
+impl BindTelegram {
+    /// Check the request against the user's current Telegram ID.
+    ///
+    /// Binding is allowed when nothing is bound yet or the same ID is
+    /// re-bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - Telegram ID already bound to the user, if any
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` with code `already_bound` if `current`
+    /// is set to a different ID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::BindTelegram;
+    ///
+    /// let bind = BindTelegram {
+    ///     telegram_id: 123
+    /// };
+    ///
+    /// assert!(bind.check_against(None).is_ok());
+    /// assert!(bind.check_against(Some(123)).is_ok());
+    /// assert!(bind.check_against(Some(456)).is_err());
+    /// ```
+    pub fn check_against(&self, current: Option<i64>) -> Result<(), ValidationError> {
+        match current {
+            Some(current) if current != self.telegram_id => Err(already_bound(
+                "a different Telegram account is already bound"
+            )),
+            _ => Ok(())
+        }
+    }
+}
+
 /// Request to bind email address to user.
 ///
 /// Used when a user wants to add or change their email address.
@@ -84,7 +129,7 @@ pub struct BindTelegram {
 ///
 /// # Validation
 ///
-/// - `email`: Must be valid email format
+/// - `email`: Must be valid email format, at most 254 characters
 ///
 /// # Examples
 ///
@@ -104,14 +149,60 @@ pub struct BindTelegram {
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct BindEmail {
     /// Email address to bind.
     ///
-    /// Must be a valid email format (validated by `validator` crate).
-    #[validate(email)]
+    /// Must be a valid email format (validated by `validator` crate),
+    /// at most 254 characters (RFC 5321).
+    #[validate(email, length(max = 254))]
     pub email: String
 }
 
+impl BindEmail {
+    /// Check the request against the user's current email.
+    ///
+    /// Binding is allowed when nothing is bound yet or the same address
+    /// is re-bound. Addresses are compared ignoring surrounding
+    /// whitespace and ASCII case, so `User@Example.com` matches
+    /// `user@example.com`: the domain is case-insensitive, and mail
+    /// providers treat the local part the same way.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - Email already bound to the user, if any
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` with code `already_bound` if `current`
+    /// is set to a different address.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::BindEmail;
+    ///
+    /// let bind = BindEmail {
+    ///     email: "user@example.com".into()
+    /// };
+    ///
+    /// assert!(bind.check_against(None).is_ok());
+    /// assert!(bind.check_against(Some("user@example.com")).is_ok());
+    /// assert!(bind.check_against(Some("User@Example.COM")).is_ok());
+    /// assert!(bind.check_against(Some("other@example.com")).is_err());
+    /// ```
+    pub fn check_against(&self, current: Option<&str>) -> Result<(), ValidationError> {
+        match current {
+            Some(current) if !current.trim().eq_ignore_ascii_case(self.email.trim()) => {
+                Err(already_bound("a different email is already bound"))
+            }
+            _ => Ok(())
+        }
+    }
+}
+
 /// Request to bind phone number to user.
 ///
 /// Used when a user wants to add or change their phone number.
@@ -151,6 +242,9 @@ pub struct BindEmail {
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct BindPhone {
     /// Phone number in E.164 format.
     ///
@@ -160,13 +254,104 @@ pub struct BindPhone {
     /// - First digit after `+` must be 1-9
     ///
     /// Examples: `+14155551234`, `+442071234567`, `+79991234567`
-    #[validate(regex(path = *crate::PHONE_REGEX))]
+    #[validate(custom(function = "crate::phone_field"))]
     pub phone: String
 }
 
+impl BindPhone {
+    /// Check the request against the user's current phone number.
+    ///
+    /// Binding is allowed when nothing is bound yet or the same number
+    /// is re-bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - Phone number already bound to the user, if any
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ValidationError` with code `already_bound` if `current`
+    /// is set to a different number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::BindPhone;
+    ///
+    /// let bind = BindPhone {
+    ///     phone: "+14155551234".into()
+    /// };
+    ///
+    /// assert!(bind.check_against(None).is_ok());
+    /// assert!(bind.check_against(Some("+14155551234")).is_ok());
+    /// assert!(bind.check_against(Some("+79991234567")).is_err());
+    /// ```
+    pub fn check_against(&self, current: Option<&str>) -> Result<(), ValidationError> {
+        match current {
+            Some(current) if current != self.phone => {
+                Err(already_bound("a different phone number is already bound"))
+            }
+            _ => Ok(())
+        }
+    }
+}
+
+fn already_bound(message: &'static str) -> ValidationError {
+    ValidationError::new("already_bound").with_message(message.into())
+}
+
+/// Request to bind any contact method, tagged by `method`.
+///
+/// Lets a single endpoint accept all three bind DTOs. The `method` key
+/// selects the variant; the remaining keys belong to the inner DTO.
+///
+/// # Validation
+///
+/// Delegates to the inner [`BindTelegram`], [`BindEmail`] or [`BindPhone`].
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::BindRequest;
+/// use validator::Validate;
+///
+/// let req: BindRequest =
+///     serde_json::from_str(r#"{"method":"email","email":"user@example.com"}"#).unwrap();
+/// assert!(matches!(req, BindRequest::Email(_)));
+/// assert!(req.validate().is_ok());
+///
+/// let req: BindRequest = serde_json::from_str(r#"{"method":"phone","phone":"123"}"#).unwrap();
+/// assert!(req.validate().is_err());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "method", rename_all = "lowercase")]
+pub enum BindRequest {
+    /// Bind a Telegram account.
+    Telegram(BindTelegram),
+
+    /// Bind an email address.
+    Email(BindEmail),
+
+    /// Bind a phone number.
+    Phone(BindPhone)
+}
+
+impl Validate for BindRequest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            Self::Telegram(bind) => bind.validate(),
+            Self::Email(bind) => bind.validate(),
+            Self::Phone(bind) => bind.validate()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dto::test_emails;
 
     #[test]
     fn telegram_validates_positive_id() {
@@ -266,4 +451,219 @@ mod tests {
             .is_err()
         );
     }
+
+    #[test]
+    fn check_against_allows_unbound() {
+        let telegram = BindTelegram {
+            telegram_id: 123
+        };
+        let email = BindEmail {
+            email: "user@example.com".into()
+        };
+        let phone = BindPhone {
+            phone: "+14155551234".into()
+        };
+
+        assert!(telegram.check_against(None).is_ok());
+        assert!(email.check_against(None).is_ok());
+        assert!(phone.check_against(None).is_ok());
+    }
+
+    #[test]
+    fn check_against_allows_same_value() {
+        let telegram = BindTelegram {
+            telegram_id: 123
+        };
+        let email = BindEmail {
+            email: "user@example.com".into()
+        };
+        let phone = BindPhone {
+            phone: "+14155551234".into()
+        };
+
+        assert!(telegram.check_against(Some(123)).is_ok());
+        assert!(email.check_against(Some("user@example.com")).is_ok());
+        assert!(phone.check_against(Some("+14155551234")).is_ok());
+    }
+
+    #[test]
+    fn email_check_against_ignores_case() {
+        let email = BindEmail {
+            email: "User@Example.com".into()
+        };
+
+        assert!(email.check_against(Some("user@example.com")).is_ok());
+        assert!(email.check_against(Some(" USER@EXAMPLE.COM ")).is_ok());
+        assert!(email.check_against(Some("user@example.org")).is_err());
+    }
+
+    #[test]
+    fn check_against_rejects_conflict() {
+        let telegram = BindTelegram {
+            telegram_id: 123
+        };
+        let email = BindEmail {
+            email: "user@example.com".into()
+        };
+        let phone = BindPhone {
+            phone: "+14155551234".into()
+        };
+
+        assert_eq!(
+            telegram.check_against(Some(456)).unwrap_err().code,
+            "already_bound"
+        );
+        assert_eq!(
+            email
+                .check_against(Some("other@example.com"))
+                .unwrap_err()
+                .code,
+            "already_bound"
+        );
+        assert_eq!(
+            phone.check_against(Some("+79991234567")).unwrap_err().code,
+            "already_bound"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "camel"))]
+    fn bind_request_deserializes_telegram() {
+        let req: BindRequest =
+            serde_json::from_str(r#"{"method":"telegram","telegram_id":123}"#).unwrap();
+
+        assert!(matches!(
+            req,
+            BindRequest::Telegram(BindTelegram {
+                telegram_id: 123
+            })
+        ));
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "camel")]
+    fn bind_request_deserializes_telegram_camel() {
+        let req: BindRequest =
+            serde_json::from_str(r#"{"method":"telegram","telegramId":123}"#).unwrap();
+
+        assert!(matches!(
+            req,
+            BindRequest::Telegram(BindTelegram {
+                telegram_id: 123
+            })
+        ));
+    }
+
+    #[test]
+    fn bind_request_deserializes_email_and_phone() {
+        let email: BindRequest =
+            serde_json::from_str(r#"{"method":"email","email":"user@example.com"}"#).unwrap();
+        match &email {
+            BindRequest::Email(bind) => assert_eq!(bind.email, "user@example.com"),
+            other => panic!("expected email, got {other:?}")
+        }
+        assert!(email.validate().is_ok());
+
+        let phone: BindRequest =
+            serde_json::from_str(r#"{"method":"phone","phone":"+14155551234"}"#).unwrap();
+        match &phone {
+            BindRequest::Phone(bind) => assert_eq!(bind.phone, "+14155551234"),
+            other => panic!("expected phone, got {other:?}")
+        }
+        assert!(phone.validate().is_ok());
+    }
+
+    #[test]
+    fn bind_request_delegates_validation() {
+        let email = BindRequest::Email(BindEmail {
+            email: "invalid".into()
+        });
+        assert!(
+            email
+                .validate()
+                .unwrap_err()
+                .field_errors()
+                .contains_key("email")
+        );
+
+        let phone = BindRequest::Phone(BindPhone {
+            phone: "+1234".into()
+        });
+        assert!(phone.validate().is_err());
+    }
+
+    #[test]
+    fn bind_request_rejects_unknown_method() {
+        let result = serde_json::from_str::<BindRequest>(r#"{"method":"fax","fax":"1"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn bind_request_serializes_method_tag() {
+        let req = BindRequest::Phone(BindPhone {
+            phone: "+14155551234".into()
+        });
+        let json = serde_json::to_value(&req).unwrap();
+
+        assert_eq!(json["method"], "phone");
+        assert_eq!(json["phone"], "+14155551234");
+    }
+
+    #[test]
+    #[cfg(feature = "camel")]
+    fn telegram_deserializes_camel_case_key() {
+        let bind: BindTelegram = serde_json::from_str(r#"{"telegramId":123}"#).unwrap();
+        assert_eq!(bind.telegram_id, 123);
+    }
+
+    #[test]
+    #[cfg(feature = "strict")]
+    fn strict_rejects_unknown_field() {
+        let result =
+            serde_json::from_str::<BindEmail>(r#"{"email":"user@example.com","emial":"x"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn email_length_is_bounded() {
+        assert!(
+            BindEmail {
+                email: test_emails::longest()
+            }
+            .validate()
+            .is_ok()
+        );
+
+        let err = BindEmail {
+            email: test_emails::too_long()
+        }
+        .validate()
+        .unwrap_err();
+        assert!(
+            err.field_errors()["email"]
+                .iter()
+                .any(|e| e.code == "length")
+        );
+    }
+
+    #[test]
+    fn telegram_id_accepts_numeric_string() {
+        let key = if cfg!(feature = "camel") {
+            "telegramId"
+        } else {
+            "telegram_id"
+        };
+
+        let from_number: BindTelegram =
+            serde_json::from_value(serde_json::json!({ key: 123 })).unwrap();
+        let from_string: BindTelegram =
+            serde_json::from_value(serde_json::json!({ key: "123" })).unwrap();
+
+        assert_eq!(from_number.telegram_id, from_string.telegram_id);
+        assert_eq!(from_string.telegram_id, 123);
+        let invalid = serde_json::json!({ key: "abc" });
+        assert!(serde_json::from_value::<BindTelegram>(invalid).is_err());
+        assert!(serde_json::from_value::<BindTelegram>(serde_json::json!({ key: "" })).is_err());
+    }
 }
@@ -9,6 +9,7 @@
 //!
 //! [`CreateUserRequest`] is used when creating a user from
 //! an authentication event (Telegram login, email signup, etc.).
+//! [`BulkCreateUsersRequest`] batches them for admin imports.
 //!
 //! # Examples
 //!
@@ -24,9 +25,13 @@
 //! assert!(req.email.is_some());
 //! ```
 
+use std::collections::BTreeMap;
+
+#[cfg(feature = "proptest-strategies")]
+use proptest::strategy::Strategy;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use validator::Validate;
+use validator::{Validate, ValidationError, ValidationErrors, ValidationErrorsKind};
 
 /// Request to create a new user.
 ///
@@ -45,7 +50,8 @@ use validator::Validate;
 /// # Validation
 ///
 /// - `telegram_id`: Must be positive (≥ 1)
-/// - `email`: Must be valid email format
+/// - `email`: Must be valid email format, at most 254 characters
+/// - `phone`: Must match E.164 format (`+` followed by 10-15 digits)
 ///
 /// # Examples
 ///
@@ -69,6 +75,9 @@ use validator::Validate;
 /// [`UpdateProfileRequest`]: crate::UpdateProfileRequest
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct CreateUserRequest {
     /// Pre-generated user ID.
     ///
@@ -78,19 +87,30 @@ pub struct CreateUserRequest {
 
     /// Telegram user ID from bot callback.
     ///
-    /// Must be a positive integer.
+    /// Must be a positive integer. Numeric strings such as `"123"` are
+    /// accepted too, as sent by the Telegram Login Widget.
     #[validate(range(min = 1))]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::telegram_id::option"
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<i64>"))]
     pub telegram_id: Option<i64>,
 
     /// Email address from email authentication.
     ///
-    /// Must be a valid email format.
-    #[validate(email)]
+    /// Must be a valid email format, at most 254 characters (RFC 5321).
+    #[validate(email, length(max = 254))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
 
     /// Phone number from phone authentication.
     ///
-    /// Should be in E.164 format (e.g., `+14155551234`).
+    /// Must be in E.164 format (e.g., `+14155551234`), which also
+    /// bounds its length.
+    #[validate(custom(function = "crate::phone_field"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub phone: Option<String>
 }
 
@@ -174,9 +194,171 @@ impl CreateUserRequest {
     }
 }
 
+/// Request to create many users at once, e.g. from an admin import.
+///
+/// # Validation
+///
+/// - `users`: at most [`DEFAULT_MAX_BATCH`](Self::DEFAULT_MAX_BATCH) entries,
+///   or the limit given to [`validate_with_max`](Self::validate_with_max)
+///   (error code `batch_too_large`); oversized batches are rejected before any
+///   entry is looked at
+/// - every entry is validated like a single [`CreateUserRequest`]; errors are
+///   reported per index under `users`
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{BulkCreateUsersRequest, CreateUserRequest};
+/// use validator::Validate;
+///
+/// let req = BulkCreateUsersRequest {
+///     users: vec![
+///         CreateUserRequest::telegram(1),
+///         CreateUserRequest::email("a@example.com"),
+///     ]
+/// };
+///
+/// assert!(req.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BulkCreateUsersRequest {
+    /// Users to create.
+    pub users: Vec<CreateUserRequest>
+}
+
+impl BulkCreateUsersRequest {
+    /// Batch size limit used by [`Validate::validate`].
+    pub const DEFAULT_MAX_BATCH: usize = 1000;
+
+    /// Validate with a batch size limit other than
+    /// [`DEFAULT_MAX_BATCH`](Self::DEFAULT_MAX_BATCH).
+    ///
+    /// # Errors
+    ///
+    /// Returns `batch_too_large` under `users` if there are more than
+    /// `max` entries, without validating them; otherwise the errors of
+    /// each invalid entry, keyed by index.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{BulkCreateUsersRequest, CreateUserRequest};
+    ///
+    /// let req = BulkCreateUsersRequest {
+    ///     users: (1..=3).map(CreateUserRequest::telegram).collect()
+    /// };
+    ///
+    /// assert!(req.validate_with_max(3).is_ok());
+    /// assert!(req.validate_with_max(2).is_err());
+    /// ```
+    pub fn validate_with_max(&self, max: usize) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+
+        if self.users.len() > max {
+            let mut err = ValidationError::new("batch_too_large");
+            err.add_param("max".into(), &max);
+            err.add_param("actual".into(), &self.users.len());
+            errors.add("users", err);
+            return Err(errors);
+        }
+
+        let invalid: BTreeMap<usize, Box<ValidationErrors>> = self
+            .users
+            .iter()
+            .enumerate()
+            .filter_map(|(index, user)| user.validate().err().map(|e| (index, Box::new(e))))
+            .collect();
+        if invalid.is_empty() {
+            return Ok(());
+        }
+        errors
+            .errors_mut()
+            .insert("users".into(), ValidationErrorsKind::List(invalid));
+        Err(errors)
+    }
+}
+
+impl Validate for BulkCreateUsersRequest {
+    /// Validates with [`DEFAULT_MAX_BATCH`](Self::DEFAULT_MAX_BATCH).
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        self.validate_with_max(Self::DEFAULT_MAX_BATCH)
+    }
+}
+
+/// Strategy producing [`CreateUserRequest`]s that pass validation.
+///
+/// Each request carries exactly one contact: a positive Telegram ID, a
+/// well-formed email or an E.164 phone number.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use proptest::prelude::*;
+/// use revelation_user::valid_create_request;
+/// use validator::Validate;
+///
+/// proptest! {
+///     #[test]
+///     fn accepts(req in valid_create_request()) {
+///         prop_assert!(req.validate().is_ok());
+///     }
+/// }
+/// ```
+#[cfg(feature = "proptest-strategies")]
+pub fn valid_create_request() -> impl Strategy<Value = CreateUserRequest> {
+    use proptest::prelude::*;
+
+    let request = prop_oneof![
+        (1..=i64::MAX).prop_map(CreateUserRequest::telegram),
+        "[a-z0-9]{1,32}@[a-z0-9]{1,32}\\.(com|org|net)".prop_map(CreateUserRequest::email),
+        "\\+[1-9][0-9]{9,14}".prop_map(CreateUserRequest::phone)
+    ];
+
+    (any::<u128>(), request).prop_map(|(id, req)| CreateUserRequest {
+        id: Uuid::from_u128(id),
+        ..req
+    })
+}
+
+/// Strategy producing [`CreateUserRequest`]s that fail validation.
+///
+/// Each request breaks one rule: a non-positive Telegram ID, an email
+/// without `@` or over 254 characters, or a phone number without `+` or
+/// with too few digits.
+#[cfg(feature = "proptest-strategies")]
+pub fn invalid_create_request() -> impl Strategy<Value = CreateUserRequest> {
+    use proptest::prelude::*;
+
+    prop_oneof![
+        (i64::MIN..=0).prop_map(CreateUserRequest::telegram),
+        "[a-z0-9]{1,64}".prop_map(CreateUserRequest::email),
+        "[a-z]{64}@[a-z]{63}\\.[a-z]{63}\\.[a-z]{63}\\.com".prop_map(CreateUserRequest::email),
+        "[1-9][0-9]{9,14}".prop_map(CreateUserRequest::phone),
+        "\\+[1-9][0-9]{0,8}".prop_map(CreateUserRequest::phone)
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dto::test_emails;
+
+    #[cfg(feature = "proptest-strategies")]
+    proptest::proptest! {
+        #[test]
+        fn valid_create_requests_validate(req in valid_create_request()) {
+            proptest::prop_assert!(req.validate().is_ok());
+        }
+
+        #[test]
+        fn invalid_create_requests_never_validate(req in invalid_create_request()) {
+            proptest::prop_assert!(req.validate().is_err());
+        }
+    }
 
     #[test]
     fn telegram_constructor() {
@@ -219,6 +401,26 @@ mod tests {
         assert!(req.validate().is_ok());
     }
 
+    #[test]
+    fn serialization_omits_unset_contacts() {
+        let req = CreateUserRequest::email("test@test.com");
+        let json = serde_json::to_value(&req).unwrap();
+        let telegram_key = if cfg!(feature = "camel") {
+            "telegramId"
+        } else {
+            "telegram_id"
+        };
+
+        assert_eq!(json["email"], "test@test.com");
+        assert!(json.get(telegram_key).is_none());
+        assert!(json.get("phone").is_none());
+
+        let decoded: CreateUserRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.id, req.id);
+        assert!(decoded.telegram_id.is_none());
+        assert!(decoded.phone.is_none());
+    }
+
     #[test]
     fn phone_constructor() {
         let req = CreateUserRequest::phone("+14155551234");
@@ -226,4 +428,115 @@ mod tests {
         assert!(req.telegram_id.is_none());
         assert!(req.email.is_none());
     }
+
+    #[test]
+    #[cfg(feature = "strict")]
+    fn strict_rejects_unknown_field() {
+        let result = serde_json::from_str::<CreateUserRequest>(r#"{"telegram":123}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn email_length_is_bounded() {
+        assert!(
+            CreateUserRequest::email(test_emails::longest())
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            CreateUserRequest::email(test_emails::too_long())
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn phone_must_be_e164() {
+        assert!(CreateUserRequest::phone("+14155551234").validate().is_ok());
+        assert!(
+            CreateUserRequest::phone("+1".repeat(100))
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn telegram_id_accepts_numeric_string() {
+        let key = if cfg!(feature = "camel") {
+            "telegramId"
+        } else {
+            "telegram_id"
+        };
+
+        let from_number: CreateUserRequest =
+            serde_json::from_value(serde_json::json!({ key: 123 })).unwrap();
+        let from_string: CreateUserRequest =
+            serde_json::from_value(serde_json::json!({ key: "123" })).unwrap();
+
+        assert_eq!(from_number.telegram_id, Some(123));
+        assert_eq!(from_string.telegram_id, Some(123));
+        let invalid = serde_json::json!({ key: "abc" });
+        assert!(serde_json::from_value::<CreateUserRequest>(invalid).is_err());
+    }
+
+    #[test]
+    fn bulk_accepts_all_valid_batch() {
+        let req = BulkCreateUsersRequest {
+            users: vec![
+                CreateUserRequest::telegram(1),
+                CreateUserRequest::email("a@example.com"),
+                CreateUserRequest::phone("+14155551234"),
+            ]
+        };
+
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn bulk_rejects_batch_with_one_invalid_entry() {
+        let req = BulkCreateUsersRequest {
+            users: vec![
+                CreateUserRequest::telegram(1),
+                CreateUserRequest::email("not-an-email"),
+                CreateUserRequest::telegram(2),
+            ]
+        };
+
+        let errors = req.validate().unwrap_err();
+        let validator::ValidationErrorsKind::List(list) = &errors.errors()["users"] else {
+            panic!("expected per-entry errors");
+        };
+        assert_eq!(list.keys().copied().collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn bulk_rejects_oversized_batch() {
+        let size = BulkCreateUsersRequest::DEFAULT_MAX_BATCH + 1;
+        let req = BulkCreateUsersRequest {
+            users: (1..=size as i64).map(CreateUserRequest::telegram).collect()
+        };
+
+        let errors = req.validate().unwrap_err();
+        let field = errors.field_errors();
+        assert_eq!(field["users"][0].code, "batch_too_large");
+    }
+
+    #[test]
+    fn bulk_rejects_oversized_batch_before_entries() {
+        let req = BulkCreateUsersRequest {
+            users: vec![
+                CreateUserRequest::telegram(1),
+                CreateUserRequest::email("not-an-email"),
+                CreateUserRequest::telegram(2),
+            ]
+        };
+
+        let errors = req.validate_with_max(2).unwrap_err();
+        let validator::ValidationErrorsKind::Field(field) = &errors.errors()["users"] else {
+            panic!("expected only the batch size error");
+        };
+        assert_eq!(field.len(), 1);
+        assert_eq!(field[0].code, "batch_too_large");
+        assert_eq!(field[0].params["max"], 2);
+    }
 }
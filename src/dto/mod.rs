@@ -12,9 +12,11 @@
 //! |-----|---------|
 //! | [`CreateUserRequest`] | Create a new user |
 //! | [`UpdateProfileRequest`] | Update user profile |
+//! | [`PatchProfileRequest`] | Update user profile, with explicit clearing |
 //! | [`BindTelegram`] | Bind Telegram account |
 //! | [`BindEmail`] | Bind email address |
 //! | [`BindPhone`] | Bind phone number |
+//! | [`BindRequest`] | Bind any contact method, tagged by `method` |
 //!
 //! # Validation
 //!
@@ -25,10 +27,11 @@
 //! use validator::Validate;
 //!
 //! let req = UpdateProfileRequest {
-//!     name:          Some("A".into()), // Too short (min 2)
-//!     gender:        None,
-//!     birth_date:    None,
-//!     confession_id: None
+//!     name:               Some("A".into()), // Too short (min 2)
+//!     gender:             None,
+//!     birth_date:         None,
+//!     confession_id:      None,
+//!     notification_prefs: None
 //! };
 //!
 //! assert!(req.validate().is_err());
@@ -46,3 +49,36 @@ mod update;
 pub use bind::*;
 pub use create::*;
 pub use update::*;
+
+/// Email addresses for the 254-character limit shared by the DTOs.
+#[cfg(test)]
+mod test_emails {
+    /// Valid address of exactly 254 characters.
+    pub fn longest() -> String {
+        let domain = format!(
+            "{}.{}.{}.com",
+            "b".repeat(63),
+            "c".repeat(63),
+            "d".repeat(57)
+        );
+        format!("{}@{domain}", "a".repeat(64))
+    }
+
+    /// Well-formed address of 300 characters.
+    pub fn too_long() -> String {
+        let domain = format!(
+            "{}.{}.{}.{}.com",
+            "b".repeat(63),
+            "c".repeat(63),
+            "d".repeat(63),
+            "e".repeat(39)
+        );
+        format!("{}@{domain}", "a".repeat(64))
+    }
+
+    #[test]
+    fn lengths_straddle_the_limit() {
+        assert_eq!(longest().len(), 254);
+        assert_eq!(too_long().len(), 300);
+    }
+}
@@ -10,6 +10,9 @@
 //! [`UpdateProfileRequest`] contains all updateable profile fields.
 //! All fields are optional - only provided fields will be updated.
 //!
+//! [`PatchProfileRequest`] carries the same fields as [`Patch`] values,
+//! so a PATCH endpoint can also clear a field by sending `null`.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -17,28 +20,37 @@
 //! use validator::Validate;
 //!
 //! let req = UpdateProfileRequest {
-//!     name:          Some("John Doe".into()),
-//!     gender:        Some(Gender::Male),
-//!     birth_date:    None,
-//!     confession_id: None
+//!     name:               Some("John Doe".into()),
+//!     gender:             Some(Gender::Male),
+//!     birth_date:         None,
+//!     confession_id:      None,
+//!     notification_prefs: None
 //! };
 //!
 //! assert!(req.validate().is_ok());
 //! assert!(!req.is_empty());
 //! ```
 
-use chrono::NaiveDate;
+use std::sync::OnceLock;
+
+use chrono::{NaiveDate, Utc};
+#[cfg(feature = "proptest-strategies")]
+use proptest::strategy::Strategy;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use validator::Validate;
+use validator::{Validate, ValidationError, ValidationErrors};
 
-use crate::Gender;
+use crate::{Gender, NotificationPrefs, Patch};
 
 /// Request to update user profile fields.
 ///
 /// All fields are optional. Only fields with `Some` value
 /// will be updated; `None` means "don't change".
 ///
+/// `None` fields are omitted when serializing, so clients never see a
+/// `null` they could mistake for "clear this field". An explicit `null`
+/// on input is still read as `None`.
+///
 /// # Validation
 ///
 /// - `name`: Must be 2-100 characters when provided
@@ -52,10 +64,11 @@ use crate::Gender;
 ///
 /// // Only update name
 /// let req = UpdateProfileRequest {
-///     name:          Some("New Name".into()),
-///     gender:        None,
-///     birth_date:    None,
-///     confession_id: None
+///     name:               Some("New Name".into()),
+///     gender:             None,
+///     birth_date:         None,
+///     confession_id:      None,
+///     notification_prefs: None
 /// };
 ///
 /// assert!(!req.is_empty());
@@ -69,10 +82,11 @@ use crate::Gender;
 /// use uuid::Uuid;
 ///
 /// let req = UpdateProfileRequest {
-///     name:          Some("John Doe".into()),
-///     gender:        Some(Gender::Male),
-///     birth_date:    Some(NaiveDate::from_ymd_opt(1990, 1, 15).unwrap()),
-///     confession_id: Some(Uuid::now_v7())
+///     name:               Some("John Doe".into()),
+///     gender:             Some(Gender::Male),
+///     birth_date:         Some(NaiveDate::from_ymd_opt(1990, 1, 15).unwrap()),
+///     confession_id:      Some(Uuid::now_v7()),
+///     notification_prefs: None
 /// };
 /// ```
 ///
@@ -83,31 +97,46 @@ use crate::Gender;
 /// use validator::Validate;
 ///
 /// let req = UpdateProfileRequest {
-///     name:          Some("X".into()), // Too short!
-///     gender:        None,
-///     birth_date:    None,
-///     confession_id: None
+///     name:               Some("X".into()), // Too short!
+///     gender:             None,
+///     birth_date:         None,
+///     confession_id:      None,
+///     notification_prefs: None
 /// };
 ///
 /// assert!(req.validate().is_err());
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
 pub struct UpdateProfileRequest {
     /// Display name (2-100 characters).
     ///
     /// When provided, must be between 2 and 100 characters.
     #[validate(length(min = 2, max = 100))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 
     /// User's gender.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub gender: Option<Gender>,
 
     /// Date of birth.
+    ///
+    /// Checked against the installed [`AgePolicy`].
+    #[validate(custom(function = "validate_birth_date"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub birth_date: Option<NaiveDate>,
 
     /// Reference to confession/denomination.
-    pub confession_id: Option<Uuid>
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confession_id: Option<Uuid>,
+
+    /// Notification channels the user accepts, replaced as a whole.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification_prefs: Option<NotificationPrefs>
 }
 
 impl UpdateProfileRequest {
@@ -121,18 +150,20 @@ impl UpdateProfileRequest {
     /// use revelation_user::UpdateProfileRequest;
     ///
     /// let empty = UpdateProfileRequest {
-    ///     name:          None,
-    ///     gender:        None,
-    ///     birth_date:    None,
-    ///     confession_id: None
+    ///     name:               None,
+    ///     gender:             None,
+    ///     birth_date:         None,
+    ///     confession_id:      None,
+    ///     notification_prefs: None
     /// };
     /// assert!(empty.is_empty());
     ///
     /// let not_empty = UpdateProfileRequest {
-    ///     name:          Some("Test".into()),
-    ///     gender:        None,
-    ///     birth_date:    None,
-    ///     confession_id: None
+    ///     name:               Some("Test".into()),
+    ///     gender:             None,
+    ///     birth_date:         None,
+    ///     confession_id:      None,
+    ///     notification_prefs: None
     /// };
     /// assert!(!not_empty.is_empty());
     /// ```
@@ -142,6 +173,7 @@ impl UpdateProfileRequest {
             && self.gender.is_none()
             && self.birth_date.is_none()
             && self.confession_id.is_none()
+            && self.notification_prefs.is_none()
     }
 
     /// Create an empty update request.
@@ -157,10 +189,11 @@ impl UpdateProfileRequest {
     #[must_use]
     pub const fn empty() -> Self {
         Self {
-            name:          None,
-            gender:        None,
-            birth_date:    None,
-            confession_id: None
+            name:               None,
+            gender:             None,
+            birth_date:         None,
+            confession_id:      None,
+            notification_prefs: None
         }
     }
 }
@@ -171,10 +204,282 @@ impl Default for UpdateProfileRequest {
     }
 }
 
+/// Request to patch user profile fields with explicit clearing.
+///
+/// Like [`UpdateProfileRequest`], but every field is a [`Patch`]: an
+/// omitted key leaves the field unchanged, `null` clears it and a value
+/// sets it.
+///
+/// # Validation
+///
+/// - `name`: Must be 2-100 characters when set
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{Gender, Patch, PatchProfileRequest};
+/// use validator::Validate;
+///
+/// let req: PatchProfileRequest =
+///     serde_json::from_str(r#"{"name":"John Doe","gender":null}"#).unwrap();
+///
+/// assert_eq!(req.name, Patch::Set("John Doe".into()));
+/// assert_eq!(req.gender, Patch::<Gender>::Clear);
+/// assert_eq!(req.birth_date, Patch::Unchanged);
+/// assert!(req.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PatchProfileRequest {
+    /// Display name (2-100 characters).
+    #[serde(default, skip_serializing_if = "Patch::is_unchanged")]
+    #[cfg_attr(feature = "api", schema(value_type = Option<String>))]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub name: Patch<String>,
+
+    /// User's gender.
+    #[serde(default, skip_serializing_if = "Patch::is_unchanged")]
+    #[cfg_attr(feature = "api", schema(value_type = Option<Gender>))]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Gender>"))]
+    pub gender: Patch<Gender>,
+
+    /// Date of birth.
+    #[serde(default, skip_serializing_if = "Patch::is_unchanged")]
+    #[cfg_attr(feature = "api", schema(value_type = Option<NaiveDate>))]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<NaiveDate>"))]
+    pub birth_date: Patch<NaiveDate>,
+
+    /// Reference to confession/denomination.
+    #[serde(default, skip_serializing_if = "Patch::is_unchanged")]
+    #[cfg_attr(feature = "api", schema(value_type = Option<Uuid>))]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<Uuid>"))]
+    pub confession_id: Patch<Uuid>
+}
+
+impl PatchProfileRequest {
+    /// Check if the request leaves every field unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Patch, PatchProfileRequest};
+    ///
+    /// assert!(PatchProfileRequest::default().is_empty());
+    ///
+    /// let req = PatchProfileRequest {
+    ///     name: Patch::Clear,
+    ///     ..Default::default()
+    /// };
+    /// assert!(!req.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.name.is_unchanged()
+            && self.gender.is_unchanged()
+            && self.birth_date.is_unchanged()
+            && self.confession_id.is_unchanged()
+    }
+}
+
+impl Validate for PatchProfileRequest {
+    /// Applies the [`UpdateProfileRequest`] rules to the fields being set.
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        UpdateProfileRequest {
+            name:               self.name.as_set().cloned(),
+            gender:             self.gender.as_set().copied(),
+            birth_date:         self.birth_date.as_set().copied(),
+            confession_id:      self.confession_id.as_set().copied(),
+            notification_prefs: None
+        }
+        .validate()
+    }
+}
+
+/// Minimum-age rule applied to birth date updates.
+///
+/// Apps that gate signup by age must apply the same gate when users
+/// change their birth date later. Install a policy once at startup with
+/// [`AgePolicy::install`]; from then on [`UpdateProfileRequest`] and
+/// [`PatchProfileRequest`] validation rejects birth dates implying a
+/// younger age. Without an installed policy no age is enforced.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use revelation_user::AgePolicy;
+///
+/// let policy = AgePolicy::min_age(18);
+/// let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+///
+/// assert!(policy.allows(NaiveDate::from_ymd_opt(2007, 6, 1).unwrap(), today));
+/// assert!(!policy.allows(NaiveDate::from_ymd_opt(2007, 6, 2).unwrap(), today));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(default)]
+pub struct AgePolicy {
+    /// Minimum age in full years, `None` to disable the check.
+    pub min_age: Option<u32>
+}
+
+static AGE_POLICY: OnceLock<AgePolicy> = OnceLock::new();
+
+impl AgePolicy {
+    /// Policy enforcing no minimum age.
+    pub const NONE: Self = Self {
+        min_age: None
+    };
+
+    /// Create a policy requiring at least `years` full years of age.
+    #[must_use]
+    pub const fn min_age(years: u32) -> Self {
+        Self {
+            min_age: Some(years)
+        }
+    }
+
+    /// Check whether someone born on `birth_date` is old enough on `today`.
+    ///
+    /// Birth dates after `today` are rejected whenever a minimum age is
+    /// set.
+    #[must_use]
+    pub fn allows(&self, birth_date: NaiveDate, today: NaiveDate) -> bool {
+        match self.min_age {
+            None => true,
+            Some(min) => today.years_since(birth_date).is_some_and(|age| age >= min)
+        }
+    }
+
+    /// Install this policy process-wide.
+    ///
+    /// Can only be done once; call it during startup before handling
+    /// requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy back if one is already installed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{Months, Utc};
+    /// use revelation_user::{AgePolicy, UpdateProfileRequest};
+    /// use validator::Validate;
+    ///
+    /// AgePolicy::min_age(18).install().unwrap();
+    ///
+    /// let today = Utc::now().date_naive();
+    /// let req = UpdateProfileRequest {
+    ///     birth_date: today.checked_sub_months(Months::new(15 * 12)),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert!(req.validate().is_err());
+    /// assert!(AgePolicy::NONE.install().is_err());
+    /// ```
+    pub fn install(self) -> Result<(), Self> {
+        AGE_POLICY.set(self)
+    }
+
+    /// Returns the installed policy, or [`AgePolicy::NONE`] if none is.
+    #[must_use]
+    pub fn current() -> Self {
+        AGE_POLICY.get().copied().unwrap_or_default()
+    }
+}
+
+/// Validator for `birth_date` against the installed [`AgePolicy`].
+fn validate_birth_date(birth_date: &NaiveDate) -> Result<(), ValidationError> {
+    if AgePolicy::current().allows(*birth_date, Utc::now().date_naive()) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("min_age"))
+    }
+}
+
+/// Strategy producing [`UpdateProfileRequest`]s that pass validation.
+///
+/// Every field is independently `None` or a valid value.
+#[cfg(feature = "proptest-strategies")]
+pub fn valid_update_request() -> impl Strategy<Value = UpdateProfileRequest> {
+    use proptest::{option, prelude::*};
+
+    (option::of("[A-Za-z ]{2,100}"), profile_fields()).prop_map(
+        |(name, (gender, birth_date, confession_id))| UpdateProfileRequest {
+            name,
+            gender,
+            birth_date,
+            confession_id,
+            notification_prefs: None
+        }
+    )
+}
+
+/// Strategy producing [`UpdateProfileRequest`]s that fail validation.
+///
+/// `name` is always under 2 or over 100 characters; the other fields
+/// are arbitrary valid values.
+#[cfg(feature = "proptest-strategies")]
+pub fn invalid_update_request() -> impl Strategy<Value = UpdateProfileRequest> {
+    use proptest::prelude::*;
+
+    (prop_oneof!["[a-z]?", "[a-z]{101,150}"], profile_fields()).prop_map(
+        |(name, (gender, birth_date, confession_id))| UpdateProfileRequest {
+            name: Some(name),
+            gender,
+            birth_date,
+            confession_id,
+            notification_prefs: None
+        }
+    )
+}
+
+/// Optional gender, birth date and confession.
+#[cfg(feature = "proptest-strategies")]
+type ProfileFields = (Option<Gender>, Option<NaiveDate>, Option<Uuid>);
+
+/// Strategy for the fields that cannot fail validation.
+///
+/// Birth dates stop at 2000 so they also pass a typical installed
+/// [`AgePolicy`].
+#[cfg(feature = "proptest-strategies")]
+fn profile_fields() -> impl Strategy<Value = ProfileFields> {
+    use proptest::{option, prelude::*};
+
+    let gender = prop_oneof![Just(Gender::Male), Just(Gender::Female)];
+    let birth_date = (1900..=2000_i32, 1..=12_u32, 1..=28_u32)
+        .prop_filter_map("valid date", |(y, m, d)| NaiveDate::from_ymd_opt(y, m, d));
+    let confession_id = any::<u128>().prop_map(Uuid::from_u128);
+
+    (
+        option::of(gender),
+        option::of(birth_date),
+        option::of(confession_id)
+    )
+}
+
 #[cfg(test)]
 mod tests {
+    use chrono::Months;
+
     use super::*;
 
+    #[cfg(feature = "proptest-strategies")]
+    proptest::proptest! {
+        #[test]
+        fn valid_update_requests_validate(req in valid_update_request()) {
+            proptest::prop_assert!(req.validate().is_ok());
+        }
+
+        #[test]
+        fn invalid_update_requests_never_validate(req in invalid_update_request()) {
+            proptest::prop_assert!(req.validate().is_err());
+        }
+    }
+
     #[test]
     fn is_empty_when_all_none() {
         let req = UpdateProfileRequest::empty();
@@ -205,9 +510,155 @@ mod tests {
         assert!(valid.validate().is_ok());
     }
 
+    #[test]
+    fn age_policy_counts_full_years() {
+        let policy = AgePolicy::min_age(18);
+        let today = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+
+        assert!(policy.allows(NaiveDate::from_ymd_opt(2007, 3, 10).unwrap(), today));
+        assert!(!policy.allows(NaiveDate::from_ymd_opt(2007, 3, 11).unwrap(), today));
+        assert!(!policy.allows(NaiveDate::from_ymd_opt(2030, 1, 1).unwrap(), today));
+        assert!(AgePolicy::NONE.allows(NaiveDate::from_ymd_opt(2030, 1, 1).unwrap(), today));
+    }
+
+    #[test]
+    fn birth_date_is_unchecked_without_installed_policy() {
+        // Never installed here: the policy is process-wide and would leak
+        // into other tests. `AgePolicy::install` is covered by its doctest.
+        let today = Utc::now().date_naive();
+        let req = UpdateProfileRequest {
+            birth_date: today.checked_sub_months(Months::new(5 * 12)),
+            ..Default::default()
+        };
+
+        assert_eq!(AgePolicy::current(), AgePolicy::NONE);
+        assert!(req.validate().is_ok());
+    }
+
     #[test]
     fn default_is_empty() {
         let req = UpdateProfileRequest::default();
         assert!(req.is_empty());
     }
+
+    #[test]
+    fn serialization_omits_none_fields() {
+        let req = UpdateProfileRequest {
+            gender: Some(Gender::Female),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&req).unwrap();
+
+        assert_eq!(json, serde_json::json!({ "gender": "female" }));
+    }
+
+    #[test]
+    fn omitted_and_null_fields_both_mean_unchanged() {
+        let omitted: UpdateProfileRequest = serde_json::from_str("{}").unwrap();
+        let null: UpdateProfileRequest = serde_json::from_str(r#"{"gender":null}"#).unwrap();
+
+        assert!(omitted.is_empty());
+        assert!(null.is_empty());
+    }
+
+    #[test]
+    fn serialization_round_trips() {
+        let req = UpdateProfileRequest {
+            name: Some("John Doe".into()),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&req).unwrap();
+        let back: UpdateProfileRequest = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(back.name.as_deref(), Some("John Doe"));
+        assert!(back.gender.is_none());
+    }
+
+    #[test]
+    fn notification_prefs_count_as_an_update() {
+        let prefs = NotificationPrefs {
+            push: true,
+            ..Default::default()
+        };
+        let req = UpdateProfileRequest {
+            notification_prefs: Some(prefs),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&req).unwrap();
+        let back: UpdateProfileRequest = serde_json::from_str(&json).unwrap();
+
+        assert!(!req.is_empty());
+        assert_eq!(back.notification_prefs, Some(prefs));
+    }
+
+    #[test]
+    #[cfg(feature = "camel")]
+    fn serializes_camel_case_keys() {
+        let req = UpdateProfileRequest {
+            birth_date: NaiveDate::from_ymd_opt(1990, 1, 15),
+            confession_id: Some(Uuid::nil()),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&req).unwrap();
+
+        assert!(json.contains("\"birthDate\""));
+        assert!(json.contains("\"confessionId\""));
+        assert!(!json.contains("birth_date"));
+    }
+
+    #[test]
+    #[cfg(feature = "strict")]
+    fn strict_rejects_unknown_field() {
+        let result = serde_json::from_str::<UpdateProfileRequest>(r#"{"naem":"x"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn patch_missing_gender_is_unchanged() {
+        let req: PatchProfileRequest = serde_json::from_str(r#"{"name":"John"}"#).unwrap();
+
+        assert_eq!(req.gender, Patch::Unchanged);
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({ "name": "John" })
+        );
+    }
+
+    #[test]
+    fn patch_null_gender_is_clear() {
+        let req: PatchProfileRequest = serde_json::from_str(r#"{"gender":null}"#).unwrap();
+
+        assert_eq!(req.gender, Patch::Clear);
+        assert!(!req.is_empty());
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({ "gender": null })
+        );
+    }
+
+    #[test]
+    fn patch_gender_value_is_set() {
+        let req: PatchProfileRequest = serde_json::from_str(r#"{"gender":"female"}"#).unwrap();
+
+        assert_eq!(req.gender, Patch::Set(Gender::Female));
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({ "gender": "female" })
+        );
+    }
+
+    #[test]
+    fn patch_validates_only_set_name() {
+        let too_short = PatchProfileRequest {
+            name: Patch::Set("X".into()),
+            ..Default::default()
+        };
+        assert!(too_short.validate().is_err());
+
+        let cleared = PatchProfileRequest {
+            name: Patch::Clear,
+            ..Default::default()
+        };
+        assert!(cleared.validate().is_ok());
+    }
 }
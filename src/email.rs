@@ -0,0 +1,303 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! Validated email addresses.
+//!
+//! This module provides [`EmailAddress`], a newtype that always holds an
+//! address accepted by the `validator` email rules.
+//!
+//! # Normalization
+//!
+//! [`EmailAddress::parse`] trims the input and lowercases the domain part.
+//! The local part is kept as-is, since it may be case-sensitive.
+//!
+//! | Input | Result |
+//! |-------|--------|
+//! | `"John@Example.COM"` | `John@example.com` |
+//! | `" jane@example.com "` | `jane@example.com` |
+//! | `"not-an-email"` | Error |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use revelation_user::EmailAddress;
+//!
+//! let email = EmailAddress::parse("John@Example.COM").unwrap();
+//! assert_eq!(email.as_str(), "John@example.com");
+//!
+//! // Rejected on deserialize as well
+//! assert!(serde_json::from_str::<EmailAddress>("\"nope\"").is_err());
+//! ```
+
+use core::ops::Deref;
+
+use masterror::AppError;
+use serde::{Deserialize, Serialize};
+use validator::ValidateEmail;
+
+use crate::RUser;
+
+/// Maximum email length accepted, matching the DTO validation rules.
+const MAX_EMAIL_LEN: usize = 254;
+
+/// Email address with a lowercased domain.
+///
+/// Can only be built through [`EmailAddress::parse`] (or deserialization,
+/// which parses), so every value is a valid address.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::EmailAddress;
+///
+/// let email = EmailAddress::parse("jane@Example.org").unwrap();
+///
+/// // Derefs to str
+/// assert!(email.ends_with("@example.org"));
+/// assert_eq!(email.domain(), "example.org");
+/// assert_eq!(email.local_part(), "jane");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(transparent))]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EmailAddress(String);
+
+impl EmailAddress {
+    /// Validate an email address and lowercase its domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Email address, surrounding whitespace is ignored
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmailError`] if the input is empty, longer than 254
+    /// characters, or fails the `validator` email rules.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{EmailAddress, EmailError};
+    ///
+    /// assert!(EmailAddress::parse("john@example.com").is_ok());
+    /// assert_eq!(EmailAddress::parse("  "), Err(EmailError::Empty));
+    /// assert!(EmailAddress::parse("john@").is_err());
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, EmailError> {
+        let trimmed = input.trim();
+
+        if trimmed.is_empty() {
+            return Err(EmailError::Empty);
+        }
+        if trimmed.len() > MAX_EMAIL_LEN || !trimmed.validate_email() {
+            return Err(EmailError::Invalid(input.to_owned()));
+        }
+
+        let normalized = match trimmed.rsplit_once('@') {
+            Some((local, domain)) => format!("{local}@{}", domain.to_lowercase()),
+            None => return Err(EmailError::Invalid(input.to_owned()))
+        };
+
+        Ok(Self(normalized))
+    }
+
+    /// Returns the address as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the part before `@`.
+    #[must_use]
+    pub fn local_part(&self) -> &str {
+        self.0.rsplit_once('@').map_or("", |(local, _)| local)
+    }
+
+    /// Returns the lowercased part after `@`.
+    #[must_use]
+    pub fn domain(&self) -> &str {
+        self.0.rsplit_once('@').map_or("", |(_, domain)| domain)
+    }
+
+    /// Consumes the address and returns the inner string.
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for EmailAddress {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for EmailAddress {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::fmt::Display for EmailAddress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl core::str::FromStr for EmailAddress {
+    type Err = EmailError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<String> for EmailAddress {
+    type Error = EmailError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<EmailAddress> for String {
+    fn from(email: EmailAddress) -> Self {
+        email.0
+    }
+}
+
+/// Error returned when an email address is not valid.
+///
+/// # Conversions
+///
+/// - `Into<AppError>` - 422 Unprocessable Entity for HTTP responses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmailError {
+    /// Input was empty after trimming.
+    Empty,
+
+    /// Input is not a valid email (original input attached).
+    Invalid(String)
+}
+
+impl core::fmt::Display for EmailError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => f.write_str("email address is empty"),
+            Self::Invalid(input) => write!(f, "invalid email address: {input}")
+        }
+    }
+}
+
+impl std::error::Error for EmailError {}
+
+impl From<EmailError> for AppError {
+    /// Converts into a 422 Unprocessable Entity error.
+    fn from(err: EmailError) -> Self {
+        AppError::validation(err.to_string())
+    }
+}
+
+impl RUser {
+    /// Get the email as a validated [`EmailAddress`].
+    ///
+    /// Returns `None` if no email is set or the stored value is not a
+    /// valid address.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let user = RUser::from_email("john@Example.com");
+    /// assert_eq!(user.email_parsed().unwrap().as_str(), "john@example.com");
+    ///
+    /// assert!(RUser::empty().email_parsed().is_none());
+    /// ```
+    #[must_use]
+    pub fn email_parsed(&self) -> Option<EmailAddress> {
+        self.email
+            .as_deref()
+            .and_then(|email| EmailAddress::parse(email).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use masterror::AppErrorKind;
+
+    use super::*;
+
+    #[test]
+    fn parse_accepts_valid_email() {
+        let email = EmailAddress::parse("john@example.com").unwrap();
+
+        assert_eq!(email.as_str(), "john@example.com");
+        assert_eq!(&*email, "john@example.com");
+        assert_eq!(email.local_part(), "john");
+        assert_eq!(email.domain(), "example.com");
+    }
+
+    #[test]
+    fn parse_lowercases_domain_only() {
+        let email = EmailAddress::parse(" John.Doe@Example.COM ").unwrap();
+
+        assert_eq!(email.as_str(), "John.Doe@example.com");
+    }
+
+    #[test]
+    fn parse_rejects_invalid() {
+        assert_eq!(EmailAddress::parse(""), Err(EmailError::Empty));
+        assert_eq!(
+            EmailAddress::parse("not-an-email"),
+            Err(EmailError::Invalid("not-an-email".into()))
+        );
+        assert!(EmailAddress::parse("john@").is_err());
+        assert!(EmailAddress::parse("@example.com").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_too_long() {
+        let email = format!("{}@example.com", "a".repeat(250));
+
+        assert!(EmailAddress::parse(&email).is_err());
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let email = EmailAddress::parse("john@example.com").unwrap();
+        let json = serde_json::to_string(&email).unwrap();
+
+        assert_eq!(json, "\"john@example.com\"");
+        assert_eq!(serde_json::from_str::<EmailAddress>(&json).unwrap(), email);
+    }
+
+    #[test]
+    fn deserialize_normalizes_and_rejects() {
+        let email: EmailAddress = serde_json::from_str("\"jane@EXAMPLE.org\"").unwrap();
+        assert_eq!(email.as_str(), "jane@example.org");
+
+        assert!(serde_json::from_str::<EmailAddress>("\"invalid\"").is_err());
+    }
+
+    #[test]
+    fn email_parsed_from_user() {
+        let mut user = RUser::from_email("john@Example.com");
+        assert_eq!(user.email_parsed().unwrap().as_str(), "john@example.com");
+
+        user.email = Some("garbage".into());
+        assert!(user.email_parsed().is_none());
+    }
+
+    #[test]
+    fn converts_into_validation_app_error() {
+        let app: AppError = EmailAddress::parse("nope").unwrap_err().into();
+
+        assert_eq!(app.kind, AppErrorKind::Validation);
+    }
+}
@@ -18,10 +18,15 @@
 //!
 //! | Claim | Type | Description |
 //! |-------|------|-------------|
-//! | `sub` | `Uuid` | Subject - the user ID |
+//! | `sub` | `UserId` | Subject - the user ID |
 //! | `role` | `RUserRole` | User's role for authorization |
 //! | `exp` | `usize` | Expiration time (Unix timestamp) |
 //! | `iat` | `Option<usize>` | Issued at time (optional) |
+//! | `aud` | `Option<Audience>` | Intended audience(s) (optional) |
+//!
+//! Tokens from external identity providers may carry non-UUID subjects
+//! (numeric IDs, emails). Decode those into [`RawClaims`] and convert
+//! with [`Claims::try_from_raw`].
 //!
 //! # Usage
 //!
@@ -60,10 +65,28 @@
 //! - Use [`is_expired()`](Claims::is_expired) to check expiration
 //! - Store sensitive data in the database, not in claims
 
-use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[cfg(feature = "jwt-peek")]
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use masterror::{AppError, AppResult};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(any(feature = "arbitrary", feature = "jwt-peek"))]
 use uuid::Uuid;
 
-use crate::{Permissions, RUserRole, Role};
+use crate::{Permissions, RUserRole, Role, UserId, missing_permission};
+
+/// Current Unix time in seconds, as used by `exp` and `iat`.
+///
+/// Falls back to `0` if the system clock is before the Unix epoch.
+pub(crate) fn now_secs() -> usize {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as usize)
+        .unwrap_or(0)
+}
 
 /// JWT claims for authentication tokens.
 ///
@@ -114,7 +137,7 @@ pub struct Claims {
     /// Subject - the user's unique identifier.
     ///
     /// This corresponds to [`RUser::id`](crate::RUser::id).
-    pub sub: Uuid,
+    pub sub: UserId,
 
     /// User's role for authorization.
     ///
@@ -132,6 +155,13 @@ pub struct Claims {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub iat: Option<usize>,
 
+    /// Audience - the recipients the token is intended for (optional).
+    ///
+    /// May be a single string or an array in JSON; check membership with
+    /// [`has_audience()`](Claims::has_audience).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aud: Option<Audience>,
+
     /// Custom permissions (optional).
     ///
     /// When set, these permissions override the role's default permissions.
@@ -142,6 +172,35 @@ pub struct Claims {
     pub permissions: Option<Permissions>
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Claims {
+    /// Generates claims with `iat <= exp` and only defined permission bits.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let exp = u.int_in_range(0..=u32::MAX as usize)?;
+        let iat = if u.arbitrary()? {
+            Some(u.int_in_range(0..=exp)?)
+        } else {
+            None
+        };
+        let aud = if u.arbitrary()? {
+            Some(Audience::Single(
+                (*u.choose(&["web", "mobile", "admin"])?).to_owned()
+            ))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            sub: Uuid::from_u128(u.arbitrary()?).into(),
+            role: *u.choose(&RUserRole::all())?,
+            exp,
+            iat,
+            aud,
+            permissions: u.arbitrary()?
+        })
+    }
+}
+
 impl Claims {
     /// Create new claims for a JWT token.
     ///
@@ -171,12 +230,13 @@ impl Claims {
     /// let claims = Claims::new(user_id, RUserRole::User, exp);
     /// ```
     #[must_use]
-    pub fn new(sub: Uuid, role: RUserRole, exp: usize) -> Self {
+    pub fn new(sub: impl Into<UserId>, role: RUserRole, exp: usize) -> Self {
         Self {
-            sub,
+            sub: sub.into(),
             role,
             exp,
             iat: None,
+            aud: None,
             permissions: None
         }
     }
@@ -204,12 +264,13 @@ impl Claims {
     /// assert_eq!(claims.iat, Some(now));
     /// ```
     #[must_use]
-    pub fn with_iat(sub: Uuid, role: RUserRole, exp: usize, iat: usize) -> Self {
+    pub fn with_iat(sub: impl Into<UserId>, role: RUserRole, exp: usize, iat: usize) -> Self {
         Self {
-            sub,
+            sub: sub.into(),
             role,
             exp,
             iat: Some(iat),
+            aud: None,
             permissions: None
         }
     }
@@ -244,20 +305,48 @@ impl Claims {
     /// ```
     #[must_use]
     pub fn with_permissions(
-        sub: Uuid,
+        sub: impl Into<UserId>,
         role: RUserRole,
         exp: usize,
         permissions: Permissions
     ) -> Self {
         Self {
-            sub,
+            sub: sub.into(),
             role,
             exp,
             iat: None,
+            aud: None,
             permissions: Some(permissions)
         }
     }
 
+    /// Create claims with the role's permissions baked in.
+    ///
+    /// Sets `permissions` to `role.permissions()` at construction, so the
+    /// token carries explicit permissions and verifiers need not know the
+    /// role mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `sub` - The user's unique identifier
+    /// * `role` - The user's role
+    /// * `exp` - Expiration time as Unix timestamp
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Claims, RUserRole, Role};
+    /// use uuid::Uuid;
+    ///
+    /// let claims = Claims::new_materialized(Uuid::now_v7(), RUserRole::Premium, usize::MAX);
+    ///
+    /// assert_eq!(claims.permissions, Some(RUserRole::Premium.permissions()));
+    /// ```
+    #[must_use]
+    pub fn new_materialized(sub: impl Into<UserId>, role: RUserRole, exp: usize) -> Self {
+        Self::with_permissions(sub, role, exp, role.permissions())
+    }
+
     /// Get the user ID from claims.
     ///
     /// This is a convenience method that returns the `sub` claim,
@@ -275,7 +364,7 @@ impl Claims {
     /// assert_eq!(claims.user_id(), id);
     /// ```
     #[must_use]
-    pub fn user_id(&self) -> Uuid {
+    pub const fn user_id(&self) -> UserId {
         self.sub
     }
 
@@ -304,14 +393,33 @@ impl Claims {
     /// For high-security scenarios, use server-side time validation.
     #[must_use]
     pub fn is_expired(&self) -> bool {
-        use std::time::{SystemTime, UNIX_EPOCH};
-
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map(|d| d.as_secs() as usize)
-            .unwrap_or(0);
+        self.is_expired_at(now_secs())
+    }
 
-        self.exp < now
+    /// Check if the claims have expired at the given time.
+    ///
+    /// A token is still valid during the second named by `exp` and
+    /// expires strictly after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `now_secs` - Reference time as Unix timestamp
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Claims, RUserRole};
+    /// use uuid::Uuid;
+    ///
+    /// let claims = Claims::new(Uuid::nil(), RUserRole::User, 1000);
+    ///
+    /// assert!(!claims.is_expired_at(999));
+    /// assert!(!claims.is_expired_at(1000));
+    /// assert!(claims.is_expired_at(1001));
+    /// ```
+    #[must_use]
+    pub const fn is_expired_at(&self, now_secs: usize) -> bool {
+        self.exp < now_secs
     }
 
     /// Check if the user has admin role.
@@ -447,18 +555,752 @@ impl Claims {
     pub fn can_any(&self, permissions: Permissions) -> bool {
         self.effective_permissions().intersects(permissions)
     }
+
+    /// Require a permission, failing with 403 if it is missing.
+    ///
+    /// Function form of [`require_permission!`](crate::require_permission).
+    ///
+    /// # Errors
+    ///
+    /// Returns a forbidden [`AppError`] naming the missing permissions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use masterror::AppErrorKind;
+    /// use revelation_user::{Claims, Permissions, RUserRole};
+    /// use uuid::Uuid;
+    ///
+    /// let user = Claims::new(Uuid::now_v7(), RUserRole::User, 0);
+    /// assert!(user.require(Permissions::READ).is_ok());
+    ///
+    /// let err = user.require(Permissions::DELETE).unwrap_err();
+    /// assert_eq!(err.kind, AppErrorKind::Forbidden);
+    /// ```
+    pub fn require(&self, permission: Permissions) -> AppResult<()> {
+        let granted = self.effective_permissions();
+        if granted.contains(permission) {
+            Ok(())
+        } else {
+            Err(missing_permission(permission, granted))
+        }
+    }
+
+    /// Require at least the given role, failing with 403 otherwise.
+    ///
+    /// Compares roles by privilege (`User < Premium < Admin`), so
+    /// `require_role(RUserRole::Premium)` admits Premium and Admin.
+    ///
+    /// # Errors
+    ///
+    /// Returns a forbidden [`AppError`] naming the required role.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Claims, RUserRole};
+    /// use uuid::Uuid;
+    ///
+    /// let admin = Claims::new(Uuid::now_v7(), RUserRole::Admin, 0);
+    /// assert!(admin.require_role(RUserRole::Premium).is_ok());
+    ///
+    /// let user = Claims::new(Uuid::now_v7(), RUserRole::User, 0);
+    /// assert!(user.require_role(RUserRole::Premium).is_err());
+    /// ```
+    pub fn require_role(&self, at_least: RUserRole) -> AppResult<()> {
+        if self.role >= at_least {
+            Ok(())
+        } else {
+            Err(AppError::forbidden(format!("Requires role: {at_least}")))
+        }
+    }
+
+    /// Check if the token is intended for the given audience.
+    ///
+    /// Returns `false` when the token has no `aud` claim.
+    ///
+    /// # Arguments
+    ///
+    /// * `audience` - Audience identifier of the current service
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Audience, Claims, RUserRole};
+    /// use uuid::Uuid;
+    ///
+    /// let mut claims = Claims::new(Uuid::nil(), RUserRole::User, 0);
+    /// claims.aud = Some(Audience::from(["web", "mobile"]));
+    ///
+    /// assert!(claims.has_audience("mobile"));
+    /// assert!(!claims.has_audience("admin"));
+    /// ```
+    #[must_use]
+    pub fn has_audience(&self, audience: &str) -> bool {
+        self.aud.as_ref().is_some_and(|aud| aud.contains(audience))
+    }
+}
+
+/// JWT `aud` claim: a single audience or a list of audiences.
+///
+/// RFC 7519 allows either form; both deserialize into this type and
+/// serialize back in the same shape.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::Audience;
+///
+/// let single: Audience = serde_json::from_str(r#""web""#).unwrap();
+/// assert!(single.contains("web"));
+///
+/// let many: Audience = serde_json::from_str(r#"["web","mobile"]"#).unwrap();
+/// assert!(many.contains("mobile"));
+/// assert_eq!(serde_json::to_string(&many).unwrap(), r#"["web","mobile"]"#);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Audience {
+    /// Single audience, serialized as a string.
+    Single(String),
+
+    /// Multiple audiences, serialized as an array.
+    Multiple(Vec<String>)
+}
+
+impl Audience {
+    /// Check if `audience` is one of the listed audiences.
+    #[must_use]
+    pub fn contains(&self, audience: &str) -> bool {
+        self.iter().any(|aud| aud == audience)
+    }
+
+    /// Iterate over all listed audiences.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        let slice = match self {
+            Self::Single(aud) => core::slice::from_ref(aud),
+            Self::Multiple(auds) => auds.as_slice()
+        };
+        slice.iter().map(String::as_str)
+    }
+}
+
+impl From<String> for Audience {
+    fn from(aud: String) -> Self {
+        Self::Single(aud)
+    }
+}
+
+impl From<&str> for Audience {
+    fn from(aud: &str) -> Self {
+        Self::Single(aud.to_owned())
+    }
+}
+
+impl From<Vec<String>> for Audience {
+    fn from(auds: Vec<String>) -> Self {
+        Self::Multiple(auds)
+    }
+}
+
+impl<const N: usize> From<[&str; N]> for Audience {
+    fn from(auds: [&str; N]) -> Self {
+        Self::Multiple(auds.iter().map(|aud| (*aud).to_owned()).collect())
+    }
+}
+
+impl Serialize for Audience {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        match self {
+            Self::Single(aud) => serializer.serialize_str(aud),
+            Self::Multiple(auds) => auds.serialize(serializer)
+        }
+    }
+}
+
+// Custom deserialization: accept a string or an array of strings
+impl<'de> Deserialize<'de> for Audience {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        struct AudienceVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for AudienceVisitor {
+            type Value = Audience;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a string or an array of strings")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error
+            {
+                Ok(Audience::Single(value.to_owned()))
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error
+            {
+                Ok(Audience::Single(value))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>
+            {
+                let mut auds = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(aud) = seq.next_element::<String>()? {
+                    auds.push(aud);
+                }
+                Ok(Audience::Multiple(auds))
+            }
+        }
+
+        deserializer.deserialize_any(AudienceVisitor)
+    }
+}
+
+/// JWT claims with an untyped subject.
+///
+/// Mirrors [`Claims`] but keeps `sub` as a string, so tokens issued by
+/// external identity providers with numeric or email subjects can still
+/// be decoded. Convert to [`Claims`] with [`Claims::try_from_raw`] once
+/// the subject is known to be a user ID.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::RawClaims;
+///
+/// let json = r#"{"sub":"583231","role":"user","exp":0}"#;
+/// let raw: RawClaims = serde_json::from_str(json).unwrap();
+///
+/// assert_eq!(raw.sub, "583231");
+/// assert_eq!(raw.subject_as::<u64>().unwrap(), 583231);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawClaims {
+    /// Subject as issued by the token provider.
+    pub sub: String,
+
+    /// User's role for authorization.
+    pub role: RUserRole,
+
+    /// Expiration time as Unix timestamp (seconds since epoch).
+    pub exp: usize,
+
+    /// Issued at time as Unix timestamp (optional).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iat: Option<usize>,
+
+    /// Intended audience(s) (optional).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aud: Option<Audience>,
+
+    /// Custom permissions (optional).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permissions>
+}
+
+impl RawClaims {
+    /// Parse the subject into any [`FromStr`] type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `T::Err` if the subject cannot be parsed as `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{RUserRole, RawClaims};
+    ///
+    /// let raw = RawClaims {
+    ///     sub:         "42".into(),
+    ///     role:        RUserRole::User,
+    ///     exp:         0,
+    ///     iat:         None,
+    ///     aud:         None,
+    ///     permissions: None
+    /// };
+    ///
+    /// assert_eq!(raw.subject_as::<i64>().unwrap(), 42);
+    /// ```
+    pub fn subject_as<T: FromStr>(&self) -> Result<T, T::Err> {
+        self.sub.parse()
+    }
+}
+
+#[cfg(feature = "jwt-peek")]
+impl Claims {
+    /// Read the `sub` claim of a JWT **without verifying its signature**.
+    ///
+    /// Base64url-decodes the payload segment and extracts `sub`, nothing
+    /// more. Meant for hot paths such as rate limiting by user, where the
+    /// token is fully validated later by a `JwtValidator`. Requires the
+    /// `jwt-peek` feature.
+    ///
+    /// # Security
+    ///
+    /// The result is unauthenticated: anyone can craft a token carrying
+    /// any subject. Never use it for authorization decisions, and never
+    /// treat it as proof of identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Raw JWT in compact `header.payload.signature` form
+    ///
+    /// # Returns
+    ///
+    /// `None` if the token is malformed or `sub` is missing or not a UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::Claims;
+    ///
+    /// // {"sub":"00000000-0000-0000-0000-000000000000"}
+    /// let token = "e30.eyJzdWIiOiIwMDAwMDAwMC0wMDAwLTAwMDAtMDAwMC0wMDAwMDAwMDAwMDAifQ.sig";
+    ///
+    /// assert!(Claims::peek_subject(token).unwrap().is_nil());
+    /// assert!(Claims::peek_subject("not-a-jwt").is_none());
+    /// ```
+    #[must_use]
+    pub fn peek_subject(token: &str) -> Option<Uuid> {
+        #[derive(Deserialize)]
+        struct Subject {
+            sub: Uuid
+        }
+
+        let mut segments = token.split('.');
+        let payload = match (
+            segments.next(),
+            segments.next(),
+            segments.next(),
+            segments.next()
+        ) {
+            (Some(_), Some(payload), Some(_), None) => payload,
+            _ => return None
+        };
+
+        let bytes = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+        serde_json::from_slice::<Subject>(&bytes)
+            .ok()
+            .map(|subject| subject.sub)
+    }
+}
+
+impl Claims {
+    /// Convert [`RawClaims`] into typed claims.
+    ///
+    /// Parses the subject as a [`UserId`]; all other claims are copied.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - Claims decoded with a string subject
+    ///
+    /// # Errors
+    ///
+    /// Returns an unauthorized [`AppError`] (HTTP 401) when the subject
+    /// is not a valid UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Claims, RawClaims};
+    ///
+    /// let json = r#"{"sub":"00000000-0000-0000-0000-000000000000","role":"admin","exp":0}"#;
+    /// let raw: RawClaims = serde_json::from_str(json).unwrap();
+    ///
+    /// let claims = Claims::try_from_raw(raw).unwrap();
+    /// assert!(claims.user_id().is_nil());
+    /// assert!(claims.is_admin());
+    /// ```
+    pub fn try_from_raw(raw: RawClaims) -> AppResult<Self> {
+        let sub = raw.subject_as::<UserId>().map_err(|_| {
+            AppError::unauthorized(format!("Token subject is not a valid UUID: {}", raw.sub))
+        })?;
+
+        Ok(Self {
+            sub,
+            role: raw.role,
+            exp: raw.exp,
+            iat: raw.iat,
+            aud: raw.aud,
+            permissions: raw.permissions
+        })
+    }
+}
+
+/// Size-optimized JWT claims with single-letter keys.
+///
+/// Same data as [`Claims`], serialized as `s` (subject), `r` (role),
+/// `e` (expiration), `i` (issued at), `a` (audience) and `p` (permissions).
+/// Issuers that need small tokens can encode this form; convert with
+/// `From`/`Into`.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{Claims, CompactClaims, RUserRole};
+/// use uuid::Uuid;
+///
+/// let claims = Claims::new(Uuid::nil(), RUserRole::Admin, 0);
+/// let json = serde_json::to_string(&CompactClaims::from(claims)).unwrap();
+///
+/// assert_eq!(
+///     json,
+///     r#"{"s":"00000000-0000-0000-0000-000000000000","r":"admin","e":0}"#
+/// );
+///
+/// let decoded: Claims = serde_json::from_str::<CompactClaims>(&json).unwrap().into();
+/// assert!(decoded.is_admin());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompactClaims {
+    /// Subject (user ID).
+    #[serde(rename = "s")]
+    pub sub: UserId,
+
+    /// User's role.
+    #[serde(rename = "r")]
+    pub role: RUserRole,
+
+    /// Expiration time as Unix timestamp.
+    #[serde(rename = "e")]
+    pub exp: usize,
+
+    /// Issued at time as Unix timestamp (optional).
+    #[serde(rename = "i", default, skip_serializing_if = "Option::is_none")]
+    pub iat: Option<usize>,
+
+    /// Intended audience(s) (optional).
+    #[serde(rename = "a", default, skip_serializing_if = "Option::is_none")]
+    pub aud: Option<Audience>,
+
+    /// Custom permissions (optional).
+    #[serde(rename = "p", default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permissions>
+}
+
+impl From<Claims> for CompactClaims {
+    fn from(claims: Claims) -> Self {
+        Self {
+            sub:         claims.sub,
+            role:        claims.role,
+            exp:         claims.exp,
+            iat:         claims.iat,
+            aud:         claims.aud,
+            permissions: claims.permissions
+        }
+    }
+}
+
+impl From<CompactClaims> for Claims {
+    fn from(compact: CompactClaims) -> Self {
+        Self {
+            sub:         compact.sub,
+            role:        compact.role,
+            exp:         compact.exp,
+            iat:         compact.iat,
+            aud:         compact.aud,
+            permissions: compact.permissions
+        }
+    }
+}
+
+/// JWT claims with RFC 3339 timestamps.
+///
+/// Same data as [`Claims`], but `exp` and `iat` serialize as RFC 3339
+/// strings (`2025-01-01T00:00:00Z`) for downstream systems that expect
+/// ISO timestamps. Deserialization accepts either strings or Unix
+/// numbers. The default [`Claims`] form stays numeric, as the JWT spec
+/// requires; convert with `From`/`Into`.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{Claims, ClaimsRfcExp, RUserRole};
+/// use uuid::Uuid;
+///
+/// let claims = Claims::new(Uuid::nil(), RUserRole::User, 1735689600);
+/// let json = serde_json::to_value(ClaimsRfcExp::from(claims)).unwrap();
+///
+/// assert_eq!(json["exp"], "2025-01-01T00:00:00Z");
+///
+/// let decoded: Claims = serde_json::from_value::<ClaimsRfcExp>(json).unwrap().into();
+/// assert_eq!(decoded.exp, 1735689600);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClaimsRfcExp {
+    /// Subject (user ID).
+    pub sub: UserId,
+
+    /// User's role.
+    pub role: RUserRole,
+
+    /// Expiration time, RFC 3339 in JSON.
+    #[serde(with = "unix_rfc3339")]
+    pub exp: usize,
+
+    /// Issued at time, RFC 3339 in JSON (optional).
+    #[serde(
+        with = "unix_rfc3339::option",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub iat: Option<usize>,
+
+    /// Intended audience(s) (optional).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aud: Option<Audience>,
+
+    /// Custom permissions (optional).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permissions>
+}
+
+impl From<Claims> for ClaimsRfcExp {
+    fn from(claims: Claims) -> Self {
+        Self {
+            sub:         claims.sub,
+            role:        claims.role,
+            exp:         claims.exp,
+            iat:         claims.iat,
+            aud:         claims.aud,
+            permissions: claims.permissions
+        }
+    }
+}
+
+impl From<ClaimsRfcExp> for Claims {
+    fn from(rfc: ClaimsRfcExp) -> Self {
+        Self {
+            sub:         rfc.sub,
+            role:        rfc.role,
+            exp:         rfc.exp,
+            iat:         rfc.iat,
+            aud:         rfc.aud,
+            permissions: rfc.permissions
+        }
+    }
+}
+
+/// Serde adapter for Unix-second timestamps written as RFC 3339.
+///
+/// Serializes a `usize` Unix timestamp as `YYYY-MM-DDTHH:MM:SSZ`.
+/// Deserialization accepts an RFC 3339 string (any offset, fractional
+/// seconds truncated) or a plain non-negative number. Use
+/// [`unix_rfc3339::option`](self::unix_rfc3339::option) for
+/// `Option<usize>` fields such as `iat` or `nbf`.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::unix_rfc3339;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Token {
+///     #[serde(with = "unix_rfc3339")]
+///     nbf: usize
+/// }
+///
+/// let json = serde_json::to_string(&Token {
+///     nbf: 0
+/// })
+/// .unwrap();
+/// assert_eq!(json, r#"{"nbf":"1970-01-01T00:00:00Z"}"#);
+///
+/// let token: Token = serde_json::from_str(r#"{"nbf":60}"#).unwrap();
+/// assert_eq!(token.nbf, 60);
+/// ```
+pub mod unix_rfc3339 {
+    use chrono::{DateTime, SecondsFormat};
+    use serde::{Deserializer, Serializer, de, ser};
+
+    /// Serialize a Unix timestamp as an RFC 3339 string.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the timestamp is outside the range `chrono` supports.
+    pub fn serialize<S>(secs: &usize, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let time = i64::try_from(*secs)
+            .ok()
+            .and_then(|secs| DateTime::from_timestamp(secs, 0))
+            .ok_or_else(|| ser::Error::custom(format!("timestamp out of range: {secs}")))?;
+
+        serializer.serialize_str(&time.to_rfc3339_opts(SecondsFormat::Secs, true))
+    }
+
+    /// Deserialize a Unix timestamp from an RFC 3339 string or a number.
+    ///
+    /// # Errors
+    ///
+    /// Fails on malformed strings, negative values and pre-epoch times.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<usize, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        deserializer.deserialize_any(UnixVisitor)
+    }
+
+    struct UnixVisitor;
+
+    impl de::Visitor<'_> for UnixVisitor {
+        type Value = usize;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("an RFC 3339 timestamp or Unix seconds")
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error
+        {
+            usize::try_from(value)
+                .map_err(|_| E::custom(format!("timestamp out of range: {value}")))
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error
+        {
+            if value < 0 {
+                return Err(E::custom("timestamp cannot be before the Unix epoch"));
+            }
+            self.visit_u64(value as u64)
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error
+        {
+            let secs = DateTime::parse_from_rfc3339(value)
+                .map_err(E::custom)?
+                .timestamp();
+            self.visit_i64(secs)
+        }
+    }
+
+    /// Same as the parent module, for `Option<usize>` fields.
+    ///
+    /// Pair with `#[serde(default, skip_serializing_if = "Option::is_none")]`.
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        /// Serialize an optional Unix timestamp as an RFC 3339 string.
+        ///
+        /// # Errors
+        ///
+        /// Fails if the timestamp is outside the range `chrono` supports.
+        pub fn serialize<S>(secs: &Option<usize>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer
+        {
+            match secs {
+                Some(secs) => super::serialize(secs, serializer),
+                None => serializer.serialize_none()
+            }
+        }
+
+        /// Deserialize an optional Unix timestamp from a string or number.
+        ///
+        /// # Errors
+        ///
+        /// Fails on malformed strings, negative values and pre-epoch times.
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<usize>, D::Error>
+        where
+            D: Deserializer<'de>
+        {
+            #[derive(Deserialize)]
+            struct Wrapper(#[serde(with = "super")] usize);
+
+            Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(secs)| secs))
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use masterror::AppErrorKind;
+    use uuid::Uuid;
+
     use super::*;
 
+    #[test]
+    fn require_allows_granted_permission() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::Premium, 0);
+
+        assert!(claims.require(Permissions::EXPORT).is_ok());
+    }
+
+    #[test]
+    fn require_denies_with_permission_name() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::User, 0);
+
+        let err = claims
+            .require(Permissions::READ | Permissions::DELETE)
+            .unwrap_err();
+        assert_eq!(err.kind, AppErrorKind::Forbidden);
+        assert_eq!(err.message.as_deref(), Some("Missing permission: delete"));
+    }
+
+    #[test]
+    fn require_role_across_all_combinations() {
+        use RUserRole::{Admin, Premium, User};
+
+        for (role, required, allowed) in [
+            (User, User, true),
+            (User, Premium, false),
+            (User, Admin, false),
+            (Premium, User, true),
+            (Premium, Premium, true),
+            (Premium, Admin, false),
+            (Admin, User, true),
+            (Admin, Premium, true),
+            (Admin, Admin, true)
+        ] {
+            let result = Claims::new(Uuid::nil(), role, 0).require_role(required);
+
+            match result {
+                Ok(()) => assert!(allowed, "{role} should not satisfy {required}"),
+                Err(err) => {
+                    assert!(!allowed, "{role} should satisfy {required}");
+                    assert_eq!(err.kind, AppErrorKind::Forbidden);
+                }
+            }
+        }
+    }
+
     #[test]
     fn new_creates_claims_without_iat() {
         let claims = Claims::new(Uuid::nil(), RUserRole::User, 1000);
         assert!(claims.iat.is_none());
     }
 
+    #[test]
+    fn new_materialized_bakes_in_role_permissions() {
+        let claims = Claims::new_materialized(Uuid::nil(), RUserRole::Admin, 1000);
+        let expected = RUserRole::Admin.permissions();
+
+        assert_eq!(claims.permissions, Some(expected));
+        assert_eq!(claims.effective_permissions(), expected);
+
+        let json = serde_json::to_value(&claims).unwrap();
+        assert_eq!(json["permissions"], expected.bits());
+
+        let decoded: Claims = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.effective_permissions(), expected);
+    }
+
     #[test]
     fn with_iat_sets_iat() {
         let claims = Claims::with_iat(Uuid::nil(), RUserRole::User, 1000, 500);
@@ -472,6 +1314,15 @@ mod tests {
         assert_eq!(claims.user_id(), id);
     }
 
+    #[test]
+    fn is_expired_at_boundary() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::User, 1000);
+
+        assert!(!claims.is_expired_at(1000), "exp == now is still valid");
+        assert!(claims.is_expired_at(1001), "exp < now is expired");
+        assert!(!claims.is_expired_at(999), "exp > now is valid");
+    }
+
     #[test]
     fn is_expired_returns_true_for_past() {
         let claims = Claims::new(Uuid::nil(), RUserRole::User, 0);
@@ -582,4 +1433,226 @@ mod tests {
         let json = serde_json::to_string(&claims).unwrap();
         assert!(json.contains("permissions"));
     }
+
+    #[test]
+    fn raw_claims_accept_numeric_subject() {
+        let raw: RawClaims =
+            serde_json::from_str(r#"{"sub":"583231","role":"premium","exp":100}"#).unwrap();
+
+        assert_eq!(raw.sub, "583231");
+        assert_eq!(raw.subject_as::<u64>().unwrap(), 583231);
+        assert_eq!(raw.role, RUserRole::Premium);
+    }
+
+    #[test]
+    fn try_from_raw_rejects_non_uuid_subject() {
+        let raw: RawClaims =
+            serde_json::from_str(r#"{"sub":"583231","role":"user","exp":0}"#).unwrap();
+
+        let err = Claims::try_from_raw(raw).unwrap_err();
+
+        assert_eq!(err.kind, AppErrorKind::Unauthorized);
+        assert!(
+            err.message
+                .as_deref()
+                .unwrap_or_default()
+                .contains("583231")
+        );
+    }
+
+    #[test]
+    fn try_from_raw_copies_claims() {
+        let id = Uuid::now_v7();
+        let raw = RawClaims {
+            sub:         id.to_string(),
+            role:        RUserRole::Admin,
+            exp:         1000,
+            iat:         Some(500),
+            aud:         None,
+            permissions: Some(Permissions::READ)
+        };
+
+        let claims = Claims::try_from_raw(raw).unwrap();
+
+        assert_eq!(claims.sub, id);
+        assert_eq!(claims.role, RUserRole::Admin);
+        assert_eq!(claims.exp, 1000);
+        assert_eq!(claims.iat, Some(500));
+        assert_eq!(claims.permissions, Some(Permissions::READ));
+    }
+
+    #[test]
+    fn compact_claims_roundtrip_preserves_fields() {
+        let claims = Claims {
+            sub:         UserId::new(),
+            role:        RUserRole::Premium,
+            exp:         2000,
+            iat:         Some(1000),
+            aud:         Some(Audience::from(["web", "mobile"])),
+            permissions: Some(Permissions::READ | Permissions::EXPORT)
+        };
+
+        let json = serde_json::to_string(&CompactClaims::from(claims.clone())).unwrap();
+        let decoded: Claims = serde_json::from_str::<CompactClaims>(&json).unwrap().into();
+
+        assert_eq!(decoded.sub, claims.sub);
+        assert_eq!(decoded.role, claims.role);
+        assert_eq!(decoded.exp, claims.exp);
+        assert_eq!(decoded.iat, claims.iat);
+        assert_eq!(decoded.aud, claims.aud);
+        assert_eq!(decoded.permissions, claims.permissions);
+    }
+
+    #[test]
+    fn audience_deserializes_from_string() {
+        let json = serde_json::json!({
+            "sub": Uuid::nil(),
+            "role": "user",
+            "exp": 0,
+            "aud": "web"
+        });
+        let claims: Claims = serde_json::from_value(json).unwrap();
+
+        assert_eq!(claims.aud, Some(Audience::Single("web".into())));
+        assert!(claims.has_audience("web"));
+        assert!(!claims.has_audience("mobile"));
+    }
+
+    #[test]
+    fn audience_deserializes_from_array() {
+        let json = serde_json::json!({
+            "sub": Uuid::nil(),
+            "role": "user",
+            "exp": 0,
+            "aud": ["web", "mobile"]
+        });
+        let claims: Claims = serde_json::from_value(json).unwrap();
+
+        assert_eq!(claims.aud, Some(Audience::from(["web", "mobile"])));
+        assert!(claims.has_audience("web"));
+        assert!(claims.has_audience("mobile"));
+        assert!(!claims.has_audience("admin"));
+    }
+
+    #[test]
+    fn audience_missing_matches_nothing() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::User, 0);
+
+        assert!(claims.aud.is_none());
+        assert!(!claims.has_audience("web"));
+        assert!(!serde_json::to_string(&claims).unwrap().contains("aud"));
+    }
+
+    #[test]
+    fn audience_serializes_in_original_shape() {
+        let single = serde_json::to_string(&Audience::from("web")).unwrap();
+        assert_eq!(single, r#""web""#);
+
+        let many = serde_json::to_string(&Audience::from(["web", "mobile"])).unwrap();
+        assert_eq!(many, r#"["web","mobile"]"#);
+
+        assert!(serde_json::from_str::<Audience>("42").is_err());
+    }
+
+    #[test]
+    fn rfc_claims_roundtrip_from_string_and_number() {
+        let claims = Claims::with_iat(Uuid::nil(), RUserRole::User, 1735689600, 1735686000);
+
+        let json = serde_json::to_value(ClaimsRfcExp::from(claims.clone())).unwrap();
+        assert_eq!(json["exp"], "2025-01-01T00:00:00Z");
+        assert_eq!(json["iat"], "2024-12-31T23:00:00Z");
+
+        let from_string: Claims = serde_json::from_value::<ClaimsRfcExp>(json).unwrap().into();
+        assert_eq!(from_string.exp, claims.exp);
+        assert_eq!(from_string.iat, claims.iat);
+
+        let numeric = serde_json::to_value(&claims).unwrap();
+        assert_eq!(numeric["exp"], 1735689600);
+
+        let from_number: Claims = serde_json::from_value::<ClaimsRfcExp>(numeric)
+            .unwrap()
+            .into();
+        assert_eq!(from_number.exp, claims.exp);
+        assert_eq!(from_number.iat, claims.iat);
+    }
+
+    #[test]
+    fn rfc_claims_accept_offsets_and_skip_missing_iat() {
+        let json = serde_json::json!({
+            "sub": Uuid::nil(),
+            "role": "user",
+            "exp": "2025-01-01T03:00:00+03:00"
+        });
+
+        let rfc: ClaimsRfcExp = serde_json::from_value(json).unwrap();
+        assert_eq!(rfc.exp, 1735689600);
+        assert!(rfc.iat.is_none());
+
+        let out = serde_json::to_string(&rfc).unwrap();
+        assert!(!out.contains("iat"));
+    }
+
+    #[test]
+    fn rfc_claims_reject_invalid_timestamps() {
+        for exp in [serde_json::json!("yesterday"), serde_json::json!(-1)] {
+            let json = serde_json::json!({"sub": Uuid::nil(), "role": "user", "exp": exp});
+            assert!(serde_json::from_value::<ClaimsRfcExp>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn compact_claims_use_short_keys() {
+        let claims = Claims::with_permissions(Uuid::nil(), RUserRole::User, 10, Permissions::READ);
+        let json = serde_json::to_string(&CompactClaims::from(claims)).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"s":"00000000-0000-0000-0000-000000000000","r":"user","e":10,"p":1}"#
+        );
+    }
+
+    #[cfg(feature = "jwt-peek")]
+    fn crafted_token(payload: &serde_json::Value) -> String {
+        let payload = URL_SAFE_NO_PAD.encode(payload.to_string());
+        format!("eyJhbGciOiJIUzI1NiJ9.{payload}.not-a-real-signature")
+    }
+
+    #[test]
+    #[cfg(feature = "jwt-peek")]
+    fn peek_subject_reads_unverified_payload() {
+        let id = Uuid::now_v7();
+        let claims = Claims::new(id, RUserRole::Admin, 0);
+        let token = crafted_token(&serde_json::to_value(&claims).unwrap());
+
+        assert_eq!(Claims::peek_subject(&token), Some(id));
+    }
+
+    #[test]
+    #[cfg(feature = "jwt-peek")]
+    fn peek_subject_accepts_padded_payload() {
+        let id = Uuid::nil();
+        let payload = base64::engine::general_purpose::URL_SAFE
+            .encode(serde_json::json!({"sub": id}).to_string());
+        let token = format!("h.{payload}.s");
+
+        assert_eq!(Claims::peek_subject(&token), Some(id));
+    }
+
+    #[test]
+    #[cfg(feature = "jwt-peek")]
+    fn peek_subject_rejects_malformed_tokens() {
+        let valid = crafted_token(&serde_json::json!({"sub": Uuid::nil()}));
+
+        assert!(Claims::peek_subject("").is_none());
+        assert!(Claims::peek_subject("only.two").is_none());
+        assert!(Claims::peek_subject(&format!("{valid}.extra")).is_none());
+        assert!(Claims::peek_subject("h.!!!.s").is_none());
+
+        for payload in [
+            serde_json::json!({"sub": "42"}),
+            serde_json::json!({"role": "user"})
+        ] {
+            assert!(Claims::peek_subject(&crafted_token(&payload)).is_none());
+        }
+    }
 }
@@ -11,11 +11,15 @@
 //! The entity module provides:
 //!
 //! - [`RUser`] - The core user aggregate
+//! - [`CreateRUserRequest`] - DTO for user creation
 //! - [`Claims`] - JWT claims for authentication tokens
+//! - [`RawClaims`] - JWT claims with an untyped subject for foreign tokens
+//! - [`CompactClaims`] - JWT claims with short keys for small tokens
+//! - [`Audience`] - JWT `aud` claim, a single string or a list
+//! - [`ClaimsRfcExp`] - JWT claims with RFC 3339 `exp`/`iat` for ISO consumers
 //!
 //! # Generated Types (via entity-derive)
 //!
-//! - [`CreateRUserRequest`] - DTO for user creation
 //! - [`UpdateRUserRequest`] - DTO for profile updates
 //! - [`RUserResponse`] - DTO for API responses
 //!
@@ -10,13 +10,15 @@
 //! # Generated Types
 //!
 //! The `Entity` derive macro generates:
-//! - [`CreateRUserRequest`] - DTO for user creation
 //! - [`UpdateRUserRequest`] - DTO for profile updates
 //! - [`RUserResponse`] - DTO for API responses
 //! - [`RUserRow`] - Database row mapping
 //! - [`InsertableRUser`] - For INSERT operations
 //! - [`RUserRepository`] - Async CRUD trait
 //!
+//! [`CreateRUserRequest`], the DTO for user creation, is written by hand
+//! so new users get a fresh [`UserId`] and an initial `version`.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -32,12 +34,19 @@
 //! let user = RUser::empty();
 //! ```
 
-use chrono::{DateTime, NaiveDate, Utc};
+use std::{collections::BTreeMap, time::Duration};
+
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
 use entity_derive::Entity;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use validator::{Validate, ValidationErrors};
 
-use crate::Gender;
+use super::claims::now_secs;
+use crate::{
+    Claims, ConfessionId, CreateUserRequest, Gender, NotificationPrefs, Patch,
+    PatchProfileRequest, RUserAdmin, RUserAuth, RUserRole, RedactedUser, UpdateProfileRequest
+};
 
 /// Core user entity for the Revelation ecosystem.
 ///
@@ -48,22 +57,32 @@ use crate::Gender;
 ///
 /// | Field | Type | Create | Update | Response |
 /// |-------|------|--------|--------|----------|
-/// | `id` | `Uuid` | — | — | Yes |
+/// | `id` | `UserId` | — | — | Yes |
 /// | `name` | `Option<String>` | — | Yes | Yes |
 /// | `gender` | `Option<Gender>` | — | Yes | Yes |
 /// | `birth_date` | `Option<NaiveDate>` | — | Yes | Yes |
-/// | `confession_id` | `Option<Uuid>` | — | Yes | Yes |
+/// | `confession_id` | `Option<ConfessionId>` | — | Yes | Yes |
 /// | `email` | `Option<String>` | Yes | — | Yes |
 /// | `phone` | `Option<String>` | Yes | — | Yes |
 /// | `telegram_id` | `Option<i64>` | Yes | — | Yes |
+/// | `email_verified` | `bool` | — | — | Yes |
+/// | `phone_verified` | `bool` | — | — | Yes |
+/// | `avatar_url` | `Option<String>` | — | Yes | Yes |
+/// | `external_ids` | `BTreeMap<String, String>` | — | — | Yes |
+/// | `signup_source` | `SignupSource` | — | — | Yes |
+/// | `metadata` | `serde_json::Value` | — | — | Yes |
 /// | `created_at` | `DateTime<Utc>` | — | — | Yes |
 /// | `updated_at` | `DateTime<Utc>` | — | — | Yes |
-#[derive(Debug, Clone, Serialize, Deserialize, Entity)]
+/// | `deleted_at` | `Option<DateTime<Utc>>` | — | — | Yes |
+/// | `version` | `i64` | — | — | Yes |
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Entity)]
 #[entity(table = "users", schema = "public", sql = "none")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
 pub struct RUser {
     /// Unique user identifier (UUIDv7).
     #[id]
-    pub id: Uuid,
+    pub id: UserId,
 
     /// Display name (2-100 chars).
     #[field(update, response)]
@@ -79,29 +98,102 @@ pub struct RUser {
 
     /// Reference to confession/denomination.
     #[field(update, response)]
-    pub confession_id: Option<Uuid>,
+    pub confession_id: Option<ConfessionId>,
 
-    /// Verified email address.
-    #[field(create, response)]
+    /// Email address; see `email_verified`.
+    #[field(response)]
     pub email: Option<String>,
 
     /// Phone number in E.164 format.
-    #[field(create, response)]
+    #[field(response)]
     pub phone: Option<String>,
 
     /// Telegram user ID.
-    #[field(create, response)]
+    ///
+    /// Accepts numeric strings on input, see [`telegram_id`].
+    #[field(response)]
+    #[serde(default, with = "telegram_id::option")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<i64>"))]
     pub telegram_id: Option<i64>,
 
-    /// Creation timestamp.
+    /// Whether the user proved control of `email`.
+    ///
+    /// Set by [`RUser::mark_email_verified`].
+    #[field(response)]
+    #[serde(default)]
+    pub email_verified: bool,
+
+    /// Whether the user proved control of `phone`.
+    ///
+    /// Set by [`RUser::mark_phone_verified`].
+    #[field(response)]
+    #[serde(default)]
+    pub phone_verified: bool,
+
+    /// Profile picture URL (e.g., from an OAuth provider).
+    #[field(update, response)]
+    pub avatar_url: Option<String>,
+
+    /// Notification channels the user accepts.
+    #[field(update, response)]
+    #[serde(default)]
+    pub notification_prefs: NotificationPrefs,
+
+    /// Subjects from external identity providers (provider → subject).
+    ///
+    /// Use [`RUser::link_external`] and [`RUser::external_id`].
+    #[field(response)]
+    #[serde(default)]
+    pub external_ids: BTreeMap<String, String>,
+
+    /// Channel the user originally signed up through.
+    ///
+    /// Set by the `from_*` constructors and kept when more methods are
+    /// linked later.
+    #[field(response)]
+    #[serde(default)]
+    pub signup_source: SignupSource,
+
+    /// Application-defined key-value data.
+    ///
+    /// `null` until the first [`RUser::set_meta`]; then a JSON object.
+    #[field(response)]
+    #[serde(default)]
+    pub metadata: serde_json::Value,
+
+    /// Creation timestamp (RFC 3339 with milliseconds in JSON).
     #[field(response)]
     #[auto]
+    #[serde(with = "rfc3339_millis")]
+    #[cfg_attr(feature = "schemars", schemars(with = "DateTime<Utc>"))]
     pub created_at: DateTime<Utc>,
 
-    /// Last update timestamp.
+    /// Last update timestamp (RFC 3339 with milliseconds in JSON).
     #[field(response)]
     #[auto]
-    pub updated_at: DateTime<Utc>
+    #[serde(with = "rfc3339_millis")]
+    #[cfg_attr(feature = "schemars", schemars(with = "DateTime<Utc>"))]
+    pub updated_at: DateTime<Utc>,
+
+    /// Soft-deletion timestamp.
+    ///
+    /// `None` for active users. Set by [`RUser::soft_delete`].
+    #[field(response)]
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>,
+
+    /// Optimistic concurrency version, starting at 1.
+    ///
+    /// Incremented by [`RUser::touch`] and [`RUser::apply_update`], so
+    /// repositories can update with `WHERE id = ? AND version = ?`.
+    #[field(response)]
+    #[serde(default = "initial_version")]
+    pub version: i64
+}
+
+/// Version of a freshly created user.
+const fn initial_version() -> i64 {
+    1
 }
 
 impl RUser {
@@ -118,21 +210,17 @@ impl RUser {
     #[must_use]
     pub fn from_telegram(telegram_id: i64) -> Self {
         Self {
-            id:            Uuid::now_v7(),
-            name:          None,
-            gender:        None,
-            birth_date:    None,
-            confession_id: None,
-            email:         None,
-            phone:         None,
-            telegram_id:   Some(telegram_id),
-            created_at:    Utc::now(),
-            updated_at:    Utc::now()
+            telegram_id: Some(telegram_id),
+            signup_source: SignupSource::Telegram,
+            ..Self::empty()
         }
     }
 
     /// Create user from email authentication.
     ///
+    /// Signing in with the email proves control of it, so the email is
+    /// marked verified.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -140,25 +228,71 @@ impl RUser {
     ///
     /// let user = RUser::from_email("user@example.com");
     /// assert_eq!(user.email.as_deref(), Some("user@example.com"));
+    /// assert!(user.email_verified);
     /// ```
     #[must_use]
     pub fn from_email(email: impl Into<String>) -> Self {
         Self {
-            id:            Uuid::now_v7(),
-            name:          None,
-            gender:        None,
-            birth_date:    None,
-            confession_id: None,
-            email:         Some(email.into()),
-            phone:         None,
-            telegram_id:   None,
-            created_at:    Utc::now(),
-            updated_at:    Utc::now()
+            email: Some(email.into()),
+            email_verified: true,
+            signup_source: SignupSource::Email,
+            ..Self::empty()
+        }
+    }
+
+    /// Create user from Telegram authentication with a display name.
+    ///
+    /// # Arguments
+    ///
+    /// * `telegram_id` - Telegram user ID
+    /// * `name` - Display name from the Telegram login payload
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let user = RUser::from_telegram_with_name(123456789, "John");
+    /// assert_eq!(user.telegram_id, Some(123456789));
+    /// assert_eq!(user.name.as_deref(), Some("John"));
+    /// ```
+    #[must_use]
+    pub fn from_telegram_with_name(telegram_id: i64, name: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Self::from_telegram(telegram_id)
+        }
+    }
+
+    /// Create user from email authentication with a display name.
+    ///
+    /// # Arguments
+    ///
+    /// * `email` - Email address
+    /// * `name` - Display name
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let user = RUser::from_email_with_name("john@example.com", "John");
+    /// assert_eq!(user.email.as_deref(), Some("john@example.com"));
+    /// assert_eq!(user.name.as_deref(), Some("John"));
+    /// ```
+    #[must_use]
+    pub fn from_email_with_name(email: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..Self::from_email(email)
         }
     }
 
     /// Create user from phone authentication.
     ///
+    /// Signing in with the phone proves control of it, so the phone is
+    /// marked verified.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -166,20 +300,15 @@ impl RUser {
     ///
     /// let user = RUser::from_phone("+79991234567");
     /// assert!(user.phone.is_some());
+    /// assert!(user.phone_verified);
     /// ```
     #[must_use]
     pub fn from_phone(phone: impl Into<String>) -> Self {
         Self {
-            id:            Uuid::now_v7(),
-            name:          None,
-            gender:        None,
-            birth_date:    None,
-            confession_id: None,
-            email:         None,
-            phone:         Some(phone.into()),
-            telegram_id:   None,
-            created_at:    Utc::now(),
-            updated_at:    Utc::now()
+            phone: Some(phone.into()),
+            phone_verified: true,
+            signup_source: SignupSource::Phone,
+            ..Self::empty()
         }
     }
 
@@ -195,17 +324,28 @@ impl RUser {
     /// ```
     #[must_use]
     pub fn empty() -> Self {
+        let now = Utc::now();
+
         Self {
-            id:            Uuid::now_v7(),
-            name:          None,
-            gender:        None,
-            birth_date:    None,
-            confession_id: None,
-            email:         None,
-            phone:         None,
-            telegram_id:   None,
-            created_at:    Utc::now(),
-            updated_at:    Utc::now()
+            id:                 UserId::new(),
+            name:               None,
+            gender:             None,
+            birth_date:         None,
+            confession_id:      None,
+            email:              None,
+            phone:              None,
+            telegram_id:        None,
+            email_verified:     false,
+            phone_verified:     false,
+            avatar_url:         None,
+            notification_prefs: NotificationPrefs::default(),
+            external_ids:       BTreeMap::new(),
+            signup_source:      SignupSource::Unknown,
+            metadata:           serde_json::Value::Null,
+            created_at:         now,
+            updated_at:         now,
+            deleted_at:         None,
+            version:            initial_version()
         }
     }
 
@@ -222,65 +362,2887 @@ impl RUser {
     /// assert_eq!(user.id, id);
     /// ```
     #[must_use]
-    pub fn with_id(id: Uuid) -> Self {
+    pub fn with_id(id: impl Into<UserId>) -> Self {
+        Self {
+            id: id.into(),
+            ..Self::empty()
+        }
+    }
+
+    /// Restore a user with its original id, timestamps and version.
+    ///
+    /// Unlike [`RUser::empty`] and the `from_*` constructors, this does
+    /// not stamp `created_at`/`updated_at` with the current time or
+    /// reset `version`, so audit data and the optimistic-concurrency
+    /// token survive a round trip through storage. Remaining fields
+    /// start empty and can be filled in afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Stored user identifier
+    /// * `created_at` - Original creation timestamp
+    /// * `updated_at` - Last modification timestamp
+    /// * `version` - Stored version
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use revelation_user::{RUser, UserId};
+    ///
+    /// let created = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    /// let user = RUser::rehydrate(UserId::new(), created, created, 7);
+    ///
+    /// assert_eq!(user.created_at, created);
+    /// assert_eq!(user.version, 7);
+    /// ```
+    #[must_use]
+    pub fn rehydrate(
+        id: impl Into<UserId>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+        version: i64
+    ) -> Self {
+        Self {
+            id: id.into(),
+            created_at,
+            updated_at,
+            version,
+            ..Self::empty()
+        }
+    }
+
+    /// Assemble a user from every field, e.g. in a hand-written repository.
+    ///
+    /// Arguments follow the field order of [`RUser`]. Prefer this over a
+    /// struct literal when mapping database rows without `entity-derive`:
+    /// adding a field to [`RUser`] then fails to compile at the call site
+    /// instead of silently picking up a default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    ///
+    /// use chrono::Utc;
+    /// use revelation_user::{NotificationPrefs, RUser, SignupSource, UserId};
+    ///
+    /// let now = Utc::now();
+    /// let user = RUser::from_parts(
+    ///     UserId::new(),
+    ///     Some("Alice".into()),
+    ///     None,
+    ///     None,
+    ///     None,
+    ///     Some("alice@example.com".into()),
+    ///     None,
+    ///     None,
+    ///     true,
+    ///     false,
+    ///     None,
+    ///     NotificationPrefs::default(),
+    ///     BTreeMap::new(),
+    ///     SignupSource::Email,
+    ///     serde_json::Value::Null,
+    ///     now,
+    ///     now,
+    ///     None,
+    ///     1
+    /// );
+    ///
+    /// assert_eq!(user.name.as_deref(), Some("Alice"));
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub const fn from_parts(
+        id: UserId,
+        name: Option<String>,
+        gender: Option<Gender>,
+        birth_date: Option<NaiveDate>,
+        confession_id: Option<ConfessionId>,
+        email: Option<String>,
+        phone: Option<String>,
+        telegram_id: Option<i64>,
+        email_verified: bool,
+        phone_verified: bool,
+        avatar_url: Option<String>,
+        notification_prefs: NotificationPrefs,
+        external_ids: BTreeMap<String, String>,
+        signup_source: SignupSource,
+        metadata: serde_json::Value,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+        deleted_at: Option<DateTime<Utc>>,
+        version: i64
+    ) -> Self {
         Self {
             id,
-            name: None,
-            gender: None,
-            birth_date: None,
-            confession_id: None,
-            email: None,
-            phone: None,
-            telegram_id: None,
-            created_at: Utc::now(),
-            updated_at: Utc::now()
+            name,
+            gender,
+            birth_date,
+            confession_id,
+            email,
+            phone,
+            telegram_id,
+            email_verified,
+            phone_verified,
+            avatar_url,
+            notification_prefs,
+            external_ids,
+            signup_source,
+            metadata,
+            created_at,
+            updated_at,
+            deleted_at,
+            version
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Pair the user with its auth projection for the given role.
+    ///
+    /// Handy right after creating a user, when a token is minted for it.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - Authorization role for the auth projection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{RUser, RUserRole};
+    ///
+    /// let (user, auth) = RUser::from_telegram(123456789).with_role(RUserRole::Premium);
+    ///
+    /// assert_eq!(auth.id, user.id);
+    /// assert!(auth.role.is_premium());
+    /// ```
+    #[must_use]
+    pub fn with_role(self, role: RUserRole) -> (Self, RUserAuth) {
+        let auth = RUserAuth::from_user(&self, role);
+        (self, auth)
+    }
 
-    #[test]
-    fn from_telegram_sets_telegram_id() {
-        let user = RUser::from_telegram(123);
-        assert_eq!(user.telegram_id, Some(123));
-        assert!(user.email.is_none());
+    /// Build JWT claims for this user.
+    ///
+    /// Sets `sub` to the user id, `iat` to the current time and `exp` to
+    /// `iat + ttl`.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - Authorization role embedded in the token
+    /// * `ttl` - Token lifetime
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use revelation_user::{RUser, RUserRole};
+    ///
+    /// let user = RUser::from_telegram(123456789);
+    /// let claims = user.to_claims(RUserRole::User, Duration::from_secs(3600));
+    ///
+    /// assert_eq!(claims.user_id(), user.id);
+    /// assert!(!claims.is_expired());
+    /// ```
+    #[must_use]
+    pub fn to_claims(&self, role: RUserRole, ttl: Duration) -> Claims {
+        let now = now_secs();
+        let exp = now.saturating_add(usize::try_from(ttl.as_secs()).unwrap_or(usize::MAX));
+
+        Claims::with_iat(self.id, role, exp, now)
     }
 
-    #[test]
-    fn from_email_sets_email() {
-        let user = RUser::from_email("test@example.com");
-        assert_eq!(user.email.as_deref(), Some("test@example.com"));
-        assert!(user.telegram_id.is_none());
+    /// Link an external identity provider subject to this user.
+    ///
+    /// Replaces any subject previously linked for the same provider and
+    /// calls [`RUser::touch`] if the link changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let mut user = RUser::from_email("user@example.com");
+    /// user.link_external("github", "583231");
+    ///
+    /// assert_eq!(user.external_id("github"), Some("583231"));
+    /// ```
+    pub fn link_external(&mut self, provider: impl Into<String>, subject: impl Into<String>) {
+        let subject = subject.into();
+        let previous = self.external_ids.insert(provider.into(), subject.clone());
+        if previous.as_ref() != Some(&subject) {
+            self.touch();
+        }
     }
 
-    #[test]
-    fn from_phone_sets_phone() {
-        let user = RUser::from_phone("+14155551234");
-        assert_eq!(user.phone.as_deref(), Some("+14155551234"));
+    /// Redact this user for a viewer.
+    ///
+    /// The owner gets every field, admins get [`RUserAdmin`], and
+    /// everyone else gets [`RUserPublic`](crate::RUserPublic).
+    ///
+    /// # Arguments
+    ///
+    /// * `viewer` - Role of the user looking at this one
+    /// * `is_owner` - Whether the viewer is this user
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{RUser, RUserRole, RedactedUser};
+    ///
+    /// let user = RUser::from_email("alice@example.com");
+    ///
+    /// assert!(matches!(
+    ///     user.redact_for(RUserRole::User, true),
+    ///     RedactedUser::Full(_)
+    /// ));
+    /// assert!(matches!(
+    ///     user.redact_for(RUserRole::Premium, false),
+    ///     RedactedUser::Public(_)
+    /// ));
+    /// ```
+    #[must_use]
+    pub fn redact_for(&self, viewer: RUserRole, is_owner: bool) -> RedactedUser {
+        if is_owner {
+            RedactedUser::Full(Box::new(self.clone()))
+        } else if viewer.is_admin() {
+            RedactedUser::Admin(RUserAdmin::from(self))
+        } else {
+            RedactedUser::Public(self.into())
+        }
     }
 
-    #[test]
-    fn empty_has_only_id() {
-        let user = RUser::empty();
-        assert!(user.name.is_none());
-        assert!(user.email.is_none());
-        assert!(user.telegram_id.is_none());
+    /// Set an application metadata entry.
+    ///
+    /// Turns [`metadata`](RUser::metadata) into an object first if it
+    /// holds anything else, and replaces an existing entry for `key`.
+    /// Calls [`RUser::touch`] if the entry changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let mut user = RUser::empty();
+    /// user.set_meta("theme", "dark");
+    ///
+    /// assert_eq!(user.get_meta("theme"), Some(&"dark".into()));
+    /// assert_eq!(user.version, 2);
+    /// ```
+    pub fn set_meta(&mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) {
+        let key = key.into();
+        let value = value.into();
+        if self.get_meta(&key) == Some(&value) {
+            return;
+        }
+
+        if !self.metadata.is_object() {
+            self.metadata = serde_json::Value::Object(serde_json::Map::new());
+        }
+        if let serde_json::Value::Object(map) = &mut self.metadata {
+            map.insert(key, value);
+        }
+        self.touch();
     }
 
-    #[test]
-    fn with_id_sets_specific_id() {
-        let id = Uuid::nil();
-        let user = RUser::with_id(id);
-        assert_eq!(user.id, id);
+    /// Get an application metadata entry.
+    ///
+    /// Returns `None` if the key is missing or no metadata is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// assert!(RUser::empty().get_meta("theme").is_none());
+    /// ```
+    #[must_use]
+    pub fn get_meta(&self, key: &str) -> Option<&serde_json::Value> {
+        self.metadata.get(key)
     }
 
-    #[test]
-    fn serializes_to_json() {
-        let user = RUser::from_telegram(123);
-        let json = serde_json::to_string(&user).unwrap();
-        assert!(json.contains("\"telegram_id\":123"));
+    /// Get the subject linked for an external identity provider.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let user = RUser::empty();
+    /// assert_eq!(user.external_id("google"), None);
+    /// ```
+    #[must_use]
+    pub fn external_id(&self, provider: &str) -> Option<&str> {
+        self.external_ids.get(provider).map(String::as_str)
+    }
+
+    /// Mark the email as verified, e.g. after a confirmation link.
+    ///
+    /// Calls [`RUser::touch`] when the flag changes. Does nothing if no
+    /// email is set or it is already verified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let mut user = RUser::empty();
+    /// user.email = Some("user@example.com".into());
+    /// assert!(!user.email_verified);
+    ///
+    /// user.mark_email_verified();
+    /// assert!(user.email_verified);
+    /// ```
+    pub fn mark_email_verified(&mut self) {
+        if self.email.is_some() && !self.email_verified {
+            self.email_verified = true;
+            self.touch();
+        }
+    }
+
+    /// Mark the phone as verified, e.g. after an SMS code.
+    ///
+    /// Calls [`RUser::touch`] when the flag changes. Does nothing if no
+    /// phone is set or it is already verified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let mut user = RUser::from_telegram(123456789);
+    /// user.phone = Some("+14155551234".into());
+    ///
+    /// user.mark_phone_verified();
+    /// assert!(user.phone_verified);
+    /// ```
+    pub fn mark_phone_verified(&mut self) {
+        if self.phone.is_some() && !self.phone_verified {
+            self.phone_verified = true;
+            self.touch();
+        }
+    }
+
+    /// Mark the user as deleted without removing the record.
+    ///
+    /// Sets `deleted_at` to the current time and calls [`RUser::touch`].
+    /// Calling it on an already deleted user keeps the original
+    /// deletion timestamp, so retention windows are not extended.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let mut user = RUser::from_telegram(123456789);
+    /// user.soft_delete();
+    ///
+    /// assert!(user.is_deleted());
+    /// assert!(user.deleted_at.is_some());
+    /// ```
+    pub fn soft_delete(&mut self) {
+        if self.deleted_at.is_none() {
+            self.touch();
+            self.deleted_at = Some(self.updated_at);
+        }
+    }
+
+    /// Record a modification.
+    ///
+    /// Sets `updated_at` to the current time and increments `version`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let mut user = RUser::empty();
+    /// assert_eq!(user.version, 1);
+    ///
+    /// user.touch();
+    /// assert_eq!(user.version, 2);
+    /// ```
+    pub fn touch(&mut self) {
+        self.updated_at = Utc::now();
+        self.version += 1;
+    }
+
+    /// Apply a profile update request.
+    ///
+    /// Copies every field set in `update` and calls [`RUser::touch`] only
+    /// if at least one value actually changed, so no-op updates keep the
+    /// current version.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - Profile changes to apply
+    ///
+    /// # Returns
+    ///
+    /// `true` if the user was modified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{RUser, UpdateProfileRequest};
+    ///
+    /// let mut user = RUser::empty();
+    /// let update = UpdateProfileRequest {
+    ///     name: Some("Alice".into()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert!(user.apply_update(&update));
+    /// assert_eq!(user.version, 2);
+    ///
+    /// // Same values again: nothing changes
+    /// assert!(!user.apply_update(&update));
+    /// assert_eq!(user.version, 2);
+    /// ```
+    pub fn apply_update(&mut self, update: &UpdateProfileRequest) -> bool {
+        let mut changed = false;
+
+        if update.name.is_some() && update.name != self.name {
+            self.name.clone_from(&update.name);
+            changed = true;
+        }
+        if update.gender.is_some() && update.gender != self.gender {
+            self.gender = update.gender;
+            changed = true;
+        }
+        if update.birth_date.is_some() && update.birth_date != self.birth_date {
+            self.birth_date = update.birth_date;
+            changed = true;
+        }
+        let confession_id = update.confession_id.map(ConfessionId::from);
+        if confession_id.is_some() && confession_id != self.confession_id {
+            self.confession_id = confession_id;
+            changed = true;
+        }
+        let prefs = update
+            .notification_prefs
+            .filter(|p| *p != self.notification_prefs);
+        if let Some(prefs) = prefs {
+            self.notification_prefs = prefs;
+            changed = true;
+        }
+
+        if changed {
+            self.touch();
+        }
+        changed
+    }
+
+    /// Apply a tri-state profile patch.
+    ///
+    /// [`Patch::Unchanged`] leaves a field alone, [`Patch::Set`] replaces
+    /// it and [`Patch::Clear`] resets it to `None`. Like
+    /// [`RUser::apply_update`], calls [`RUser::touch`] only if a value
+    /// actually changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - Profile patch to apply
+    ///
+    /// # Returns
+    ///
+    /// `true` if the user was modified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Gender, Patch, PatchProfileRequest, RUser};
+    ///
+    /// let mut user = RUser::empty();
+    /// user.gender = Some(Gender::Male);
+    ///
+    /// let req = PatchProfileRequest {
+    ///     gender: Patch::Clear,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert!(user.apply_patch(req));
+    /// assert!(user.gender.is_none());
+    /// assert_eq!(user.version, 2);
+    /// ```
+    pub fn apply_patch(&mut self, req: PatchProfileRequest) -> bool {
+        fn apply<T: PartialEq>(patch: Patch<T>, field: &mut Option<T>) -> bool {
+            match patch.into_option() {
+                Some(value) if value != *field => {
+                    *field = value;
+                    true
+                }
+                _ => false
+            }
+        }
+
+        let confession_id = match req.confession_id {
+            Patch::Unchanged => Patch::Unchanged,
+            Patch::Set(id) => Patch::Set(ConfessionId::from(id)),
+            Patch::Clear => Patch::Clear
+        };
+
+        // Non-short-circuiting `|` so every field is applied.
+        let changed = apply(req.name, &mut self.name)
+            | apply(req.gender, &mut self.gender)
+            | apply(req.birth_date, &mut self.birth_date)
+            | apply(confession_id, &mut self.confession_id);
+
+        if changed {
+            self.touch();
+        }
+        changed
+    }
+
+    /// Check if the user has been soft-deleted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let user = RUser::empty();
+    /// assert!(!user.is_deleted());
+    /// ```
+    #[must_use]
+    pub const fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Get how complete the profile is, as a percentage (0-100).
+    ///
+    /// Five profile fields count equally, 20 points each:
+    ///
+    /// | Field | Weight |
+    /// |-------|--------|
+    /// | `name` | 20 |
+    /// | `gender` | 20 |
+    /// | `birth_date` | 20 |
+    /// | `avatar_url` | 20 |
+    /// | `confession_id` | 20 |
+    ///
+    /// Contact fields are not counted since every user has at least one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Gender, RUser};
+    ///
+    /// let mut user = RUser::from_telegram(123456789);
+    /// assert_eq!(user.completion(), 0);
+    ///
+    /// user.name = Some("John".into());
+    /// user.gender = Some(Gender::Male);
+    /// user.avatar_url = Some("https://cdn.example.com/a.png".into());
+    /// assert_eq!(user.completion(), 60);
+    /// ```
+    #[must_use]
+    pub const fn completion(&self) -> u8 {
+        let filled = [
+            self.name.is_some(),
+            self.gender.is_some(),
+            self.birth_date.is_some(),
+            self.avatar_url.is_some(),
+            self.confession_id.is_some()
+        ];
+
+        let mut score = 0;
+        let mut i = 0;
+        while i < filled.len() {
+            if filled[i] {
+                score += 20;
+            }
+            i += 1;
+        }
+        score
+    }
+
+    /// [`RUser::field_mask`] bit for `name`.
+    pub const MASK_NAME: u16 = 1 << 0;
+    /// [`RUser::field_mask`] bit for `gender`.
+    pub const MASK_GENDER: u16 = 1 << 1;
+    /// [`RUser::field_mask`] bit for `birth_date`.
+    pub const MASK_BIRTH_DATE: u16 = 1 << 2;
+    /// [`RUser::field_mask`] bit for `confession_id`.
+    pub const MASK_CONFESSION_ID: u16 = 1 << 3;
+    /// [`RUser::field_mask`] bit for `email`.
+    pub const MASK_EMAIL: u16 = 1 << 4;
+    /// [`RUser::field_mask`] bit for `phone`.
+    pub const MASK_PHONE: u16 = 1 << 5;
+    /// [`RUser::field_mask`] bit for `telegram_id`.
+    pub const MASK_TELEGRAM_ID: u16 = 1 << 6;
+    /// [`RUser::field_mask`] bit for `avatar_url`.
+    pub const MASK_AVATAR_URL: u16 = 1 << 7;
+    /// [`RUser::field_mask`] bit for `deleted_at`.
+    pub const MASK_DELETED_AT: u16 = 1 << 8;
+
+    /// Get a bitmask of which optional fields are set.
+    ///
+    /// Bits follow the field order of [`RUser`]; bits 9-15 are unused
+    /// and always zero:
+    ///
+    /// | Bit | Constant | Field |
+    /// |-----|----------|-------|
+    /// | 0 | [`MASK_NAME`](Self::MASK_NAME) | `name` |
+    /// | 1 | [`MASK_GENDER`](Self::MASK_GENDER) | `gender` |
+    /// | 2 | [`MASK_BIRTH_DATE`](Self::MASK_BIRTH_DATE) | `birth_date` |
+    /// | 3 | [`MASK_CONFESSION_ID`](Self::MASK_CONFESSION_ID) | `confession_id` |
+    /// | 4 | [`MASK_EMAIL`](Self::MASK_EMAIL) | `email` |
+    /// | 5 | [`MASK_PHONE`](Self::MASK_PHONE) | `phone` |
+    /// | 6 | [`MASK_TELEGRAM_ID`](Self::MASK_TELEGRAM_ID) | `telegram_id` |
+    /// | 7 | [`MASK_AVATAR_URL`](Self::MASK_AVATAR_URL) | `avatar_url` |
+    /// | 8 | [`MASK_DELETED_AT`](Self::MASK_DELETED_AT) | `deleted_at` |
+    ///
+    /// Comparing masks of two versions gives a cheap set/unset change
+    /// check; use [`RUser::diff`] for value changes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let user = RUser::from_telegram_with_name(123456789, "John");
+    ///
+    /// assert_eq!(
+    ///     user.field_mask(),
+    ///     RUser::MASK_NAME | RUser::MASK_TELEGRAM_ID
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn field_mask(&self) -> u16 {
+        let set = [
+            (self.name.is_some(), Self::MASK_NAME),
+            (self.gender.is_some(), Self::MASK_GENDER),
+            (self.birth_date.is_some(), Self::MASK_BIRTH_DATE),
+            (self.confession_id.is_some(), Self::MASK_CONFESSION_ID),
+            (self.email.is_some(), Self::MASK_EMAIL),
+            (self.phone.is_some(), Self::MASK_PHONE),
+            (self.telegram_id.is_some(), Self::MASK_TELEGRAM_ID),
+            (self.avatar_url.is_some(), Self::MASK_AVATAR_URL),
+            (self.deleted_at.is_some(), Self::MASK_DELETED_AT)
+        ];
+
+        let mut mask = 0;
+        let mut i = 0;
+        while i < set.len() {
+            if set[i].0 {
+                mask |= set[i].1;
+            }
+            i += 1;
+        }
+        mask
+    }
+
+    /// Fields [`RUser::to_value_selected`] may include.
+    ///
+    /// The [`RUserPublic`](crate::RUserPublic) field set. Every other
+    /// field, including ones added later, stays private unless allowed
+    /// through [`RUser::to_value_selected_with`].
+    pub const PUBLIC_FIELDS: &'static [&'static str] = &["id", "name", "gender"];
+
+    /// Serialize only the requested fields, for `?fields=` selection.
+    ///
+    /// Names may be given in snake_case or camelCase; output keys follow
+    /// the `camel` feature. Unknown names and fields outside
+    /// [`PUBLIC_FIELDS`](Self::PUBLIC_FIELDS) are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - Requested field names
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let user = RUser::from_email("user@example.com");
+    /// let value = user.to_value_selected(&["id", "email"]);
+    ///
+    /// assert!(value.get("id").is_some());
+    /// assert!(value.get("email").is_none());
+    /// ```
+    #[must_use]
+    pub fn to_value_selected(&self, fields: &[&str]) -> serde_json::Value {
+        self.to_value_selected_with(fields, &[])
+    }
+
+    /// Like [`RUser::to_value_selected`], but also allows the listed
+    /// private fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - Requested field names
+    /// * `allowed_private` - Fields beyond
+    ///   [`PUBLIC_FIELDS`](Self::PUBLIC_FIELDS) the caller may see
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let user = RUser::from_email("user@example.com");
+    /// let value = user.to_value_selected_with(&["email", "phone"], &["email"]);
+    ///
+    /// assert_eq!(value["email"], "user@example.com");
+    /// assert!(value.get("phone").is_none());
+    /// ```
+    #[must_use]
+    pub fn to_value_selected_with(
+        &self,
+        fields: &[&str],
+        allowed_private: &[&str]
+    ) -> serde_json::Value {
+        let mut map = serde_json::Map::new();
+
+        for &requested in fields {
+            let Some(&(snake, camel)) = FIELD_NAMES
+                .iter()
+                .find(|(snake, camel)| requested == *snake || requested == *camel)
+            else {
+                continue;
+            };
+            let allowed = Self::PUBLIC_FIELDS.contains(&snake)
+                || allowed_private.iter().any(|&a| a == snake || a == camel);
+            if !allowed {
+                continue;
+            }
+            if let Some(value) = self.field_value(snake) {
+                let key = if cfg!(feature = "camel") {
+                    camel
+                } else {
+                    snake
+                };
+                map.insert(key.to_owned(), value);
+            }
+        }
+
+        serde_json::Value::Object(map)
+    }
+
+    /// Serialize a single field by its snake_case name.
+    fn field_value(&self, field: &str) -> Option<serde_json::Value> {
+        use serde_json::{to_value, value::Serializer};
+
+        let value = match field {
+            "id" => to_value(self.id),
+            "name" => to_value(&self.name),
+            "gender" => to_value(self.gender),
+            "birth_date" => to_value(self.birth_date),
+            "confession_id" => to_value(self.confession_id),
+            "email" => to_value(&self.email),
+            "phone" => to_value(&self.phone),
+            "telegram_id" => to_value(self.telegram_id),
+            "email_verified" => to_value(self.email_verified),
+            "phone_verified" => to_value(self.phone_verified),
+            "avatar_url" => to_value(&self.avatar_url),
+            "notification_prefs" => to_value(self.notification_prefs),
+            "external_ids" => to_value(&self.external_ids),
+            "signup_source" => to_value(self.signup_source),
+            "metadata" => Ok(self.metadata.clone()),
+            "created_at" => rfc3339_millis::serialize(&self.created_at, Serializer),
+            "updated_at" => rfc3339_millis::serialize(&self.updated_at, Serializer),
+            "deleted_at" => to_value(self.deleted_at),
+            "version" => to_value(self.version),
+            _ => return None
+        };
+        value.ok()
+    }
+
+    /// Get the preferred way to reach this user.
+    ///
+    /// Priority is email, then phone, then Telegram. Returns `None`
+    /// when no contact is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Contact, RUser};
+    ///
+    /// let mut user = RUser::from_telegram(123456789);
+    /// assert_eq!(user.primary_contact(), Some(Contact::Telegram(123456789)));
+    ///
+    /// user.email = Some("user@example.com".into());
+    /// assert_eq!(
+    ///     user.primary_contact(),
+    ///     Some(Contact::Email("user@example.com".into()))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn primary_contact(&self) -> Option<Contact> {
+        if let Some(email) = &self.email {
+            return Some(Contact::Email(email.clone()));
+        }
+        if let Some(phone) = &self.phone {
+            return Some(Contact::Phone(phone.clone()));
+        }
+        self.telegram_id.map(Contact::Telegram)
+    }
+
+    /// List every authentication method available to this user.
+    ///
+    /// Ordered Telegram, email, phone. Empty when no identifier is set;
+    /// [`AuthMethod::None`] is never included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{AuthMethod, RUser};
+    ///
+    /// let mut user = RUser::from_telegram(123456789);
+    /// user.email = Some("user@example.com".into());
+    ///
+    /// assert_eq!(
+    ///     user.auth_methods(),
+    ///     vec![AuthMethod::Telegram, AuthMethod::Email]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn auth_methods(&self) -> Vec<AuthMethod> {
+        let mut methods = Vec::with_capacity(3);
+        if self.telegram_id.is_some() {
+            methods.push(AuthMethod::Telegram);
+        }
+        if self.email.is_some() {
+            methods.push(AuthMethod::Email);
+        }
+        if self.phone.is_some() {
+            methods.push(AuthMethod::Phone);
+        }
+        methods
+    }
+
+    /// Get the main authentication method of this user.
+    ///
+    /// Uses the same order as [`RUser::auth_methods`] and returns
+    /// [`AuthMethod::None`] when no identifier is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{AuthMethod, RUser};
+    ///
+    /// assert_eq!(
+    ///     RUser::from_email("user@example.com").primary_auth_method(),
+    ///     AuthMethod::Email
+    /// );
+    /// assert_eq!(RUser::empty().primary_auth_method(), AuthMethod::None);
+    /// ```
+    #[must_use]
+    pub const fn primary_auth_method(&self) -> AuthMethod {
+        if self.telegram_id.is_some() {
+            AuthMethod::Telegram
+        } else if self.email.is_some() {
+            AuthMethod::Email
+        } else if self.phone.is_some() {
+            AuthMethod::Phone
+        } else {
+            AuthMethod::None
+        }
+    }
+
+    /// Compare two snapshots of a user field by field.
+    ///
+    /// Returns one [`FieldChange`] per differing field, in declaration
+    /// order. Contact fields (`email`, `phone`, `telegram_id`) are reported
+    /// with both values as [`FieldChange::REDACTED`] so audit logs never
+    /// store them.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The newer snapshot
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let before = RUser::from_email("old@example.com");
+    /// let mut after = before.clone();
+    /// after.name = Some("John".into());
+    /// after.email = Some("new@example.com".into());
+    ///
+    /// let changes = before.diff(&after);
+    ///
+    /// assert_eq!(changes.len(), 2);
+    /// assert_eq!(changes[0].field, "name");
+    /// assert_eq!(changes[0].to, "John");
+    /// assert_eq!(changes[1].field, "email");
+    /// assert_eq!(changes[1].to, "[redacted]");
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &RUser) -> Vec<FieldChange> {
+        self.diff_fields(other, true)
+    }
+
+    /// Compare every field except `created_at` and `updated_at`.
+    ///
+    /// Useful for deduplicating imports, where the same user may be
+    /// loaded at different times. `version` and `deleted_at` are still
+    /// compared.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let a = RUser::from_telegram(123);
+    /// let mut b = a.clone();
+    /// b.updated_at += chrono::Duration::seconds(1);
+    ///
+    /// assert!(a.eq_ignoring_timestamps(&b));
+    /// assert_ne!(a, b);
+    /// ```
+    #[must_use]
+    pub fn eq_ignoring_timestamps(&self, other: &Self) -> bool {
+        // Exhaustive so new fields cannot be silently skipped.
+        let Self {
+            id,
+            name,
+            gender,
+            birth_date,
+            confession_id,
+            email,
+            phone,
+            telegram_id,
+            email_verified,
+            phone_verified,
+            avatar_url,
+            notification_prefs,
+            external_ids,
+            signup_source,
+            metadata,
+            created_at: _,
+            updated_at: _,
+            deleted_at,
+            version
+        } = self;
+
+        *id == other.id
+            && *name == other.name
+            && *gender == other.gender
+            && *birth_date == other.birth_date
+            && *confession_id == other.confession_id
+            && *email == other.email
+            && *phone == other.phone
+            && *telegram_id == other.telegram_id
+            && *email_verified == other.email_verified
+            && *phone_verified == other.phone_verified
+            && *avatar_url == other.avatar_url
+            && *notification_prefs == other.notification_prefs
+            && *external_ids == other.external_ids
+            && *signup_source == other.signup_source
+            && *metadata == other.metadata
+            && *deleted_at == other.deleted_at
+            && *version == other.version
+    }
+
+    /// Like [`RUser::diff`], but ignores `created_at` and `updated_at`.
+    ///
+    /// Useful when comparing content edits, where the update timestamp
+    /// always moves.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let before = RUser::empty();
+    /// let mut after = before.clone();
+    /// after.touch();
+    ///
+    /// assert!(
+    ///     before
+    ///         .diff_without_timestamps(&after)
+    ///         .iter()
+    ///         .all(|c| c.field == "version")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn diff_without_timestamps(&self, other: &RUser) -> Vec<FieldChange> {
+        self.diff_fields(other, false)
+    }
+
+    fn diff_fields(&self, other: &RUser, timestamps: bool) -> Vec<FieldChange> {
+        fn opt<T: core::fmt::Display>(value: &Option<T>) -> String {
+            value
+                .as_ref()
+                .map_or_else(|| "null".to_owned(), ToString::to_string)
+        }
+
+        fn time(value: &DateTime<Utc>) -> String {
+            value.to_rfc3339_opts(SecondsFormat::Millis, true)
+        }
+
+        let mut changes = Vec::new();
+        let mut record = |field: &'static str, from: String, to: String| {
+            if from != to {
+                changes.push(FieldChange {
+                    field,
+                    from,
+                    to
+                });
+            }
+        };
+
+        record("id", self.id.to_string(), other.id.to_string());
+        record("name", opt(&self.name), opt(&other.name));
+        record("gender", opt(&self.gender), opt(&other.gender));
+        record("birth_date", opt(&self.birth_date), opt(&other.birth_date));
+        record(
+            "confession_id",
+            opt(&self.confession_id),
+            opt(&other.confession_id)
+        );
+        record("email", opt(&self.email), opt(&other.email));
+        record("phone", opt(&self.phone), opt(&other.phone));
+        record(
+            "telegram_id",
+            opt(&self.telegram_id),
+            opt(&other.telegram_id)
+        );
+        record(
+            "email_verified",
+            self.email_verified.to_string(),
+            other.email_verified.to_string()
+        );
+        record(
+            "phone_verified",
+            self.phone_verified.to_string(),
+            other.phone_verified.to_string()
+        );
+        record("avatar_url", opt(&self.avatar_url), opt(&other.avatar_url));
+        record(
+            "notification_prefs",
+            format!("{:?}", self.notification_prefs),
+            format!("{:?}", other.notification_prefs)
+        );
+        record(
+            "external_ids",
+            format!("{:?}", self.external_ids),
+            format!("{:?}", other.external_ids)
+        );
+        record(
+            "signup_source",
+            self.signup_source.to_string(),
+            other.signup_source.to_string()
+        );
+        record(
+            "metadata",
+            self.metadata.to_string(),
+            other.metadata.to_string()
+        );
+        if timestamps {
+            record(
+                "created_at",
+                time(&self.created_at),
+                time(&other.created_at)
+            );
+            record(
+                "updated_at",
+                time(&self.updated_at),
+                time(&other.updated_at)
+            );
+        }
+        record(
+            "deleted_at",
+            self.deleted_at
+                .as_ref()
+                .map_or_else(|| "null".to_owned(), time),
+            other
+                .deleted_at
+                .as_ref()
+                .map_or_else(|| "null".to_owned(), time)
+        );
+        record(
+            "version",
+            self.version.to_string(),
+            other.version.to_string()
+        );
+
+        for change in &mut changes {
+            if matches!(change.field, "email" | "phone" | "telegram_id") {
+                change.from = FieldChange::REDACTED.to_owned();
+                change.to = FieldChange::REDACTED.to_owned();
+            }
+        }
+        changes
+    }
+}
+
+impl RUser {
+    /// Check whether `name` is a serialized [`RUser`] field.
+    ///
+    /// Accepts snake_case or camelCase names. Usable in `const`
+    /// contexts; [`extend_user!`](crate::extend_user) relies on it to
+    /// reject custom fields that would collide with flattened user fields.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// const _: () = assert!(RUser::is_field("email"));
+    /// assert!(RUser::is_field("birthDate"));
+    /// assert!(!RUser::is_field("company_id"));
+    /// ```
+    #[must_use]
+    pub const fn is_field(name: &str) -> bool {
+        const fn eq(a: &str, b: &str) -> bool {
+            let (a, b) = (a.as_bytes(), b.as_bytes());
+            if a.len() != b.len() {
+                return false;
+            }
+            let mut i = 0;
+            while i < a.len() {
+                if a[i] != b[i] {
+                    return false;
+                }
+                i += 1;
+            }
+            true
+        }
+
+        let mut i = 0;
+        while i < FIELD_NAMES.len() {
+            let (snake, camel) = FIELD_NAMES[i];
+            if eq(name, snake) || eq(name, camel) {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+}
+
+/// Selectable [`RUser`] fields as `(snake_case, camelCase)` pairs.
+const FIELD_NAMES: [(&str, &str); 19] = [
+    ("id", "id"),
+    ("name", "name"),
+    ("gender", "gender"),
+    ("birth_date", "birthDate"),
+    ("confession_id", "confessionId"),
+    ("email", "email"),
+    ("phone", "phone"),
+    ("telegram_id", "telegramId"),
+    ("email_verified", "emailVerified"),
+    ("phone_verified", "phoneVerified"),
+    ("avatar_url", "avatarUrl"),
+    ("notification_prefs", "notificationPrefs"),
+    ("external_ids", "externalIds"),
+    ("signup_source", "signupSource"),
+    ("metadata", "metadata"),
+    ("created_at", "createdAt"),
+    ("updated_at", "updatedAt"),
+    ("deleted_at", "deletedAt"),
+    ("version", "version")
+];
+
+/// DTO for user creation, with the contact fields of [`RUser`].
+///
+/// Defined by hand rather than through `#[field(create)]`: the mapping
+/// `entity-derive` generates assigns a bare `Uuid` to the id and
+/// `Default::default()` to everything else, which fits neither
+/// [`UserId`] nor `version`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "validate", derive(Validate))]
+pub struct CreateRUserRequest {
+    /// Email address.
+    pub email:       Option<String>,
+    /// Phone number in E.164 format.
+    pub phone:       Option<String>,
+    /// Telegram user ID.
+    pub telegram_id: Option<i64>
+}
+
+impl From<CreateRUserRequest> for RUser {
+    /// Build a user with a fresh id; every field not in the request
+    /// starts as in [`RUser::empty`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{CreateRUserRequest, RUser};
+    ///
+    /// let user = RUser::from(CreateRUserRequest {
+    ///     email:       Some("user@example.com".into()),
+    ///     phone:       None,
+    ///     telegram_id: None
+    /// });
+    ///
+    /// assert!(!user.id.is_nil());
+    /// assert_eq!(user.email.as_deref(), Some("user@example.com"));
+    /// ```
+    fn from(req: CreateRUserRequest) -> Self {
+        Self {
+            email: req.email,
+            phone: req.phone,
+            telegram_id: req.telegram_id,
+            ..Self::empty()
+        }
+    }
+}
+
+impl TryFrom<CreateUserRequest> for RUser {
+    type Error = ValidationErrors;
+
+    /// Validate the request and build a user from it.
+    ///
+    /// Carries over `id`, `telegram_id`, `email` and `phone`, and sets
+    /// `signup_source` from the first of them present in that order;
+    /// every other field starts as in [`RUser::empty`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the request's [`ValidationErrors`] if it fails validation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{CreateUserRequest, RUser};
+    ///
+    /// let req = CreateUserRequest::telegram(123456789);
+    /// let id = req.id;
+    ///
+    /// let user = RUser::try_from(req).unwrap();
+    /// assert_eq!(user.id, id);
+    /// assert_eq!(user.telegram_id, Some(123456789));
+    /// ```
+    fn try_from(req: CreateUserRequest) -> Result<Self, Self::Error> {
+        req.validate()?;
+
+        let signup_source = if req.telegram_id.is_some() {
+            SignupSource::Telegram
+        } else if req.email.is_some() {
+            SignupSource::Email
+        } else if req.phone.is_some() {
+            SignupSource::Phone
+        } else {
+            SignupSource::Unknown
+        };
+
+        Ok(Self {
+            id: req.id.into(),
+            telegram_id: req.telegram_id,
+            email: req.email,
+            phone: req.phone,
+            signup_source,
+            ..Self::empty()
+        })
+    }
+}
+
+impl Validate for RUser {
+    /// Applies the DTO rules to the stored values.
+    ///
+    /// Contacts are checked as in [`CreateUserRequest`], profile fields
+    /// as in [`UpdateProfileRequest`]. Errors from the contact check are
+    /// returned first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    /// use validator::Validate;
+    ///
+    /// assert!(RUser::from_email("user@example.com").validate().is_ok());
+    /// assert!(RUser::from_email("not-an-email").validate().is_err());
+    /// ```
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        CreateUserRequest {
+            id:          self.id.0,
+            telegram_id: self.telegram_id,
+            email:       self.email.clone(),
+            phone:       self.phone.clone()
+        }
+        .validate()?;
+
+        UpdateProfileRequest {
+            name:               self.name.clone(),
+            gender:             self.gender,
+            birth_date:         self.birth_date,
+            confession_id:      self.confession_id.map(|id| id.0),
+            notification_prefs: None
+        }
+        .validate()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for RUser {
+    /// Generates a user that passes [`Validate`].
+    ///
+    /// Contacts are well-formed, verification flags are only set for
+    /// present contacts and `created_at <= updated_at`.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use arbitrary::Unstructured;
+
+        fn word(
+            u: &mut Unstructured<'_>,
+            len: core::ops::RangeInclusive<usize>
+        ) -> arbitrary::Result<String> {
+            const ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+            let len = u.int_in_range(len)?;
+            (0..len)
+                .map(|_| u.choose(ALPHABET).map(|&c| char::from(c)))
+                .collect()
+        }
+
+        fn maybe<T>(
+            u: &mut Unstructured<'_>,
+            value: impl FnOnce(&mut Unstructured<'_>) -> arbitrary::Result<T>
+        ) -> arbitrary::Result<Option<T>> {
+            if u.arbitrary()? {
+                value(u).map(Some)
+            } else {
+                Ok(None)
+            }
+        }
+
+        fn timestamp(secs: i64) -> arbitrary::Result<DateTime<Utc>> {
+            DateTime::from_timestamp(secs, 0).ok_or(arbitrary::Error::IncorrectFormat)
+        }
+
+        let email = maybe(u, |u| {
+            let tld = u.choose(&["com", "org", "net", "io"])?;
+            Ok(format!("{}@{}.{tld}", word(u, 1..=32)?, word(u, 1..=32)?))
+        })?;
+        let phone = maybe(u, |u| {
+            let digits: String = (0..u.int_in_range(9..=14)?)
+                .map(|_| u.int_in_range(0..=9).map(|d: u8| char::from(b'0' + d)))
+                .collect::<arbitrary::Result<_>>()?;
+            Ok(format!("+{}{digits}", u.int_in_range(1..=9_u8)?))
+        })?;
+
+        let created = u.int_in_range(1_500_000_000..=2_000_000_000)?;
+        let updated = created + u.int_in_range(0..=100_000_000)?;
+
+        let mut external_ids = BTreeMap::new();
+        if u.arbitrary()? {
+            let provider = u.choose(&["apple", "github", "google"])?;
+            external_ids.insert((*provider).to_owned(), word(u, 1..=21)?);
+        }
+
+        Ok(Self {
+            id: UserId(Uuid::from_u128(u.arbitrary()?)),
+            name: maybe(u, |u| word(u, 2..=100))?,
+            gender: maybe(u, |u| u.choose(&[Gender::Male, Gender::Female]).copied())?,
+            birth_date: maybe(u, |u| {
+                let (year, month, day) = (
+                    u.int_in_range(1900..=2000)?,
+                    u.int_in_range(1..=12)?,
+                    u.int_in_range(1..=28)?
+                );
+                NaiveDate::from_ymd_opt(year, month, day).ok_or(arbitrary::Error::IncorrectFormat)
+            })?,
+            confession_id: maybe(u, |u| Ok(ConfessionId(Uuid::from_u128(u.arbitrary()?))))?,
+            email_verified: email.is_some() && u.arbitrary()?,
+            phone_verified: phone.is_some() && u.arbitrary()?,
+            email,
+            phone,
+            telegram_id: maybe(u, |u| u.int_in_range(1..=i64::MAX))?,
+            avatar_url: maybe(u, |u| {
+                Ok(format!("https://cdn.example.com/{}.png", word(u, 1..=16)?))
+            })?,
+            notification_prefs: NotificationPrefs {
+                telegram: u.arbitrary()?,
+                email:    u.arbitrary()?,
+                push:     u.arbitrary()?
+            },
+            external_ids,
+            signup_source: *u.choose(&[
+                SignupSource::Telegram,
+                SignupSource::Email,
+                SignupSource::Phone,
+                SignupSource::OAuth,
+                SignupSource::Unknown
+            ])?,
+            metadata: serde_json::Value::Null,
+            created_at: timestamp(created)?,
+            updated_at: timestamp(updated)?,
+            deleted_at: maybe(u, |_| timestamp(updated))?,
+            version: u.int_in_range(1..=1_000)?
+        })
+    }
+}
+
+/// A channel through which a user can be reached.
+///
+/// Returned by [`RUser::primary_contact`].
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{Contact, RUser};
+///
+/// let user = RUser::from_phone("+14155551234");
+///
+/// match user.primary_contact() {
+///     Some(Contact::Phone(phone)) => assert_eq!(phone, "+14155551234"),
+///     other => panic!("unexpected contact: {other:?}")
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Contact {
+    /// Email address.
+    Email(String),
+
+    /// Phone number in E.164 format.
+    Phone(String),
+
+    /// Telegram user ID.
+    Telegram(i64)
+}
+
+/// How a user authenticates.
+///
+/// Derived from which identifiers are set on [`RUser`]; see
+/// [`RUser::auth_methods`] and [`RUser::primary_auth_method`].
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{AuthMethod, RUser};
+///
+/// let user = RUser::from_phone("+14155551234");
+/// assert_eq!(user.primary_auth_method(), AuthMethod::Phone);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuthMethod {
+    /// Telegram login.
+    Telegram,
+
+    /// Email sign-in.
+    Email,
+
+    /// Phone (SMS) sign-in.
+    Phone,
+
+    /// No identifier is set.
+    None
+}
+
+/// Channel a user originally signed up through.
+///
+/// Stored in [`RUser::signup_source`] for analytics; unlike
+/// [`AuthMethod`] it does not change when more methods are linked.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{RUser, SignupSource};
+///
+/// let mut user = RUser::from_telegram(123456789);
+/// user.email = Some("user@example.com".into());
+///
+/// assert_eq!(user.signup_source, SignupSource::Telegram);
+/// assert_eq!(
+///     serde_json::to_string(&SignupSource::OAuth).unwrap(),
+///     "\"oauth\""
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(
+    feature = "db",
+    sqlx(type_name = "signup_source", rename_all = "snake_case")
+)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum SignupSource {
+    /// Telegram login.
+    Telegram,
+
+    /// Email sign-in.
+    Email,
+
+    /// Phone (SMS) sign-in.
+    Phone,
+
+    /// OAuth provider, see [`RUser::from_oauth`].
+    #[serde(rename = "oauth")]
+    #[cfg_attr(feature = "db", sqlx(rename = "oauth"))]
+    OAuth,
+
+    /// Not recorded, e.g. users created before tracking existed.
+    #[default]
+    Unknown
+}
+
+impl SignupSource {
+    /// Returns the source as a lowercase string, as in JSON.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::SignupSource;
+    ///
+    /// assert_eq!(SignupSource::Email.as_str(), "email");
+    /// ```
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Telegram => "telegram",
+            Self::Email => "email",
+            Self::Phone => "phone",
+            Self::OAuth => "oauth",
+            Self::Unknown => "unknown"
+        }
+    }
+}
+
+impl core::fmt::Display for SignupSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single field difference produced by [`RUser::diff`].
+///
+/// Values are rendered as strings (`"null"` for absent optional values).
+/// Contact fields carry [`FieldChange::REDACTED`] instead of real values.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::RUser;
+///
+/// let before = RUser::empty();
+/// let mut after = before.clone();
+/// after.avatar_url = Some("https://cdn.example.com/a.png".into());
+///
+/// let change = &before.diff(&after)[0];
+/// assert_eq!(change.field, "avatar_url");
+/// assert_eq!(change.from, "null");
+/// assert_eq!(change.to, "https://cdn.example.com/a.png");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldChange {
+    /// Field name as declared on [`RUser`].
+    pub field: &'static str,
+
+    /// Value in the original snapshot.
+    pub from: String,
+
+    /// Value in the compared snapshot.
+    pub to: String
+}
+
+impl FieldChange {
+    /// Placeholder used for contact field values.
+    pub const REDACTED: &'static str = "[redacted]";
+}
+
+/// Serde adapter for fixed-precision RFC 3339 timestamps.
+///
+/// Serializes as `YYYY-MM-DDTHH:MM:SS.mmmZ` (always three fractional
+/// digits, UTC), so JSON output does not change width with the clock's
+/// precision. Deserialization accepts any RFC 3339 timestamp and
+/// converts it to UTC.
+///
+/// Use with `#[serde(with = "rfc3339_millis")]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{DateTime, Utc};
+/// use revelation_user::rfc3339_millis;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "rfc3339_millis")]
+///     at: DateTime<Utc>
+/// }
+///
+/// let json = serde_json::to_string(&Event {
+///     at: DateTime::UNIX_EPOCH
+/// })
+/// .unwrap();
+/// assert_eq!(json, r#"{"at":"1970-01-01T00:00:00.000Z"}"#);
+/// ```
+pub mod rfc3339_millis {
+    use chrono::{DateTime, SecondsFormat, Utc};
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    /// Serialize a timestamp as RFC 3339 with millisecond precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error if writing fails.
+    pub fn serialize<S>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        serializer.serialize_str(&value.to_rfc3339_opts(SecondsFormat::Millis, true))
+    }
+
+    /// Deserialize any RFC 3339 timestamp into UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a valid RFC 3339 string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let text = String::deserialize(deserializer)?;
+        DateTime::parse_from_rfc3339(&text)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Serde adapter for Telegram IDs sent as numbers or numeric strings.
+///
+/// The Telegram Login Widget sometimes sends `id` as a JSON string
+/// (`"123456789"`). Deserialization accepts both forms; serialization
+/// always writes a number. Use
+/// [`telegram_id::option`](self::telegram_id::option) for `Option<i64>` fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::telegram_id;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Login {
+///     #[serde(with = "telegram_id")]
+///     id: i64
+/// }
+///
+/// let login: Login = serde_json::from_str(r#"{"id":"123456789"}"#).unwrap();
+/// assert_eq!(login.id, 123456789);
+/// assert_eq!(
+///     serde_json::to_string(&login).unwrap(),
+///     r#"{"id":123456789}"#
+/// );
+/// ```
+pub mod telegram_id {
+    use serde::{Deserializer, Serializer, de};
+
+    /// Serialize a Telegram ID as a number.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error if writing fails.
+    pub fn serialize<S>(id: &i64, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        serializer.serialize_i64(*id)
+    }
+
+    /// Deserialize a Telegram ID from a number or a numeric string.
+    ///
+    /// # Errors
+    ///
+    /// Fails on non-numeric strings, floats and out-of-range numbers.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<i64, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        deserializer.deserialize_any(TelegramIdVisitor)
+    }
+
+    struct TelegramIdVisitor;
+
+    impl de::Visitor<'_> for TelegramIdVisitor {
+        type Value = i64;
+
+        fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+            formatter.write_str("a Telegram ID as an integer or numeric string")
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error
+        {
+            Ok(value)
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error
+        {
+            i64::try_from(value)
+                .map_err(|_| E::custom(format!("Telegram ID out of range: {value}")))
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error
+        {
+            value
+                .parse()
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+    }
+
+    /// Same as the parent module, for `Option<i64>` fields.
+    ///
+    /// Pair with `#[serde(default)]` so a missing field stays `None`.
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        /// Serialize an optional Telegram ID as a number or `null`.
+        ///
+        /// # Errors
+        ///
+        /// Returns the serializer's error if writing fails.
+        pub fn serialize<S>(id: &Option<i64>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer
+        {
+            match id {
+                Some(id) => super::serialize(id, serializer),
+                None => serializer.serialize_none()
+            }
+        }
+
+        /// Deserialize an optional Telegram ID from a number or string.
+        ///
+        /// # Errors
+        ///
+        /// Fails on non-numeric strings, floats and out-of-range numbers.
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+        where
+            D: Deserializer<'de>
+        {
+            #[derive(Deserialize)]
+            struct Wrapper(#[serde(with = "super")] i64);
+
+            Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(id)| id))
+        }
+    }
+}
+
+/// Unique identifier of an [`RUser`].
+///
+/// A typed wrapper around [`Uuid`] so user IDs cannot be mixed up with
+/// confession IDs or other UUIDs at call sites. Serializes exactly like
+/// the inner UUID.
+///
+/// # Conversions
+///
+/// - `From<Uuid>` / `From<UserId> for Uuid` - migrate from bare UUIDs
+/// - `FromStr` - parse the hyphenated UUID form
+/// - `Display` - hyphenated lowercase UUID
+/// - `Default` - the nil ID, like [`Uuid::default`]; use [`UserId::new`] for a
+///   fresh one
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::UserId;
+/// use uuid::Uuid;
+///
+/// let id: UserId = "00000000-0000-0000-0000-000000000000".parse().unwrap();
+/// assert!(id.is_nil());
+/// assert_eq!(Uuid::from(id), Uuid::nil());
+///
+/// let json = serde_json::to_string(&id).unwrap();
+/// assert_eq!(json, "\"00000000-0000-0000-0000-000000000000\"");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(transparent))]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(inline))]
+pub struct UserId(pub Uuid);
+
+impl UserId {
+    /// Generate a new time-ordered (UUIDv7) user ID.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::now_v7())
+    }
+
+    /// The nil user ID (all zeros).
+    #[must_use]
+    pub const fn nil() -> Self {
+        Self(Uuid::nil())
+    }
+
+    /// Returns `true` if this is the nil ID.
+    #[must_use]
+    pub const fn is_nil(&self) -> bool {
+        self.0.is_nil()
+    }
+
+    /// Returns the inner UUID.
+    #[must_use]
+    pub const fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for UserId {
+    fn default() -> Self {
+        Self::nil()
+    }
+}
+
+impl From<Uuid> for UserId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl From<UserId> for Uuid {
+    fn from(id: UserId) -> Self {
+        id.0
+    }
+}
+
+impl PartialEq<Uuid> for UserId {
+    fn eq(&self, other: &Uuid) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<UserId> for Uuid {
+    fn eq(&self, other: &UserId) -> bool {
+        *self == other.0
+    }
+}
+
+impl core::fmt::Display for UserId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl core::str::FromStr for UserId {
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Channel;
+
+    #[test]
+    fn from_telegram_sets_telegram_id() {
+        let user = RUser::from_telegram(123);
+        assert_eq!(user.telegram_id, Some(123));
+        assert!(user.email.is_none());
+    }
+
+    #[test]
+    fn from_telegram_with_name_sets_both() {
+        let user = RUser::from_telegram_with_name(123, "John");
+
+        assert_eq!(user.telegram_id, Some(123));
+        assert_eq!(user.name.as_deref(), Some("John"));
+        assert!(user.email.is_none());
+    }
+
+    #[test]
+    fn from_email_with_name_sets_both() {
+        let user = RUser::from_email_with_name("test@example.com", String::from("Jane"));
+
+        assert_eq!(user.email.as_deref(), Some("test@example.com"));
+        assert_eq!(user.name.as_deref(), Some("Jane"));
+        assert!(user.telegram_id.is_none());
+    }
+
+    #[test]
+    fn diff_reports_changed_fields() {
+        let before = RUser::from_telegram(123);
+        let mut after = before.clone();
+        after.name = Some("John".into());
+        after.avatar_url = Some("https://cdn.example.com/a.png".into());
+        after.external_ids.insert("github".into(), "583231".into());
+
+        let changes = before.diff(&after);
+
+        assert_eq!(
+            changes,
+            vec![
+                FieldChange {
+                    field: "name",
+                    from:  "null".into(),
+                    to:    "John".into()
+                },
+                FieldChange {
+                    field: "avatar_url",
+                    from:  "null".into(),
+                    to:    "https://cdn.example.com/a.png".into()
+                },
+                FieldChange {
+                    field: "external_ids",
+                    from:  "{}".into(),
+                    to:    r#"{"github": "583231"}"#.into()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_of_identical_users_is_empty() {
+        let user = RUser::from_email("test@example.com");
+
+        assert!(user.diff(&user.clone()).is_empty());
+    }
+
+    #[test]
+    fn diff_redacts_contact_fields() {
+        let before = RUser::from_email("old@example.com");
+        let mut after = before.clone();
+        after.email = Some("new@example.com".into());
+        after.phone = Some("+14155551234".into());
+        after.telegram_id = Some(42);
+
+        let changes = before.diff(&after);
+        let fields: Vec<_> = changes.iter().map(|c| c.field).collect();
+
+        assert_eq!(fields, ["email", "phone", "telegram_id"]);
+        for change in &changes {
+            assert_eq!(change.from, FieldChange::REDACTED);
+            assert_eq!(change.to, FieldChange::REDACTED);
+        }
+    }
+
+    #[test]
+    fn diff_without_timestamps_skips_created_and_updated() {
+        let before = RUser::empty();
+        let mut after = before.clone();
+        after.updated_at = DateTime::UNIX_EPOCH;
+        after.created_at = DateTime::UNIX_EPOCH;
+        after.gender = Some(Gender::Female);
+
+        let all: Vec<_> = before.diff(&after).iter().map(|c| c.field).collect();
+        assert_eq!(all, ["gender", "created_at", "updated_at"]);
+
+        let content = before.diff_without_timestamps(&after);
+        assert_eq!(content.len(), 1);
+        assert_eq!(content[0].field, "gender");
+        assert_eq!(content[0].to, "female");
+    }
+
+    #[test]
+    fn field_mask_of_empty_user_is_zero() {
+        assert_eq!(RUser::empty().field_mask(), 0);
+    }
+
+    #[test]
+    fn field_mask_of_telegram_user_with_name() {
+        let user = RUser::from_telegram_with_name(123, "John");
+
+        assert_eq!(user.field_mask(), 0b0100_0001);
+        assert_eq!(
+            user.field_mask(),
+            RUser::MASK_NAME | RUser::MASK_TELEGRAM_ID
+        );
+    }
+
+    #[test]
+    fn field_mask_tracks_soft_delete() {
+        let mut user = RUser::from_email("a@example.com");
+        user.soft_delete();
+
+        assert_eq!(
+            user.field_mask(),
+            RUser::MASK_EMAIL | RUser::MASK_DELETED_AT
+        );
+    }
+
+    #[test]
+    fn completion_of_empty_profile_is_zero() {
+        assert_eq!(RUser::from_email("test@example.com").completion(), 0);
+    }
+
+    #[test]
+    fn completion_of_full_profile_is_hundred() {
+        let mut user = RUser::from_telegram(123);
+        user.name = Some("John".into());
+        user.gender = Some(Gender::Male);
+        user.birth_date = NaiveDate::from_ymd_opt(1990, 1, 15);
+        user.avatar_url = Some("https://cdn.example.com/a.png".into());
+        user.confession_id = Some(ConfessionId(Uuid::now_v7()));
+
+        assert_eq!(user.completion(), 100);
+
+        user.birth_date = None;
+        assert_eq!(user.completion(), 80);
+    }
+
+    #[test]
+    fn from_email_sets_email() {
+        let user = RUser::from_email("test@example.com");
+        assert_eq!(user.email.as_deref(), Some("test@example.com"));
+        assert!(user.telegram_id.is_none());
+    }
+
+    #[test]
+    fn from_phone_sets_phone() {
+        let user = RUser::from_phone("+14155551234");
+        assert_eq!(user.phone.as_deref(), Some("+14155551234"));
+    }
+
+    #[test]
+    fn empty_has_only_id() {
+        let user = RUser::empty();
+        assert!(user.name.is_none());
+        assert!(user.email.is_none());
+        assert!(user.telegram_id.is_none());
+    }
+
+    #[test]
+    fn with_id_sets_specific_id() {
+        let id = Uuid::nil();
+        let user = RUser::with_id(id);
+        assert_eq!(user.id, id);
+    }
+
+    #[test]
+    fn try_from_valid_telegram_request() {
+        let req = CreateUserRequest::telegram(123456789);
+        let id = req.id;
+
+        let user = RUser::try_from(req).unwrap();
+
+        assert_eq!(user.id, id);
+        assert_eq!(user.telegram_id, Some(123456789));
+        assert!(user.email.is_none());
+        assert!(user.phone.is_none());
+    }
+
+    #[test]
+    fn try_from_rejects_invalid_email() {
+        let err = RUser::try_from(CreateUserRequest::email("not-an-email")).unwrap_err();
+
+        assert!(err.field_errors().contains_key("email"));
+    }
+
+    #[test]
+    fn telegram_id_accepts_number_and_numeric_string() {
+        let key = if cfg!(feature = "camel") {
+            "telegramId"
+        } else {
+            "telegram_id"
+        };
+        let mut json = serde_json::to_value(RUser::with_id(Uuid::nil())).unwrap();
+
+        json[key] = serde_json::json!(123);
+        let from_number: RUser = serde_json::from_value(json.clone()).unwrap();
+        json[key] = serde_json::json!("123");
+        let from_string: RUser = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(from_number.telegram_id, Some(123));
+        assert_eq!(from_string.telegram_id, Some(123));
+        assert_eq!(serde_json::to_value(&from_string).unwrap()[key], 123);
+
+        json[key] = serde_json::json!("12abc");
+        assert!(serde_json::from_value::<RUser>(json.clone()).is_err());
+
+        json.as_object_mut().unwrap().remove(key);
+        assert!(
+            serde_json::from_value::<RUser>(json)
+                .unwrap()
+                .telegram_id
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn rehydrate_preserves_past_timestamps() {
+        let created = DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let updated = DateTime::parse_from_rfc3339("2021-06-15T12:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let user = RUser::rehydrate(Uuid::nil(), created, updated, 5);
+
+        assert_eq!(user.id, Uuid::nil());
+        assert_eq!(user.created_at, created);
+        assert_eq!(user.updated_at, updated);
+        assert_eq!(user.version, 5);
+        assert!(user.created_at < Utc::now());
+    }
+
+    #[test]
+    fn timestamps_serialize_with_millis() {
+        let at = DateTime::parse_from_rfc3339("2025-03-04T05:06:07.8Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut user = RUser::empty();
+        user.created_at = at;
+        user.updated_at = DateTime::UNIX_EPOCH;
+
+        let value = serde_json::to_value(&user).unwrap();
+        let (created, updated) = if cfg!(feature = "camel") {
+            (&value["createdAt"], &value["updatedAt"])
+        } else {
+            (&value["created_at"], &value["updated_at"])
+        };
+
+        assert_eq!(*created, "2025-03-04T05:06:07.800Z");
+        assert_eq!(*updated, "1970-01-01T00:00:00.000Z");
+    }
+
+    #[test]
+    fn timestamps_roundtrip_and_accept_standard_rfc3339() {
+        #[derive(Serialize, Deserialize)]
+        struct Stamp {
+            #[serde(with = "rfc3339_millis")]
+            at: DateTime<Utc>
+        }
+
+        let at = DateTime::parse_from_rfc3339("2025-03-04T05:06:07.123Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let json = serde_json::to_string(&Stamp {
+            at
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"at":"2025-03-04T05:06:07.123Z"}"#);
+        assert_eq!(serde_json::from_str::<Stamp>(&json).unwrap().at, at);
+
+        let offset: Stamp = serde_json::from_str(r#"{"at":"2025-03-04T08:06:07+03:00"}"#).unwrap();
+        assert_eq!(offset.at.to_rfc3339(), "2025-03-04T05:06:07+00:00");
+
+        assert!(serde_json::from_str::<Stamp>(r#"{"at":"yesterday"}"#).is_err());
+    }
+
+    #[test]
+    fn from_parts_matches_struct_literal() {
+        let id = UserId::new();
+        let confession_id = ConfessionId(Uuid::now_v7());
+        let birth_date = NaiveDate::from_ymd_opt(1990, 1, 15).unwrap();
+        let created_at = DateTime::UNIX_EPOCH;
+        let updated_at = Utc::now();
+        let deleted_at = Some(updated_at);
+        let external_ids = BTreeMap::from([("google".to_owned(), "1098765".to_owned())]);
+
+        let from_parts = RUser::from_parts(
+            id,
+            Some("Alice".into()),
+            Some(Gender::Female),
+            Some(birth_date),
+            Some(confession_id),
+            Some("alice@example.com".into()),
+            Some("+14155551234".into()),
+            Some(123),
+            true,
+            false,
+            Some("https://example.com/a.png".into()),
+            NotificationPrefs::default(),
+            external_ids.clone(),
+            SignupSource::Email,
+            serde_json::json!({ "plan": "pro" }),
+            created_at,
+            updated_at,
+            deleted_at,
+            7
+        );
+        let literal = RUser {
+            id,
+            name: Some("Alice".into()),
+            gender: Some(Gender::Female),
+            birth_date: Some(birth_date),
+            confession_id: Some(confession_id),
+            email: Some("alice@example.com".into()),
+            phone: Some("+14155551234".into()),
+            telegram_id: Some(123),
+            email_verified: true,
+            phone_verified: false,
+            avatar_url: Some("https://example.com/a.png".into()),
+            notification_prefs: NotificationPrefs::default(),
+            external_ids,
+            signup_source: SignupSource::Email,
+            metadata: serde_json::json!({ "plan": "pro" }),
+            created_at,
+            updated_at,
+            deleted_at,
+            version: 7
+        };
+
+        assert_eq!(
+            serde_json::to_value(&from_parts).unwrap(),
+            serde_json::to_value(&literal).unwrap()
+        );
+    }
+
+    #[test]
+    fn with_role_returns_matching_auth() {
+        let (user, auth) = RUser::from_telegram(123).with_role(RUserRole::Admin);
+
+        assert_eq!(auth.id, user.id);
+        assert_eq!(auth.telegram_id, Some(123));
+        assert_eq!(auth.role, RUserRole::Admin);
+    }
+
+    #[test]
+    #[cfg(feature = "schemars")]
+    fn json_schema_lists_user_properties() {
+        let schema = serde_json::to_value(schemars::schema_for!(RUser)).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+
+        for key in ["id", "name", "gender", "email", "phone", "version"] {
+            assert!(properties.contains_key(key), "missing property {key}");
+        }
+        assert_eq!(properties["id"]["format"], "uuid");
+    }
+
+    #[test]
+    fn to_claims_uses_user_id_and_ttl() {
+        let user = RUser::from_email("john@example.com");
+        let claims = user.to_claims(RUserRole::Premium, Duration::from_secs(3600));
+
+        assert_eq!(claims.user_id(), user.id);
+        assert_eq!(claims.role, RUserRole::Premium);
+        assert!(!claims.is_expired());
+
+        let iat = claims.iat.unwrap();
+        assert_eq!(claims.exp, iat + 3600);
+    }
+
+    #[test]
+    fn to_claims_saturates_huge_ttl() {
+        let claims = RUser::empty().to_claims(RUserRole::User, Duration::from_secs(u64::MAX));
+
+        assert_eq!(claims.exp, usize::MAX);
+    }
+
+    #[test]
+    fn new_user_starts_at_version_one() {
+        assert_eq!(RUser::empty().version, 1);
+    }
+
+    #[test]
+    fn create_ruser_request_starts_at_version_one() {
+        let user = RUser::from(CreateRUserRequest {
+            email:       Some("user@example.com".into()),
+            phone:       None,
+            telegram_id: None
+        });
+
+        assert_eq!(user.version, initial_version());
+        assert_eq!(user.version, 1);
+    }
+
+    #[test]
+    fn apply_update_increments_version() {
+        let mut user = RUser::empty();
+        let update = UpdateProfileRequest {
+            name: Some("Alice".into()),
+            gender: Some(Gender::Female),
+            ..Default::default()
+        };
+
+        assert!(user.apply_update(&update));
+        assert_eq!(user.version, 2);
+        assert_eq!(user.name.as_deref(), Some("Alice"));
+        assert_eq!(user.gender, Some(Gender::Female));
+    }
+
+    #[test]
+    fn apply_update_noop_keeps_version() {
+        let mut user = RUser::empty();
+        user.name = Some("Alice".into());
+        let before = user.updated_at;
+
+        let same = UpdateProfileRequest {
+            name: Some("Alice".into()),
+            ..Default::default()
+        };
+
+        assert!(!user.apply_update(&same));
+        assert!(!user.apply_update(&UpdateProfileRequest::empty()));
+        assert_eq!(user.version, 1);
+        assert_eq!(user.updated_at, before);
+    }
+
+    #[test]
+    fn apply_update_replaces_notification_prefs() {
+        let mut user = RUser::empty();
+        let prefs = NotificationPrefs {
+            telegram: false,
+            push: true,
+            ..Default::default()
+        };
+        let update = UpdateProfileRequest {
+            notification_prefs: Some(prefs),
+            ..Default::default()
+        };
+
+        assert!(user.apply_update(&update));
+        assert_eq!(user.notification_prefs, prefs);
+        assert!(!user.accepts(Channel::Telegram));
+        assert!(user.accepts(Channel::Push));
+        assert_eq!(user.version, 2);
+
+        assert!(!user.apply_update(&update));
+        assert!(!user.apply_update(&UpdateProfileRequest::empty()));
+        assert_eq!(user.notification_prefs, prefs);
+        assert_eq!(user.version, 2);
+    }
+
+    #[test]
+    fn apply_patch_clear_nulls_field_and_unchanged_keeps_it() {
+        let mut user = RUser::empty();
+        user.name = Some("Alice".into());
+        user.gender = Some(Gender::Female);
+        let before = user.updated_at;
+
+        let req: PatchProfileRequest = serde_json::from_str(r#"{"gender":null}"#).unwrap();
+
+        assert!(user.apply_patch(req));
+        assert!(user.gender.is_none());
+        assert_eq!(user.name.as_deref(), Some("Alice"));
+        assert_eq!(user.version, 2);
+        assert!(user.updated_at >= before);
+    }
+
+    #[test]
+    fn apply_patch_sets_values() {
+        let mut user = RUser::empty();
+        let confession_id = Uuid::now_v7();
+        let req = PatchProfileRequest {
+            name: Patch::Set("Bob".into()),
+            confession_id: Patch::Set(confession_id),
+            ..Default::default()
+        };
+
+        assert!(user.apply_patch(req));
+        assert_eq!(user.name.as_deref(), Some("Bob"));
+        assert_eq!(user.confession_id, Some(ConfessionId::from(confession_id)));
+        assert!(user.gender.is_none());
+    }
+
+    #[test]
+    fn apply_patch_noop_keeps_version() {
+        let mut user = RUser::empty();
+        let before = user.updated_at;
+
+        let clear_absent = PatchProfileRequest {
+            gender: Patch::Clear,
+            ..Default::default()
+        };
+
+        assert!(!user.apply_patch(PatchProfileRequest::default()));
+        assert!(!user.apply_patch(clear_absent));
+        assert_eq!(user.version, 1);
+        assert_eq!(user.updated_at, before);
+    }
+
+    #[test]
+    fn validate_checks_contacts_and_profile() {
+        assert!(RUser::from_phone("+14155551234").validate().is_ok());
+        assert!(RUser::from_phone("14155551234").validate().is_err());
+
+        let mut user = RUser::from_telegram(123);
+        user.name = Some("X".into());
+        assert!(user.validate().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn arbitrary_users_validate() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let seed: Vec<u8> = (0..4096_u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+            .collect();
+
+        for offset in (0..seed.len()).step_by(64) {
+            let mut u = Unstructured::new(&seed[offset..]);
+            let user = RUser::arbitrary(&mut u).unwrap();
+
+            assert!(user.validate().is_ok(), "{user:?}");
+            assert!(user.created_at <= user.updated_at);
+            assert!(user.email.is_some() || !user.email_verified);
+
+            let claims = Claims::arbitrary(&mut u).unwrap();
+            assert!(claims.iat.is_none_or(|iat| iat <= claims.exp));
+            let permissions = crate::Permissions::arbitrary(&mut u).unwrap();
+            assert!(crate::Permissions::from_bits(permissions.bits()).is_some());
+        }
+    }
+
+    #[test]
+    fn to_value_selected_includes_only_requested_fields() {
+        let mut user = RUser::from_email("user@example.com");
+        user.name = Some("Alice".into());
+
+        let value = user.to_value_selected(&["id", "name"]);
+
+        assert_eq!(value, serde_json::json!({ "id": user.id, "name": "Alice" }));
+    }
+
+    #[test]
+    fn to_value_selected_rejects_sensitive_fields() {
+        let user = RUser::from_email("user@example.com");
+
+        let value = user.to_value_selected(&["email", "telegram_id", "unknown"]);
+        assert_eq!(value, serde_json::json!({}));
+
+        let value = user.to_value_selected_with(&["email"], &["email"]);
+        assert_eq!(value["email"], "user@example.com");
+    }
+
+    #[test]
+    fn to_value_selected_drops_private_profile_fields() {
+        let mut user = RUser::from_email("user@example.com");
+        user.birth_date = NaiveDate::from_ymd_opt(1990, 5, 17);
+        user.confession_id = Some(ConfessionId(Uuid::now_v7()));
+
+        let value = user.to_value_selected(&["id", "birth_date", "confessionId"]);
+        assert_eq!(value, serde_json::json!({ "id": user.id }));
+
+        let value = user.to_value_selected_with(&["birth_date"], &["birth_date"]);
+        assert_eq!(value.as_object().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn to_value_selected_matches_full_serialization() {
+        let user = RUser::from_telegram(123);
+        let full = serde_json::to_value(&user).unwrap();
+        let key = if cfg!(feature = "camel") {
+            "createdAt"
+        } else {
+            "created_at"
+        };
+
+        let fields = ["created_at", "version"];
+        let value = user.to_value_selected_with(&fields, &fields);
+
+        assert_eq!(value[key], full[key]);
+        assert_eq!(value["version"], full["version"]);
+    }
+
+    #[test]
+    fn eq_ignoring_timestamps_skips_only_timestamps() {
+        let a = RUser::from_email("user@example.com");
+        let mut b = a.clone();
+        b.updated_at = a.updated_at + chrono::Duration::minutes(5);
+
+        assert!(a.eq_ignoring_timestamps(&b));
+        assert_ne!(a, b);
+
+        b.name = Some("Alice".into());
+        assert!(!a.eq_ignoring_timestamps(&b));
+    }
+
+    #[test]
+    fn constructors_record_signup_source() {
+        assert_eq!(
+            RUser::from_telegram(123).signup_source,
+            SignupSource::Telegram
+        );
+        assert_eq!(RUser::empty().signup_source, SignupSource::Unknown);
+        assert_eq!(
+            RUser::from_email("a@b.co").signup_source,
+            SignupSource::Email
+        );
+        assert_eq!(
+            RUser::from_phone("+14155551234").signup_source,
+            SignupSource::Phone
+        );
+
+        let user = RUser::try_from(CreateUserRequest::phone("+14155551234")).unwrap();
+        assert_eq!(user.signup_source, SignupSource::Phone);
+    }
+
+    #[test]
+    fn signup_source_defaults_when_missing_from_json() {
+        let key = if cfg!(feature = "camel") {
+            "signupSource"
+        } else {
+            "signup_source"
+        };
+        let mut json = serde_json::to_value(RUser::from_telegram(123)).unwrap();
+        assert_eq!(json[key], "telegram");
+
+        json.as_object_mut().unwrap().remove(key);
+        let user: RUser = serde_json::from_value(json).unwrap();
+        assert_eq!(user.signup_source, SignupSource::Unknown);
+    }
+
+    #[test]
+    fn redact_for_user_non_owner_is_public() {
+        let user = RUser::from_email("alice@example.com");
+
+        let RedactedUser::Public(public) = user.redact_for(RUserRole::User, false) else {
+            panic!("expected public projection");
+        };
+        assert_eq!(public.id, user.id);
+        assert!(
+            !serde_json::to_string(&public)
+                .unwrap()
+                .contains("alice@example.com")
+        );
+    }
+
+    #[test]
+    fn redact_for_admin_includes_contacts() {
+        let mut user = RUser::from_email("alice@example.com");
+        user.phone = Some("+14155551234".into());
+
+        let RedactedUser::Admin(admin) = user.redact_for(RUserRole::Admin, false) else {
+            panic!("expected admin projection");
+        };
+        assert_eq!(admin.email.as_deref(), Some("alice@example.com"));
+        assert_eq!(admin.phone.as_deref(), Some("+14155551234"));
+    }
+
+    #[test]
+    fn redact_for_owner_is_full() {
+        let user = RUser::from_email("alice@example.com");
+
+        let RedactedUser::Full(full) = user.redact_for(RUserRole::User, true) else {
+            panic!("expected full user");
+        };
+        assert_eq!(*full, user);
+    }
+
+    #[test]
+    fn metadata_set_and_read() {
+        let mut user = RUser::empty();
+        assert!(user.metadata.is_null());
+
+        user.set_meta("theme", "dark");
+        user.set_meta("beta", true);
+
+        assert_eq!(user.get_meta("theme"), Some(&serde_json::json!("dark")));
+        assert_eq!(user.get_meta("beta"), Some(&serde_json::json!(true)));
+        assert_eq!(user.get_meta("missing"), None);
+    }
+
+    #[test]
+    fn metadata_serializes() {
+        let mut user = RUser::empty();
+        user.set_meta("theme", "dark");
+
+        let json = serde_json::to_value(&user).unwrap();
+        assert_eq!(json["metadata"], serde_json::json!({ "theme": "dark" }));
+
+        let parsed: RUser = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.get_meta("theme"), Some(&serde_json::json!("dark")));
+    }
+
+    #[test]
+    fn metadata_not_in_public_projection() {
+        let mut user = RUser::empty();
+        user.set_meta("theme", "dark");
+
+        let json = serde_json::to_value(crate::RUserPublic::from(&user)).unwrap();
+        assert!(json.get("metadata").is_none());
+        assert_eq!(user.to_value_selected(&["metadata"]), serde_json::json!({}));
+    }
+
+    #[test]
+    fn set_meta_bumps_version_only_on_change() {
+        let mut user = RUser::empty();
+
+        user.set_meta("theme", "dark");
+        assert_eq!(user.version, 2);
+
+        user.set_meta("theme", "dark");
+        assert_eq!(user.version, 2);
+
+        user.set_meta("theme", "light");
+        assert_eq!(user.version, 3);
+    }
+
+    #[test]
+    fn version_defaults_when_missing_from_json() {
+        let mut value = serde_json::to_value(RUser::empty()).unwrap();
+        value
+            .as_object_mut()
+            .unwrap()
+            .retain(|key, _| key != "version");
+
+        let user: RUser = serde_json::from_value(value).unwrap();
+        assert_eq!(user.version, 1);
+    }
+
+    #[test]
+    fn primary_contact_prefers_email() {
+        let mut user = RUser::from_telegram(123);
+        user.phone = Some("+14155551234".into());
+        user.email = Some("user@example.com".into());
+
+        assert_eq!(
+            user.primary_contact(),
+            Some(Contact::Email("user@example.com".into()))
+        );
+    }
+
+    #[test]
+    fn primary_contact_falls_back_to_phone() {
+        let mut user = RUser::from_telegram(123);
+        user.phone = Some("+14155551234".into());
+
+        assert_eq!(
+            user.primary_contact(),
+            Some(Contact::Phone("+14155551234".into()))
+        );
+    }
+
+    #[test]
+    fn primary_contact_falls_back_to_telegram() {
+        let user = RUser::from_telegram(123);
+
+        assert_eq!(user.primary_contact(), Some(Contact::Telegram(123)));
+    }
+
+    #[test]
+    fn primary_contact_none_when_empty() {
+        assert_eq!(RUser::empty().primary_contact(), None);
+    }
+
+    #[test]
+    fn auth_methods_telegram_only() {
+        let user = RUser::from_telegram(123);
+
+        assert_eq!(user.auth_methods(), vec![AuthMethod::Telegram]);
+        assert_eq!(user.primary_auth_method(), AuthMethod::Telegram);
+    }
+
+    #[test]
+    fn auth_methods_multiple() {
+        let mut user = RUser::from_email("user@example.com");
+        user.phone = Some("+14155551234".into());
+        user.telegram_id = Some(123);
+
+        assert_eq!(
+            user.auth_methods(),
+            vec![AuthMethod::Telegram, AuthMethod::Email, AuthMethod::Phone]
+        );
+        assert_eq!(user.primary_auth_method(), AuthMethod::Telegram);
+    }
+
+    #[test]
+    fn auth_methods_empty_user() {
+        let user = RUser::empty();
+
+        assert!(user.auth_methods().is_empty());
+        assert_eq!(user.primary_auth_method(), AuthMethod::None);
+    }
+
+    #[test]
+    fn user_id_serializes_as_bare_uuid() {
+        let id = UserId::nil();
+        let json = serde_json::to_string(&id).unwrap();
+
+        assert_eq!(json, "\"00000000-0000-0000-0000-000000000000\"");
+        assert_eq!(serde_json::from_str::<UserId>(&json).unwrap(), id);
+    }
+
+    #[test]
+    fn user_id_from_str() {
+        let raw = Uuid::now_v7();
+        let parsed: UserId = raw.to_string().parse().unwrap();
+
+        assert_eq!(parsed, raw);
+        assert_eq!(parsed.to_string(), raw.to_string());
+        assert!("not-a-uuid".parse::<UserId>().is_err());
+    }
+
+    #[test]
+    fn create_ruser_request_gets_fresh_ids() {
+        let req = CreateRUserRequest {
+            email:       None,
+            phone:       None,
+            telegram_id: Some(123)
+        };
+
+        let first = RUser::from(req.clone());
+        let second = RUser::from(req);
+
+        assert!(!first.id.is_nil());
+        assert!(!second.id.is_nil());
+        assert_ne!(first.id, second.id);
+        assert_eq!(first.telegram_id, Some(123));
+    }
+
+    #[test]
+    fn user_id_default_is_nil() {
+        assert!(UserId::default().is_nil());
+        assert_eq!(UserId::default(), UserId::default());
+        assert!(!UserId::new().is_nil());
+    }
+
+    #[test]
+    fn user_id_uuid_conversions() {
+        let raw = Uuid::now_v7();
+        let id = UserId::from(raw);
+
+        assert_eq!(Uuid::from(id), raw);
+        assert_eq!(id.as_uuid(), &raw);
+    }
+
+    #[test]
+    fn link_external_stores_multiple_providers() {
+        let mut user = RUser::empty();
+        user.link_external("google", "1098765");
+        user.link_external("github", "583231");
+
+        assert_eq!(user.external_id("google"), Some("1098765"));
+        assert_eq!(user.external_id("github"), Some("583231"));
+        assert_eq!(user.external_id("apple"), None);
+    }
+
+    #[test]
+    fn link_external_replaces_existing_subject() {
+        let mut user = RUser::empty();
+        user.link_external("google", "old");
+        user.link_external("google", "new");
+
+        assert_eq!(user.external_id("google"), Some("new"));
+        assert_eq!(user.external_ids.len(), 1);
+    }
+
+    #[test]
+    fn link_external_bumps_version_only_on_change() {
+        let mut user = RUser::empty();
+        user.link_external("google", "1098765");
+        assert_eq!(user.version, 2);
+
+        user.link_external("google", "1098765");
+        assert_eq!(user.version, 2);
+
+        user.link_external("google", "other");
+        assert_eq!(user.version, 3);
+    }
+
+    #[test]
+    fn external_ids_roundtrip_through_json() {
+        let mut user = RUser::from_telegram(123);
+        user.link_external("google", "1098765");
+
+        let json = serde_json::to_string(&user).unwrap();
+        let parsed: RUser = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.external_id("google"), Some("1098765"));
+    }
+
+    #[test]
+    fn contacts_are_unverified_by_default() {
+        let user = RUser::empty();
+        assert!(!user.email_verified);
+        assert!(!user.phone_verified);
+
+        let user = RUser::from_telegram(123);
+        assert!(!user.email_verified);
+        assert!(!user.phone_verified);
+
+        let mut profile = crate::OAuthProfile::new("google", "1098765");
+        profile.email = Some("a@example.com".into());
+        assert!(!RUser::from_oauth(&profile).email_verified);
+
+        let user = RUser::try_from(CreateUserRequest::email("a@example.com")).unwrap();
+        assert!(!user.email_verified);
+    }
+
+    #[test]
+    fn auth_constructors_mark_contact_verified() {
+        assert!(RUser::from_email("a@example.com").email_verified);
+        assert!(RUser::from_email_with_name("a@example.com", "A").email_verified);
+        assert!(RUser::from_phone("+14155551234").phone_verified);
+        assert!(!RUser::from_phone("+14155551234").email_verified);
+    }
+
+    #[test]
+    fn mark_email_verified_sets_flag_once() {
+        let mut user = RUser::with_id(Uuid::nil());
+        user.email = Some("a@example.com".into());
+
+        user.mark_email_verified();
+        assert!(user.email_verified);
+        assert_eq!(user.version, 2);
+
+        user.mark_email_verified();
+        assert_eq!(user.version, 2);
+    }
+
+    #[test]
+    fn mark_verified_without_contact_is_noop() {
+        let mut user = RUser::empty();
+
+        user.mark_email_verified();
+        user.mark_phone_verified();
+
+        assert!(!user.email_verified);
+        assert!(!user.phone_verified);
+        assert_eq!(user.version, 1);
+    }
+
+    #[test]
+    fn new_users_are_not_deleted() {
+        let user = RUser::from_email("test@example.com");
+        assert!(!user.is_deleted());
+        assert!(user.deleted_at.is_none());
+    }
+
+    #[test]
+    fn soft_delete_sets_timestamp() {
+        let mut user = RUser::from_telegram(123);
+        user.soft_delete();
+
+        assert!(user.is_deleted());
+        assert_eq!(user.deleted_at, Some(user.updated_at));
+    }
+
+    #[test]
+    fn soft_delete_keeps_first_timestamp() {
+        let mut user = RUser::from_telegram(123);
+        user.soft_delete();
+        let first = user.deleted_at;
+
+        user.soft_delete();
+
+        assert_eq!(user.deleted_at, first);
+    }
+
+    #[test]
+    fn soft_delete_bumps_version_once() {
+        let mut user = RUser::from_telegram(123);
+        let version = user.version;
+
+        user.soft_delete();
+        assert_eq!(user.version, version + 1);
+
+        user.soft_delete();
+        assert_eq!(user.version, version + 1);
+    }
+
+    #[test]
+    #[cfg(not(feature = "camel"))]
+    fn serializes_to_json() {
+        let user = RUser::from_telegram(123);
+        let json = serde_json::to_string(&user).unwrap();
+        assert!(json.contains("\"telegram_id\":123"));
+    }
+
+    #[test]
+    #[cfg(feature = "camel")]
+    fn serializes_to_camel_case_json() {
+        let mut user = RUser::from_telegram(123);
+        user.birth_date = NaiveDate::from_ymd_opt(1990, 1, 15);
+        let json = serde_json::to_string(&user).unwrap();
+
+        assert!(json.contains("\"telegramId\":123"));
+        assert!(json.contains("\"birthDate\":\"1990-01-15\""));
+        assert!(json.contains("\"createdAt\""));
+        assert!(!json.contains("telegram_id"));
+
+        let parsed: RUser = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.telegram_id, Some(123));
     }
 }
@@ -0,0 +1,207 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! Error types for the user domain.
+//!
+//! This module provides [`UserValidationError`], a friendlier wrapper
+//! around [`validator::ValidationErrors`] for DTO validation failures.
+//!
+//! # Overview
+//!
+//! | Type | Purpose | HTTP Status |
+//! |------|---------|-------------|
+//! | [`UserValidationError`] | DTO validation failure | 422 |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use revelation_user::{UpdateProfileRequest, UserValidationError};
+//! use validator::Validate;
+//!
+//! let req = UpdateProfileRequest {
+//!     name: Some("X".into()),
+//!     ..Default::default()
+//! };
+//!
+//! let err: UserValidationError = req.validate().unwrap_err().into();
+//! assert_eq!(err.to_string(), "validation failed: name: length");
+//! ```
+
+use masterror::AppError;
+use validator::ValidationErrors;
+
+/// Validation failure for user-domain DTOs.
+///
+/// Wraps [`ValidationErrors`] and renders a compact, human-readable
+/// summary listing each invalid field with its message (or error code
+/// when no message is set). Fields are sorted for stable output.
+///
+/// # Conversions
+///
+/// - `From<ValidationErrors>` - wrap raw validator output
+/// - `Into<AppError>` - 422 Unprocessable Entity for HTTP responses
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::AppError;
+/// use revelation_user::{BindEmail, UserValidationError};
+/// use validator::Validate;
+///
+/// let bind = BindEmail {
+///     email: "not-an-email".into()
+/// };
+///
+/// let err = UserValidationError::from(bind.validate().unwrap_err());
+/// assert_eq!(err.fields(), vec!["email"]);
+///
+/// // Ready to return from a handler
+/// let _app: AppError = err.into();
+/// ```
+#[derive(Debug, Clone)]
+pub struct UserValidationError(ValidationErrors);
+
+impl UserValidationError {
+    /// Returns the wrapped validator errors.
+    #[must_use]
+    pub const fn errors(&self) -> &ValidationErrors {
+        &self.0
+    }
+
+    /// Consumes the wrapper and returns the validator errors.
+    #[must_use]
+    pub fn into_inner(self) -> ValidationErrors {
+        self.0
+    }
+
+    /// Returns the names of invalid fields in sorted order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{CreateUserRequest, UserValidationError};
+    /// use validator::Validate;
+    ///
+    /// let mut req = CreateUserRequest::email("invalid");
+    /// req.telegram_id = Some(0);
+    ///
+    /// let err = UserValidationError::from(req.validate().unwrap_err());
+    /// assert_eq!(err.fields(), vec!["email", "telegram_id"]);
+    /// ```
+    #[must_use]
+    pub fn fields(&self) -> Vec<&str> {
+        let mut fields: Vec<&str> = self.0.errors().keys().map(|k| k.as_ref()).collect();
+        fields.sort_unstable();
+        fields
+    }
+}
+
+impl From<ValidationErrors> for UserValidationError {
+    fn from(errors: ValidationErrors) -> Self {
+        Self(errors)
+    }
+}
+
+impl core::fmt::Display for UserValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let field_errors = self.0.field_errors();
+        let mut fields: Vec<_> = field_errors.iter().collect();
+        fields.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+        f.write_str("validation failed")?;
+
+        for (i, (field, errors)) in fields.into_iter().enumerate() {
+            f.write_str(if i == 0 { ": " } else { "; " })?;
+            write!(f, "{field}: ")?;
+
+            for (j, error) in errors.iter().enumerate() {
+                if j > 0 {
+                    f.write_str(", ")?;
+                }
+                match &error.message {
+                    Some(message) => f.write_str(message)?,
+                    None => f.write_str(&error.code)?
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for UserValidationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl From<UserValidationError> for AppError {
+    /// Converts into a 422 Unprocessable Entity error.
+    fn from(err: UserValidationError) -> Self {
+        AppError::validation(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use masterror::AppErrorKind;
+    use validator::Validate;
+
+    use super::*;
+    use crate::{BindPhone, CreateUserRequest, UpdateProfileRequest};
+
+    #[test]
+    fn display_lists_invalid_field() {
+        let req = UpdateProfileRequest {
+            name: Some("X".into()),
+            ..Default::default()
+        };
+
+        let err = UserValidationError::from(req.validate().unwrap_err());
+
+        assert_eq!(err.to_string(), "validation failed: name: length");
+    }
+
+    #[test]
+    fn display_sorts_multiple_fields() {
+        let req = CreateUserRequest {
+            id:          uuid::Uuid::nil(),
+            telegram_id: Some(0),
+            email:       Some("invalid".into()),
+            phone:       None
+        };
+
+        let err = UserValidationError::from(req.validate().unwrap_err());
+
+        assert_eq!(
+            err.to_string(),
+            "validation failed: email: email; telegram_id: range"
+        );
+        assert_eq!(err.fields(), vec!["email", "telegram_id"]);
+    }
+
+    #[test]
+    fn source_is_validation_errors() {
+        let bind = BindPhone {
+            phone: "123".into()
+        };
+
+        let err = UserValidationError::from(bind.validate().unwrap_err());
+
+        assert!(std::error::Error::source(&err).is_some());
+        assert!(err.errors().field_errors().contains_key("phone"));
+        assert!(!err.into_inner().is_empty());
+    }
+
+    #[test]
+    fn converts_into_validation_app_error() {
+        let req = UpdateProfileRequest {
+            name: Some("X".into()),
+            ..Default::default()
+        };
+
+        let app: AppError = UserValidationError::from(req.validate().unwrap_err()).into();
+
+        assert_eq!(app.kind, AppErrorKind::Validation);
+    }
+}
@@ -122,7 +122,16 @@
 //!
 //! # JSON Serialization
 //!
-//! Uses `#[serde(flatten)]` for flat JSON structure:
+//! Uses `#[serde(flatten)]` for flat JSON structure.
+//!
+//! Custom fields share one JSON object with the [`RUser`] fields, so a
+//! custom field named like a user field (e.g. `email`) would make the
+//! JSON ambiguous. [`extend_user!`] rejects such names at compile time
+//! using [`RUser::is_field`].
+//!
+//! Serde does not support `deny_unknown_fields` together with
+//! `flatten`, so extended types are never strict: the `strict` feature
+//! only affects the crate's own request DTOs.
 //!
 //! ```rust,ignore
 //! use revelation_user::{extend_user, RUser};
@@ -175,6 +184,7 @@
 //! ```
 //!
 //! [`RUser`]: crate::RUser
+//! [`RUser::is_field`]: crate::RUser::is_field
 //! [`extend_user!`]: crate::extend_user
 
 /// Creates an extended user type with custom fields.
@@ -215,6 +225,11 @@
 /// - `type_name.to_public()` - Convert to RUserPublic projection
 /// - `type_name.to_auth(role)` - Convert to RUserAuth projection
 ///
+/// # Generated Builder
+///
+/// The bon builder is named `TypeNameBuilder`, with its type states in
+/// `type_name_builder`, both emitted next to the struct.
+///
 /// # Compile-Time Safety
 ///
 /// The generated builder uses bon's type-state pattern to ensure
@@ -227,6 +242,20 @@
 ///     .build();
 /// ```
 ///
+/// # Field Name Collisions
+///
+/// Custom fields are flattened next to the [`RUser`] fields, so their
+/// names must not repeat one. Colliding names fail to compile:
+///
+/// ```rust,compile_fail
+/// revelation_user::extend_user! {
+///     pub struct Clash {
+///         // `email` is already an RUser field
+///         pub email: String,
+///     }
+/// }
+/// ```
+///
 /// [`RUser`]: crate::RUser
 #[macro_export]
 macro_rules! extend_user {
@@ -239,177 +268,231 @@ macro_rules! extend_user {
             ),* $(,)?
         }
     ) => {
-        $(#[$meta])*
-        #[derive(
-            ::core::fmt::Debug,
-            ::core::clone::Clone,
-            ::serde::Serialize,
-            ::serde::Deserialize,
-            ::bon::Builder
-        )]
-        $vis struct $name {
-            /// Base user data from revelation-user.
-            #[serde(flatten)]
-            #[builder(into)]
-            inner: $crate::RUser,
+        $crate::extend::__pastey::paste! {
+            $crate::__extend_user_struct! {
+                $(#[$meta])*
+                #[derive(
+                    ::core::fmt::Debug,
+                    ::core::clone::Clone,
+                    ::serde::Serialize,
+                    ::serde::Deserialize,
+                    ::bon::Builder
+                )]
+                #[builder(builder_type = [<$name Builder>], state_mod = [<$name:snake _builder>])]
+                $vis struct $name {
+                    /// Base user data from revelation-user.
+                    #[serde(flatten)]
+                    #[builder(into)]
+                    inner: $crate::RUser,
+
+                    $(
+                        $(#[$field_meta])*
+                        $field_vis $field: $ty,
+                    )*
+                }
+            }
 
             $(
-                $(#[$field_meta])*
-                $field_vis $field: $ty,
+                const _: () = ::core::assert!(
+                    !$crate::RUser::is_field(::core::stringify!($field)),
+                    ::core::concat!(
+                        "extend_user!: field `",
+                        ::core::stringify!($field),
+                        "` collides with a flattened RUser field"
+                    )
+                );
             )*
-        }
 
-        impl ::core::ops::Deref for $name {
-            type Target = $crate::RUser;
+            impl ::core::ops::Deref for $name {
+                type Target = $crate::RUser;
 
-            #[inline]
-            fn deref(&self) -> &Self::Target {
-                &self.inner
+                #[inline]
+                fn deref(&self) -> &Self::Target {
+                    &self.inner
+                }
             }
-        }
 
-        impl ::core::ops::DerefMut for $name {
-            #[inline]
-            fn deref_mut(&mut self) -> &mut Self::Target {
-                &mut self.inner
+            impl ::core::ops::DerefMut for $name {
+                #[inline]
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.inner
+                }
             }
-        }
 
-        impl ::core::convert::AsRef<$crate::RUser> for $name {
-            #[inline]
-            fn as_ref(&self) -> &$crate::RUser {
-                &self.inner
+            impl ::core::convert::AsRef<$crate::RUser> for $name {
+                #[inline]
+                fn as_ref(&self) -> &$crate::RUser {
+                    &self.inner
+                }
             }
-        }
 
-        impl ::core::convert::AsMut<$crate::RUser> for $name {
-            #[inline]
-            fn as_mut(&mut self) -> &mut $crate::RUser {
-                &mut self.inner
+            impl ::core::convert::AsMut<$crate::RUser> for $name {
+                #[inline]
+                fn as_mut(&mut self) -> &mut $crate::RUser {
+                    &mut self.inner
+                }
             }
-        }
 
-        impl ::core::convert::From<$name> for $crate::RUser {
-            #[inline]
-            fn from(ext: $name) -> Self {
-                ext.inner
+            impl ::core::convert::From<$name> for $crate::RUser {
+                #[inline]
+                fn from(ext: $name) -> Self {
+                    ext.inner
+                }
             }
-        }
 
-        impl ::core::convert::From<$name> for $crate::RUserPublic {
-            #[inline]
-            fn from(ext: $name) -> Self {
-                ext.inner.into()
-            }
-        }
-
-        impl $name {
-            #[doc = concat!("Create [`", stringify!($name), "`] builder from Telegram authentication.")]
-            ///
-            /// Initializes the inner [`RUser`] with the provided Telegram ID
-            /// and returns a builder for setting remaining fields.
-            ///
-            /// # Example
-            ///
-            /// ```rust,ignore
-            #[doc = concat!("let user = ", stringify!($name), "::from_telegram(123456789)")]
-            ///     .company_id(id)
-            ///     .build();
-            /// ```
-            #[inline]
-            #[must_use]
-            pub fn from_telegram(
-                telegram_id: i64
-            ) -> $crate::extend::ExtendedBuilder<Self, impl FnOnce($crate::RUser) -> <Self as ::bon::Builder>::Builder> {
-                $crate::extend::ExtendedBuilder::new(
-                    $crate::RUser::from_telegram(telegram_id),
-                    |user| Self::builder().inner(user)
-                )
-            }
-
-            #[doc = concat!("Create [`", stringify!($name), "`] builder from email authentication.")]
-            ///
-            /// Initializes the inner [`RUser`] with the provided email
-            /// and returns a builder for setting remaining fields.
-            #[inline]
-            #[must_use]
-            pub fn from_email(
-                email: impl ::core::convert::Into<String>
-            ) -> $crate::extend::ExtendedBuilder<Self, impl FnOnce($crate::RUser) -> <Self as ::bon::Builder>::Builder> {
-                $crate::extend::ExtendedBuilder::new(
-                    $crate::RUser::from_email(email),
-                    |user| Self::builder().inner(user)
-                )
-            }
-
-            #[doc = concat!("Create [`", stringify!($name), "`] builder from phone authentication.")]
-            ///
-            /// Initializes the inner [`RUser`] with the provided phone number
-            /// and returns a builder for setting remaining fields.
-            #[inline]
-            #[must_use]
-            pub fn from_phone(
-                phone: impl ::core::convert::Into<String>
-            ) -> $crate::extend::ExtendedBuilder<Self, impl FnOnce($crate::RUser) -> <Self as ::bon::Builder>::Builder> {
-                $crate::extend::ExtendedBuilder::new(
-                    $crate::RUser::from_phone(phone),
-                    |user| Self::builder().inner(user)
-                )
-            }
-
-            #[doc = concat!("Create [`", stringify!($name), "`] builder from existing [`RUser`].")]
-            ///
-            /// Useful when you already have a user and want to extend it.
-            #[inline]
-            #[must_use]
-            pub fn from_user(
-                user: impl ::core::convert::Into<$crate::RUser>
-            ) -> $crate::extend::ExtendedBuilder<Self, impl FnOnce($crate::RUser) -> <Self as ::bon::Builder>::Builder> {
-                $crate::extend::ExtendedBuilder::new(
-                    user.into(),
-                    |user| Self::builder().inner(user)
-                )
+            impl ::core::convert::From<$name> for $crate::RUserPublic {
+                #[inline]
+                fn from(ext: $name) -> Self {
+                    ext.inner.into()
+                }
             }
 
-            /// Get reference to the inner [`RUser`].
-            #[inline]
-            #[must_use]
-            pub const fn as_user(&self) -> &$crate::RUser {
-                &self.inner
-            }
-
-            /// Get mutable reference to the inner [`RUser`].
-            #[inline]
-            #[must_use]
-            pub fn as_user_mut(&mut self) -> &mut $crate::RUser {
-                &mut self.inner
+            impl $name {
+                #[doc = concat!("Create [`", stringify!($name), "`] builder from Telegram authentication.")]
+                ///
+                /// Initializes the inner [`RUser`] with the provided Telegram ID
+                /// and returns a builder for setting remaining fields.
+                ///
+                /// # Example
+                ///
+                /// ```rust,ignore
+                #[doc = concat!("let user = ", stringify!($name), "::from_telegram(123456789)")]
+                ///     .company_id(id)
+                ///     .build();
+                /// ```
+                #[inline]
+                #[must_use]
+                pub fn from_telegram(
+                    telegram_id: i64
+                ) -> $crate::extend::ExtendedBuilder<Self, impl FnOnce($crate::RUser) -> [<$name Builder>]<[<$name:snake _builder>]::SetInner>> {
+                    $crate::extend::ExtendedBuilder::new(
+                        $crate::RUser::from_telegram(telegram_id),
+                        |user| Self::builder().inner(user)
+                    )
+                }
+
+                #[doc = concat!("Create [`", stringify!($name), "`] builder from email authentication.")]
+                ///
+                /// Initializes the inner [`RUser`] with the provided email
+                /// and returns a builder for setting remaining fields.
+                #[inline]
+                #[must_use]
+                pub fn from_email(
+                    email: impl ::core::convert::Into<String>
+                ) -> $crate::extend::ExtendedBuilder<Self, impl FnOnce($crate::RUser) -> [<$name Builder>]<[<$name:snake _builder>]::SetInner>> {
+                    $crate::extend::ExtendedBuilder::new(
+                        $crate::RUser::from_email(email),
+                        |user| Self::builder().inner(user)
+                    )
+                }
+
+                #[doc = concat!("Create [`", stringify!($name), "`] builder from phone authentication.")]
+                ///
+                /// Initializes the inner [`RUser`] with the provided phone number
+                /// and returns a builder for setting remaining fields.
+                #[inline]
+                #[must_use]
+                pub fn from_phone(
+                    phone: impl ::core::convert::Into<String>
+                ) -> $crate::extend::ExtendedBuilder<Self, impl FnOnce($crate::RUser) -> [<$name Builder>]<[<$name:snake _builder>]::SetInner>> {
+                    $crate::extend::ExtendedBuilder::new(
+                        $crate::RUser::from_phone(phone),
+                        |user| Self::builder().inner(user)
+                    )
+                }
+
+                #[doc = concat!("Create [`", stringify!($name), "`] builder from existing [`RUser`].")]
+                ///
+                /// Useful when you already have a user and want to extend it.
+                #[inline]
+                #[must_use]
+                pub fn from_user(
+                    user: impl ::core::convert::Into<$crate::RUser>
+                ) -> $crate::extend::ExtendedBuilder<Self, impl FnOnce($crate::RUser) -> [<$name Builder>]<[<$name:snake _builder>]::SetInner>> {
+                    $crate::extend::ExtendedBuilder::new(
+                        user.into(),
+                        |user| Self::builder().inner(user)
+                    )
+                }
+
+                /// Get reference to the inner [`RUser`].
+                #[inline]
+                #[must_use]
+                pub const fn as_user(&self) -> &$crate::RUser {
+                    &self.inner
+                }
+
+                /// Get mutable reference to the inner [`RUser`].
+                #[inline]
+                #[must_use]
+                pub fn as_user_mut(&mut self) -> &mut $crate::RUser {
+                    &mut self.inner
+                }
+
+                /// Extract the inner [`RUser`], consuming self.
+                #[inline]
+                #[must_use]
+                pub fn into_user(self) -> $crate::RUser {
+                    self.inner
+                }
+
+                /// Convert to public user projection.
+                ///
+                /// Creates an [`RUserPublic`] containing only publicly-safe fields.
+                #[inline]
+                #[must_use]
+                pub fn to_public(&self) -> $crate::RUserPublic {
+                    (&self.inner).into()
+                }
+
+                /// Convert to auth user projection.
+                ///
+                /// Creates an [`RUserAuth`] for JWT/session context.
+                #[inline]
+                #[must_use]
+                pub fn to_auth(&self, role: $crate::RUserRole) -> $crate::RUserAuth {
+                    $crate::RUserAuth::from_user(&self.inner, role)
+                }
             }
+        }
+    };
+}
 
-            /// Extract the inner [`RUser`], consuming self.
-            #[inline]
-            #[must_use]
-            pub fn into_user(self) -> $crate::RUser {
-                self.inner
-            }
+/// Token pasting for the builder names in [`extend_user!`].
+///
+/// [`extend_user!`]: crate::extend_user
+#[doc(hidden)]
+pub use pastey as __pastey;
 
-            /// Convert to public user projection.
-            ///
-            /// Creates an [`RUserPublic`] containing only publicly-safe fields.
-            #[inline]
-            #[must_use]
-            pub fn to_public(&self) -> $crate::RUserPublic {
-                (&self.inner).into()
-            }
+/// Emits the struct definition for [`extend_user!`].
+///
+/// The `camel` feature must be evaluated in this crate, not in the crate
+/// invoking [`extend_user!`], so the serde casing attribute is selected
+/// here. Extended fields then use the same key casing as the flattened
+/// [`RUser`] fields.
+///
+/// [`RUser`]: crate::RUser
+/// [`extend_user!`]: crate::extend_user
+#[doc(hidden)]
+#[cfg(feature = "camel")]
+#[macro_export]
+macro_rules! __extend_user_struct {
+    ($(#[$meta:meta])* $vis:vis struct $($rest:tt)*) => {
+        $(#[$meta])*
+        #[serde(rename_all = "camelCase")]
+        $vis struct $($rest)*
+    };
+}
 
-            /// Convert to auth user projection.
-            ///
-            /// Creates an [`RUserAuth`] for JWT/session context.
-            #[inline]
-            #[must_use]
-            pub fn to_auth(&self, role: $crate::RUserRole) -> $crate::RUserAuth {
-                $crate::RUserAuth::from_user(&self.inner, role)
-            }
-        }
+/// Emits the struct definition for [`extend_user!`].
+///
+/// [`extend_user!`]: crate::extend_user
+#[doc(hidden)]
+#[cfg(not(feature = "camel"))]
+#[macro_export]
+macro_rules! __extend_user_struct {
+    ($($item:tt)*) => {
+        $($item)*
     };
 }
 
@@ -719,4 +802,99 @@ mod tests {
         assert_eq!(result.gender, Some(Gender::Male));
         assert_eq!(result.telegram_id, Some(123456789));
     }
+
+    /// Generated helpers unused by the tests below are expected.
+    #[allow(dead_code)]
+    mod corp {
+        crate::extend_user! {
+            pub struct CorpUser {
+                pub company_id: uuid::Uuid,
+                #[builder(into)]
+                pub department: String
+            }
+        }
+
+        // A second type in the same module gets its own builder names.
+        crate::extend_user! {
+            pub struct PartnerUser {
+                pub partner_id: i64
+            }
+        }
+    }
+
+    use corp::{CorpUser, PartnerUser};
+
+    #[test]
+    fn extended_types_share_a_module() {
+        let partner = PartnerUser::from_email("p@example.com")
+            .then()
+            .partner_id(7)
+            .build();
+
+        assert_eq!(partner.partner_id, 7);
+        assert_eq!(partner.email.as_deref(), Some("p@example.com"));
+    }
+
+    #[test]
+    fn extended_type_roundtrips_flat_json() {
+        let user = CorpUser::from_telegram(123)
+            .name("John")
+            .then()
+            .company_id(uuid::Uuid::nil())
+            .department("Eng")
+            .build();
+
+        let json = serde_json::to_value(&user).unwrap();
+        let object = json.as_object().unwrap();
+        let (telegram_key, company_key) = if cfg!(feature = "camel") {
+            ("telegramId", "companyId")
+        } else {
+            ("telegram_id", "company_id")
+        };
+        assert_eq!(object[telegram_key], 123);
+        assert_eq!(object[company_key], uuid::Uuid::nil().to_string());
+        assert_eq!(object["department"], "Eng");
+        assert!(!object.contains_key("inner"));
+
+        let parsed: CorpUser = serde_json::from_value(json).unwrap();
+        assert!(parsed.as_user().eq_ignoring_timestamps(user.as_user()));
+        assert_eq!(parsed.company_id, user.company_id);
+        assert_eq!(parsed.department, "Eng");
+    }
+
+    #[test]
+    fn is_field_detects_collisions() {
+        assert!(RUser::is_field("email"));
+        assert!(RUser::is_field("telegramId"));
+        assert!(!RUser::is_field("company_id"));
+        assert!(!RUser::is_field("inner"));
+    }
+
+    /// Mirrors the layout generated by `extend_user!` without the builder.
+    #[cfg(feature = "camel")]
+    #[derive(Debug, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct FlattenedUser {
+        #[serde(flatten)]
+        inner:      RUser,
+        company_id: uuid::Uuid
+    }
+
+    #[test]
+    #[cfg(feature = "camel")]
+    fn camel_case_flatten_roundtrip() {
+        let user = FlattenedUser {
+            inner:      RUser::from_telegram(123),
+            company_id: uuid::Uuid::nil()
+        };
+
+        let json = serde_json::to_string(&user).unwrap();
+        assert!(json.contains("\"telegramId\":123"));
+        assert!(json.contains("\"companyId\""));
+        assert!(!json.contains("\"inner\""));
+
+        let parsed: FlattenedUser = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.inner.telegram_id, Some(123));
+        assert_eq!(parsed.company_id, uuid::Uuid::nil());
+    }
 }
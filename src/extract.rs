@@ -12,9 +12,13 @@
 //! |---------|-----------|-------------|
 //! | `axum` | [Axum](https://crates.io/crates/axum) | Tower-based async framework |
 //! | `actix` | [Actix-web](https://crates.io/crates/actix-web) | Actor-based async framework |
+//! | `tonic` | [tonic](https://crates.io/crates/tonic) | gRPC metadata, on its own or alongside either |
 //!
-//! **Note**: These features are mutually exclusive. If both are enabled
-//! (e.g., via `--all-features`), `axum` takes precedence.
+//! Each framework lives in its own submodule, [`axum`] and [`actix`], so
+//! both features can be enabled at once. The [`JwtValidator`] and
+//! [`AuthConfig`] traits are shared: implement them once and hand the
+//! same `Arc`s to either framework. When exactly one of the two features
+//! is enabled, its types are also re-exported at the crate root.
 //!
 //! # Authentication Flow
 //!
@@ -45,7 +49,7 @@
 //! ┌─────────────────────────────────────────────────────────┐
 //! │                     Claims                              │
 //! │                                                         │
-//! │  user_id: Uuid                                          │
+//! │  user_id: UserId                                        │
 //! │  exp: DateTime<Utc>                                     │
 //! │  role: RUserRole                                        │
 //! │                                                         │
@@ -109,10 +113,11 @@
 //!
 //! # Optional Claims
 //!
-//! Use [`OptionalClaims`] when authentication is optional:
+//! Use `OptionalClaims` from the framework submodule when authentication
+//! is optional:
 //!
 //! ```rust,ignore
-//! use revelation_user::OptionalClaims;
+//! use revelation_user::extract::axum::OptionalClaims;
 //!
 //! async fn maybe_protected(OptionalClaims(claims): OptionalClaims) -> Json<String> {
 //!     match claims {
@@ -122,18 +127,269 @@
 //! }
 //! ```
 //!
+//! # Outgoing Requests
+//!
+//! `bearer_header` in [`axum`] and [`actix`] builds the `Authorization`
+//! header, in that framework's `http` types, for clients that call
+//! services using these extractors.
+//!
+//! # Logging
+//!
+//! With the `tracing` feature, extractor failures emit a `WARN` event
+//! with `reason` (`missing_config`, `missing_validator`, `no_token`,
+//! `invalid_token`), `has_cookie` and `has_header` fields. The token
+//! value itself is never logged. Optional extractors expect anonymous
+//! requests, so they log `no_token` at `DEBUG` instead.
+//!
 //! [`Claims`]: crate::Claims
 //! [`AuthConfig`]: self::AuthConfig
 //! [`JwtValidator`]: self::JwtValidator
-//! [`OptionalClaims`]: self::OptionalClaims
 
-// When both features enabled, axum takes precedence
-#[cfg(feature = "axum")]
-mod axum_extract;
+use masterror::AppError;
+
+use crate::Claims;
+
+/// Trait for JWT token validation and decoding.
+///
+/// Implement this trait to integrate your JWT library
+/// (e.g., `jsonwebtoken`, `jwt-simple`) with the Claims extractors.
+/// The same implementation serves the axum, actix-web and tonic
+/// adapters.
+///
+/// # Thread Safety
+///
+/// Implementations must be `Send + Sync + 'static` so they can be
+/// shared as axum extensions and actix-web app data.
+///
+/// # Example Implementation
+///
+/// ```rust,ignore
+/// use revelation_user::{Claims, extract::JwtValidator};
+/// use masterror::AppError;
+/// use jsonwebtoken::{decode, DecodingKey, Validation};
+///
+/// pub struct JwtManager {
+///     decoding_key: DecodingKey,
+///     validation: Validation,
+/// }
+///
+/// impl JwtManager {
+///     pub fn new(secret: &str) -> Self {
+///         Self {
+///             decoding_key: DecodingKey::from_secret(secret.as_bytes()),
+///             validation: Validation::default(),
+///         }
+///     }
+/// }
+///
+/// impl JwtValidator for JwtManager {
+///     fn decode(&self, token: &str) -> Result<Claims, AppError> {
+///         decode::<Claims>(token, &self.decoding_key, &self.validation)
+///             .map(|data| data.claims)
+///             .map_err(|e| AppError::unauthorized(format!("Invalid token: {}", e)))
+///     }
+/// }
+/// ```
+pub trait JwtValidator: Send + Sync + 'static {
+    /// Decode and validate a JWT token string.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Raw JWT token string (without "Bearer " prefix)
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Claims)` - Successfully decoded claims
+    /// - `Err(AppError)` - Token invalid, expired, or malformed
+    ///
+    /// # Errors
+    ///
+    /// Should return appropriate errors for:
+    /// - Expired tokens
+    /// - Invalid signatures
+    /// - Malformed tokens
+    /// - Missing required claims
+    fn decode(&self, token: &str) -> Result<Claims, AppError>;
+}
+
+/// Trait for authentication configuration.
+///
+/// Provides configuration values needed by the Claims extractors.
+///
+/// # Thread Safety
+///
+/// Implementations must be `Send + Sync + 'static` so they can be
+/// shared as axum extensions and actix-web app data.
+///
+/// # Example Implementation
+///
+/// ```rust,ignore
+/// use revelation_user::extract::AuthConfig;
+///
+/// pub struct AppAuthConfig {
+///     cookie_name: String,
+/// }
+///
+/// impl AppAuthConfig {
+///     pub fn new(cookie_name: impl Into<String>) -> Self {
+///         Self { cookie_name: cookie_name.into() }
+///     }
+/// }
+///
+/// impl AuthConfig for AppAuthConfig {
+///     fn cookie_name(&self) -> &str {
+///         &self.cookie_name
+///     }
+/// }
+///
+/// // Usage
+/// let config = AppAuthConfig::new("auth_token");
+/// ```
+pub trait AuthConfig: Send + Sync + 'static {
+    /// Returns the cookie name used for JWT storage.
+    ///
+    /// The extractor will look for a cookie with this name
+    /// before falling back to the Authorization header.
+    ///
+    /// # Common Values
+    ///
+    /// - `"auth_token"` - Generic auth cookie
+    /// - `"jwt"` - JWT-specific
+    /// - `"session"` - Session-style naming
+    fn cookie_name(&self) -> &str;
+}
+
+/// Format `token` as an `Authorization` value, for the per-framework
+/// `bearer_header` helpers.
+///
+/// Rejects empty tokens, control characters (including CR/LF, which
+/// would allow header injection) and non-ASCII characters.
+#[cfg(any(feature = "axum", feature = "actix"))]
+fn bearer_value(token: &str) -> Result<String, AppError> {
+    if token.is_empty() || !token.chars().all(|c| c.is_ascii_graphic()) {
+        return Err(AppError::validation(
+            "Bearer token must be non-empty visible ASCII without control characters"
+        ));
+    }
+
+    Ok(format!("Bearer {token}"))
+}
+
+/// Emit a structured warning for a failed authentication attempt.
+///
+/// No-op unless the `tracing` feature is enabled.
+#[cfg(any(feature = "axum", feature = "actix"))]
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+fn log_auth_failure(reason: &'static str, has_cookie: bool, has_header: bool) {
+    #[cfg(feature = "tracing")]
+    tracing::warn!(reason, has_cookie, has_header, "authentication failed");
+}
+
+/// Log a request that carried no token.
+///
+/// A `WARN` failure like [`log_auth_failure`], except on optional
+/// routes, where anonymous requests are expected and only get a `DEBUG`
+/// event.
+#[cfg(any(feature = "axum", feature = "actix"))]
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+fn log_missing_token(optional: bool, has_cookie: bool, has_header: bool) {
+    #[cfg(feature = "tracing")]
+    if optional {
+        tracing::debug!(
+            reason = "no_token",
+            has_cookie,
+            has_header,
+            "anonymous request"
+        );
+    } else {
+        log_auth_failure("no_token", has_cookie, has_header);
+    }
+}
+
+#[cfg(feature = "actix")]
+pub mod actix;
 #[cfg(feature = "axum")]
-pub use axum_extract::*;
+pub mod axum;
+
+#[cfg(feature = "tonic")]
+mod tonic_extract;
+#[cfg(feature = "tonic")]
+pub use tonic_extract::*;
+
+#[cfg(test)]
+mod tests {
+    #[cfg(all(feature = "axum", feature = "actix"))]
+    use std::sync::Arc;
+
+    #[cfg(any(feature = "axum", feature = "actix"))]
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "axum")]
+    fn axum_bearer_header_is_sensitive() {
+        let (name, value) = axum::bearer_header("abc.def.ghi").unwrap();
+
+        assert_eq!(name, ::axum::http::header::AUTHORIZATION);
+        assert_eq!(value.to_str().unwrap(), "Bearer abc.def.ghi");
+        assert!(value.is_sensitive());
+    }
+
+    #[test]
+    #[cfg(feature = "actix")]
+    fn actix_bearer_header_is_sensitive() {
+        let (name, value) = actix::bearer_header("abc.def.ghi").unwrap();
+
+        assert_eq!(name, actix_web::http::header::AUTHORIZATION);
+        assert_eq!(value.to_str().unwrap(), "Bearer abc.def.ghi");
+        assert!(value.is_sensitive());
+    }
+
+    #[test]
+    #[cfg(any(feature = "axum", feature = "actix"))]
+    fn bearer_value_rejects_control_characters() {
+        for token in [
+            "",
+            "abc\ndef",
+            "abc\rdef",
+            "abc\tdef",
+            "abc\u{7f}",
+            "abc def",
+            "jwt\u{e9}"
+        ] {
+            assert!(bearer_value(token).is_err(), "{token:?}");
+        }
+    }
+
+    #[cfg(all(feature = "axum", feature = "actix"))]
+    struct Config;
+
+    #[cfg(all(feature = "axum", feature = "actix"))]
+    impl AuthConfig for Config {
+        fn cookie_name(&self) -> &str {
+            "token"
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "axum", feature = "actix"))]
+    fn both_frameworks_are_reachable() {
+        let axum_claims = crate::extract::axum::OptionalClaims(None);
+        let actix_claims = crate::extract::actix::OptionalClaims(None);
+        assert!(!axum_claims.is_authenticated());
+        assert!(!actix_claims.is_authenticated());
+
+        let _layer = crate::extract::axum::ClaimsLayer::required();
+        let config: Arc<dyn AuthConfig> = Arc::new(Config);
+        assert_eq!(config.cookie_name(), "token");
+    }
+
+    #[test]
+    #[cfg(all(feature = "axum", feature = "actix"))]
+    fn claims_implement_both_extractors() {
+        fn axum_extractor<T: ::axum::extract::FromRequestParts<()>>() {}
+        fn actix_extractor<T: actix_web::FromRequest>() {}
 
-#[cfg(all(feature = "actix", not(feature = "axum")))]
-mod actix_extract;
-#[cfg(all(feature = "actix", not(feature = "axum")))]
-pub use actix_extract::*;
+        axum_extractor::<Claims>();
+        actix_extractor::<Claims>();
+    }
+}
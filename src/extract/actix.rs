@@ -75,7 +75,7 @@
 //!
 //! ```rust,ignore
 //! use actix_web::{HttpResponse, Responder};
-//! use revelation_user::{Claims, OptionalClaims};
+//! use revelation_user::{Claims, extract::actix::OptionalClaims};
 //!
 //! // Required authentication
 //! async fn get_current_user(claims: Claims) -> impl Responder {
@@ -103,118 +103,50 @@
 
 use std::sync::Arc;
 
-use actix_web::{Error, FromRequest, HttpRequest, dev::Payload};
+use actix_web::{
+    Error, FromRequest, HttpRequest,
+    dev::Payload,
+    http::header::{AUTHORIZATION, HeaderName, HeaderValue}
+};
 use futures_util::future::{Ready, ready};
 use masterror::AppError;
 
+use super::{AuthConfig, JwtValidator, bearer_value, log_auth_failure, log_missing_token};
 use crate::Claims;
 
-/// Trait for JWT token validation and decoding.
+/// Build an `Authorization: Bearer <token>` header for outgoing requests.
 ///
-/// Implement this trait to integrate your JWT library
-/// (e.g., `jsonwebtoken`, `jwt-simple`) with the Claims extractor.
+/// The value is marked sensitive so clients and loggers that honour
+/// [`HeaderValue::is_sensitive`] do not print it.
 ///
-/// # Thread Safety
+/// # Arguments
 ///
-/// Implementations must be `Send + Sync + 'static` for use with
-/// Actix-web's async handlers and app data.
+/// * `token` - Raw JWT, without the `Bearer ` prefix
 ///
-/// # Example Implementation
-///
-/// ```rust,ignore
-/// use revelation_user::{Claims, extract::JwtValidator};
-/// use masterror::AppError;
-/// use jsonwebtoken::{decode, DecodingKey, Validation};
-///
-/// pub struct JwtManager {
-///     decoding_key: DecodingKey,
-///     validation: Validation,
-/// }
-///
-/// impl JwtManager {
-///     pub fn new(secret: &str) -> Self {
-///         Self {
-///             decoding_key: DecodingKey::from_secret(secret.as_bytes()),
-///             validation: Validation::default(),
-///         }
-///     }
-/// }
-///
-/// impl JwtValidator for JwtManager {
-///     fn decode(&self, token: &str) -> Result<Claims, AppError> {
-///         decode::<Claims>(token, &self.decoding_key, &self.validation)
-///             .map(|data| data.claims)
-///             .map_err(|e| AppError::unauthorized(format!("Invalid token: {}", e)))
-///     }
-/// }
-/// ```
-pub trait JwtValidator: Send + Sync + 'static {
-    /// Decode and validate a JWT token string.
-    ///
-    /// # Arguments
-    ///
-    /// * `token` - Raw JWT token string (without "Bearer " prefix)
-    ///
-    /// # Returns
-    ///
-    /// - `Ok(Claims)` - Successfully decoded claims
-    /// - `Err(AppError)` - Token invalid, expired, or malformed
-    ///
-    /// # Errors
-    ///
-    /// Should return appropriate errors for:
-    /// - Expired tokens
-    /// - Invalid signatures
-    /// - Malformed tokens
-    /// - Missing required claims
-    fn decode(&self, token: &str) -> Result<Claims, AppError>;
-}
-
-/// Trait for authentication configuration.
-///
-/// Provides configuration values needed by the Claims extractor.
-///
-/// # Thread Safety
-///
-/// Implementations must be `Send + Sync + 'static` for use
-/// with Actix-web's app data.
-///
-/// # Example Implementation
+/// # Errors
 ///
-/// ```rust,ignore
-/// use revelation_user::extract::AuthConfig;
+/// Returns a validation [`AppError`] if `token` is empty or contains
+/// control characters (including CR/LF, which would allow header
+/// injection) or non-ASCII characters.
 ///
-/// pub struct AppAuthConfig {
-///     cookie_name: String,
-/// }
+/// # Examples
 ///
-/// impl AppAuthConfig {
-///     pub fn new(cookie_name: impl Into<String>) -> Self {
-///         Self { cookie_name: cookie_name.into() }
-///     }
-/// }
+/// ```rust
+/// use actix_web::http::header::AUTHORIZATION;
+/// use revelation_user::extract::actix::bearer_header;
 ///
-/// impl AuthConfig for AppAuthConfig {
-///     fn cookie_name(&self) -> &str {
-///         &self.cookie_name
-///     }
-/// }
+/// let (name, value) = bearer_header("eyJhbGciOi.eyJzdWIi.sig").unwrap();
 ///
-/// // Usage
-/// let config = AppAuthConfig::new("auth_token");
+/// assert_eq!(name, AUTHORIZATION);
+/// assert!(value.is_sensitive());
+/// assert!(bearer_header("abc\r\nX-Admin: 1").is_err());
 /// ```
-pub trait AuthConfig: Send + Sync + 'static {
-    /// Returns the cookie name used for JWT storage.
-    ///
-    /// The extractor will look for a cookie with this name
-    /// before falling back to the Authorization header.
-    ///
-    /// # Common Values
-    ///
-    /// - `"auth_token"` - Generic auth cookie
-    /// - `"jwt"` - JWT-specific
-    /// - `"session"` - Session-style naming
-    fn cookie_name(&self) -> &str;
+pub fn bearer_header(token: &str) -> Result<(HeaderName, HeaderValue), AppError> {
+    let mut value = HeaderValue::from_str(&bearer_value(token)?)
+        .map_err(|_| AppError::validation("Bearer token is not a valid header value"))?;
+    value.set_sensitive(true);
+
+    Ok((AUTHORIZATION, value))
 }
 
 /// Actix-web extractor implementation for [`Claims`].
@@ -252,44 +184,61 @@ impl FromRequest for Claims {
     type Future = Ready<Result<Self, Error>>;
 
     fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
-        // Get config from app data
-        let config = match req.app_data::<Arc<dyn AuthConfig>>() {
-            Some(c) => c.clone(),
-            None => return ready(Err(AppError::internal("AuthConfig not configured").into()))
-        };
+        ready(authenticate(req, false))
+    }
+}
+
+/// Resolve the JWT from a request and decode it.
+///
+/// With `optional`, a request without any token is logged at `DEBUG`
+/// rather than as a `WARN` failure.
+fn authenticate(req: &HttpRequest, optional: bool) -> Result<Claims, Error> {
+    // Get config from app data
+    let config = match req.app_data::<Arc<dyn AuthConfig>>() {
+        Some(c) => c.clone(),
+        None => {
+            log_auth_failure("missing_config", false, false);
+            return Err(AppError::internal("AuthConfig not configured").into());
+        }
+    };
+
+    // Get JWT validator from app data
+    let jwt = match req.app_data::<Arc<dyn JwtValidator>>() {
+        Some(j) => j.clone(),
+        None => {
+            log_auth_failure("missing_validator", false, false);
+            return Err(AppError::internal("JwtValidator not configured").into());
+        }
+    };
 
-        // Get JWT validator from app data
-        let jwt = match req.app_data::<Arc<dyn JwtValidator>>() {
-            Some(j) => j.clone(),
-            None => return ready(Err(AppError::internal("JwtValidator not configured").into()))
-        };
+    let cookie = req.cookie(config.cookie_name());
+    let has_cookie = cookie.is_some();
+    let has_header = req.headers().contains_key("Authorization");
 
-        // Try cookie first
-        let token = match req.cookie(config.cookie_name()) {
-            Some(c) => c.value().to_owned(),
-            None => {
-                // Fallback to Authorization header
-                match req
-                    .headers()
-                    .get("Authorization")
-                    .and_then(|h| h.to_str().ok())
-                    .and_then(|h| h.strip_prefix("Bearer "))
-                {
-                    Some(t) => t.to_owned(),
-                    None => {
-                        return ready(Err(
-                            AppError::unauthorized("Authentication required").into()
-                        ));
-                    }
+    // Try cookie first
+    let token = match cookie {
+        Some(c) => c.value().to_owned(),
+        None => {
+            // Fallback to Authorization header
+            match req
+                .headers()
+                .get("Authorization")
+                .and_then(|h| h.to_str().ok())
+                .and_then(|h| h.strip_prefix("Bearer "))
+            {
+                Some(t) => t.to_owned(),
+                None => {
+                    log_missing_token(optional, has_cookie, has_header);
+                    return Err(AppError::unauthorized("Authentication required").into());
                 }
             }
-        };
-
-        match jwt.decode(&token) {
-            Ok(claims) => ready(Ok(claims)),
-            Err(e) => ready(Err(e.into()))
         }
-    }
+    };
+
+    jwt.decode(&token).map_err(|e| {
+        log_auth_failure("invalid_token", has_cookie, has_header);
+        e.into()
+    })
 }
 
 /// Optional claims extractor for endpoints with optional authentication.
@@ -307,7 +256,7 @@ impl FromRequest for Claims {
 ///
 /// ```rust,ignore
 /// use actix_web::HttpResponse;
-/// use revelation_user::OptionalClaims;
+/// use revelation_user::extract::actix::OptionalClaims;
 ///
 /// async fn public_with_personalization(
 ///     OptionalClaims(claims): OptionalClaims,
@@ -367,10 +316,7 @@ impl FromRequest for OptionalClaims {
     type Error = Error;
     type Future = Ready<Result<Self, Error>>;
 
-    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
-        match Claims::from_request(req, payload).into_inner() {
-            Ok(claims) => ready(Ok(OptionalClaims(Some(claims)))),
-            Err(_) => ready(Ok(OptionalClaims(None)))
-        }
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(Ok(OptionalClaims(authenticate(req, true).ok())))
     }
 }
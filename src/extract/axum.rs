@@ -0,0 +1,1492 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! Axum extractors for JWT authentication.
+//!
+//! This module provides [`Claims`] extraction from HTTP requests
+//! using the [Axum](https://crates.io/crates/axum) web framework.
+//!
+//! # Overview
+//!
+//! | Type | Purpose |
+//! |------|---------|
+//! | [`JwtValidator`] | Trait for JWT token decoding |
+//! | [`AuthConfig`] | Trait for authentication configuration |
+//! | [`OptionalClaims`] | Extractor for optional authentication |
+//! | [`AuthenticatedUser`] | Claims plus the raw token for relaying |
+//! | [`RUserAuth`] | Id, role and permissions straight from the token |
+//! | [`extract_claims_from_ws_protocol`] | Claims from a WebSocket subprotocol |
+//! | [`ClaimsLayer`] | Tower layer that validates once per request |
+//! | [`AuthRejection`] | JSON error rejection returned by the extractors |
+//!
+//! # Setup
+//!
+//! 1. Implement [`JwtValidator`] for your JWT library
+//! 2. Implement [`AuthConfig`] to specify cookie name
+//! 3. Add both as extensions to your router
+//!
+//! # Token Resolution Order
+//!
+//! The extractor looks for JWT tokens in this order:
+//! 1. Cookie with name from [`AuthConfig::cookie_name`]
+//! 2. `Authorization: Bearer <token>` header
+//!
+//! # Example Setup
+//!
+//! ```rust,ignore
+//! use axum::{Router, Extension, middleware};
+//! use revelation_user::{Claims, extract::{JwtValidator, AuthConfig}};
+//! use std::sync::Arc;
+//!
+//! // 1. Implement JwtValidator
+//! struct MyJwtManager {
+//!     secret: String,
+//! }
+//!
+//! impl JwtValidator for MyJwtManager {
+//!     fn decode(&self, token: &str) -> Result<Claims, AppError> {
+//!         // Use jsonwebtoken or similar
+//!         let data = jsonwebtoken::decode::<Claims>(
+//!             token,
+//!             &DecodingKey::from_secret(self.secret.as_bytes()),
+//!             &Validation::default(),
+//!         )?;
+//!         Ok(data.claims)
+//!     }
+//! }
+//!
+//! // 2. Implement AuthConfig
+//! struct MyAuthConfig;
+//!
+//! impl AuthConfig for MyAuthConfig {
+//!     fn cookie_name(&self) -> &str {
+//!         "auth_token"
+//!     }
+//! }
+//!
+//! // 3. Create router with extensions
+//! let app = Router::new()
+//!     .route("/me", get(get_current_user))
+//!     .layer(Extension(Arc::new(MyJwtManager { secret: "..." }) as Arc<dyn JwtValidator>))
+//!     .layer(Extension(Arc::new(MyAuthConfig) as Arc<dyn AuthConfig>));
+//! ```
+//!
+//! # Handler Examples
+//!
+//! ```rust,ignore
+//! use axum::Json;
+//! use revelation_user::{Claims, RUserPublic, extract::axum::OptionalClaims};
+//!
+//! // Required authentication
+//! async fn get_current_user(claims: Claims) -> Json<String> {
+//!     Json(format!("User ID: {}", claims.user_id()))
+//! }
+//!
+//! // Optional authentication
+//! async fn get_profile(OptionalClaims(claims): OptionalClaims) -> Json<String> {
+//!     match claims {
+//!         Some(c) => Json(format!("Welcome back, {}", c.user_id())),
+//!         None => Json("Welcome, guest!".into()),
+//!     }
+//! }
+//!
+//! // Admin-only endpoint
+//! async fn admin_dashboard(claims: Claims) -> Result<Json<String>, AppError> {
+//!     if !claims.is_admin() {
+//!         return Err(AppError::forbidden("Admin access required"));
+//!     }
+//!     Ok(Json("Admin dashboard".into()))
+//! }
+//! ```
+//!
+//! # Middleware
+//!
+//! Extractors run once per handler argument. When several layers or
+//! handlers need the same claims, use [`ClaimsLayer`] to validate the
+//! token once and store the result in request extensions:
+//!
+//! ```rust,ignore
+//! use axum::{Extension, Router, routing::get};
+//! use revelation_user::{Claims, extract::axum::ClaimsLayer};
+//!
+//! let app = Router::new()
+//!     .route("/me", get(|claims: Claims| async move { claims.user_id().to_string() }))
+//!     .layer(ClaimsLayer::required())
+//!     .layer(Extension(jwt_validator))
+//!     .layer(Extension(auth_config));
+//! ```
+//!
+//! [`Claims`]: crate::Claims
+//! [`RUserAuth`]: crate::RUserAuth
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll}
+};
+
+use axum::{
+    Json, RequestPartsExt,
+    extract::{FromRequestParts, Request},
+    http::{
+        HeaderMap, HeaderName, HeaderValue, StatusCode, header::AUTHORIZATION, request::Parts
+    },
+    response::{IntoResponse, Response}
+};
+use axum_extra::extract::CookieJar;
+use masterror::{AppError, AppErrorKind};
+use tower::{Layer, Service};
+
+use super::{AuthConfig, JwtValidator, bearer_value, log_auth_failure, log_missing_token};
+use crate::{Claims, RUserAuth};
+
+/// Rejection returned by the axum authentication extractors.
+///
+/// Renders as a JSON body that fits an API error envelope:
+///
+/// ```json
+/// { "error": "unauthorized", "code": "no_token" }
+/// ```
+///
+/// | Code | Status | Cause |
+/// |------|--------|-------|
+/// | `no_token` | 401 | Neither cookie nor `Authorization` header present |
+/// | `invalid_token` | 401 | [`JwtValidator`] rejected the token |
+/// | `missing_config` | 500 | No [`AuthConfig`] extension |
+/// | `missing_validator` | 500 | No [`JwtValidator`] extension |
+///
+/// # Conversions
+///
+/// - `From<AppError>` - status from the [`AppErrorKind`], code `invalid_token`
+///   for 401s
+/// - `Into<AppError>` - for handlers returning [`AppError`]
+///
+/// # Examples
+///
+/// ```rust
+/// use axum::http::StatusCode;
+/// use revelation_user::extract::axum::AuthRejection;
+///
+/// let rejection = AuthRejection::unauthorized("no_token");
+///
+/// assert_eq!(rejection.status(), StatusCode::UNAUTHORIZED);
+/// assert_eq!(rejection.error(), "unauthorized");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuthRejection {
+    status: StatusCode,
+    code:   &'static str
+}
+
+impl AuthRejection {
+    /// Create a 401 rejection with the given machine-readable code.
+    #[must_use]
+    pub const fn unauthorized(code: &'static str) -> Self {
+        Self {
+            status: StatusCode::UNAUTHORIZED,
+            code
+        }
+    }
+
+    /// Create a 500 rejection for server-side misconfiguration.
+    #[must_use]
+    pub const fn internal(code: &'static str) -> Self {
+        Self {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            code
+        }
+    }
+
+    /// HTTP status of the response.
+    #[must_use]
+    pub const fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Machine-readable reason, e.g. `no_token`.
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Error category for the `error` field of the body.
+    #[must_use]
+    pub fn error(&self) -> &'static str {
+        category(self.status).0
+    }
+}
+
+/// Body category and [`AppErrorKind`] for a rejection status.
+fn category(status: StatusCode) -> (&'static str, AppErrorKind) {
+    match status.as_u16() {
+        401 => ("unauthorized", AppErrorKind::Unauthorized),
+        403 => ("forbidden", AppErrorKind::Forbidden),
+        404 => ("not_found", AppErrorKind::NotFound),
+        409 => ("conflict", AppErrorKind::Conflict),
+        422 => ("validation", AppErrorKind::Validation),
+        429 => ("rate_limited", AppErrorKind::RateLimited),
+        503 => ("unavailable", AppErrorKind::DependencyUnavailable),
+        504 => ("timeout", AppErrorKind::Timeout),
+        400..=499 => ("bad_request", AppErrorKind::BadRequest),
+        _ => ("internal", AppErrorKind::Internal)
+    }
+}
+
+impl core::fmt::Display for AuthRejection {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.error(), self.code)
+    }
+}
+
+impl std::error::Error for AuthRejection {}
+
+impl IntoResponse for AuthRejection {
+    fn into_response(self) -> Response {
+        let body = serde_json::json!({
+            "error": self.error(),
+            "code": self.code
+        });
+
+        (self.status, Json(body)).into_response()
+    }
+}
+
+impl From<AppError> for AuthRejection {
+    /// Maps validator errors to the status of their kind; unauthorized
+    /// becomes `invalid_token`, other kinds use their category as code.
+    fn from(err: AppError) -> Self {
+        if err.kind == AppErrorKind::Unauthorized {
+            return Self::unauthorized("invalid_token");
+        }
+        let status = StatusCode::from_u16(err.kind.http_status())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        Self {
+            status,
+            code: category(status).0
+        }
+    }
+}
+
+impl From<AuthRejection> for AppError {
+    fn from(rejection: AuthRejection) -> Self {
+        AppError::new(category(rejection.status).1, rejection.code)
+    }
+}
+
+/// Build an `Authorization: Bearer <token>` header for outgoing requests.
+///
+/// The value is marked sensitive so clients and loggers that honour
+/// [`HeaderValue::is_sensitive`] do not print it.
+///
+/// # Arguments
+///
+/// * `token` - Raw JWT, without the `Bearer ` prefix
+///
+/// # Errors
+///
+/// Returns a validation [`AppError`] if `token` is empty or contains
+/// control characters (including CR/LF, which would allow header
+/// injection) or non-ASCII characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use axum::http::header::AUTHORIZATION;
+/// use revelation_user::extract::axum::bearer_header;
+///
+/// let (name, value) = bearer_header("eyJhbGciOi.eyJzdWIi.sig").unwrap();
+///
+/// assert_eq!(name, AUTHORIZATION);
+/// assert!(value.is_sensitive());
+/// assert!(bearer_header("abc\r\nX-Admin: 1").is_err());
+/// ```
+pub fn bearer_header(token: &str) -> Result<(HeaderName, HeaderValue), AppError> {
+    let mut value = HeaderValue::from_str(&bearer_value(token)?)
+        .map_err(|_| AppError::validation("Bearer token is not a valid header value"))?;
+    value.set_sensitive(true);
+
+    Ok((AUTHORIZATION, value))
+}
+
+/// Axum extractor implementation for [`Claims`].
+///
+/// Automatically extracts and validates JWT tokens from requests.
+///
+/// # Resolution Order
+///
+/// 1. Claims already stored in extensions by [`ClaimsLayer`]
+/// 2. Cookie (name from [`AuthConfig::cookie_name`])
+/// 3. `Authorization: Bearer <token>` header
+///
+/// # Errors
+///
+/// Returns [`AuthRejection`] for:
+/// - Missing [`AuthConfig`] extension - 500 `missing_config`
+/// - Missing [`JwtValidator`] extension - 500 `missing_validator`
+/// - No token found - 401 `no_token`
+/// - Invalid token - 401 `invalid_token` (from validator)
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use axum::Json;
+/// use revelation_user::Claims;
+///
+/// async fn handler(claims: Claims) -> Json<String> {
+///     // claims is automatically extracted and validated
+///     Json(format!("Hello {}", claims.user_id()))
+/// }
+/// ```
+///
+/// [`Claims`]: crate::Claims
+impl<S> FromRequestParts<S> for Claims
+where
+    S: Send + Sync
+{
+    type Rejection = AuthRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        resolve_claims(parts, false).await
+    }
+}
+
+/// Extracts an [`RUserAuth`] from the request's JWT.
+///
+/// Shortcut for handlers that only need the user id and role. Extracts
+/// [`Claims`] (reusing [`ClaimsLayer`] results) and maps `sub`, `role` and
+/// custom permissions; `telegram_id` is always `None` since tokens do not
+/// carry it.
+///
+/// # Errors
+///
+/// Rejects with the same [`AuthRejection`]s as the [`Claims`] extractor.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use revelation_user::RUserAuth;
+///
+/// async fn handler(auth: RUserAuth) -> String {
+///     format!("{} is admin: {}", auth.id, auth.is_admin())
+/// }
+/// ```
+///
+/// [`Claims`]: crate::Claims
+/// [`RUserAuth`]: crate::RUserAuth
+impl<S> FromRequestParts<S> for RUserAuth
+where
+    S: Send + Sync
+{
+    type Rejection = AuthRejection;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+
+        Ok(Self::from_claims(&claims))
+    }
+}
+
+/// Reuse claims validated by [`ClaimsLayer`], or [`authenticate`].
+///
+/// `optional` is set for extractors that accept anonymous requests, see
+/// [`authenticate`].
+async fn resolve_claims(parts: &mut Parts, optional: bool) -> Result<Claims, AuthRejection> {
+    if let Some(claims) = parts.extensions.get::<Claims>() {
+        return Ok(claims.clone());
+    }
+
+    authenticate(parts, optional, |_| ())
+        .await
+        .map(|(claims, ())| claims)
+}
+
+/// Resolve the raw JWT from a request and decode it.
+///
+/// Looks up [`AuthConfig`] and [`JwtValidator`] in extensions, then
+/// tries the cookie before the `Authorization: Bearer` header. Returns
+/// the decoded claims together with `keep(token)`, so callers that only
+/// need the claims pay no allocation for the token.
+///
+/// The header token is borrowed straight from the request headers. The
+/// cookie path still allocates: [`CookieJar`] parses every cookie into
+/// an owned `Cookie<'static>` and cannot lend from the header, although
+/// the token itself is then borrowed from the jar rather than copied.
+///
+/// With `optional`, a request without any token is logged at `DEBUG`
+/// rather than as a `WARN` failure.
+async fn authenticate<T>(
+    parts: &mut Parts,
+    optional: bool,
+    keep: impl FnOnce(&str) -> T
+) -> Result<(Claims, T), AuthRejection> {
+    // Extract dependencies from extensions
+    let (config, jwt): (Arc<dyn AuthConfig>, Arc<dyn JwtValidator>) = {
+        let ex = &parts.extensions;
+
+        let config = ex.get::<Arc<dyn AuthConfig>>().cloned().ok_or_else(|| {
+            log_auth_failure("missing_config", false, false);
+            AuthRejection::internal("missing_config")
+        })?;
+
+        let jwt = ex.get::<Arc<dyn JwtValidator>>().cloned().ok_or_else(|| {
+            log_auth_failure("missing_validator", false, false);
+            AuthRejection::internal("missing_validator")
+        })?;
+
+        (config, jwt)
+    };
+
+    // Try cookie first
+    let jar = parts.extract::<CookieJar>().await.ok();
+    let cookie_token = jar
+        .as_ref()
+        .and_then(|jar| jar.get(config.cookie_name()))
+        .map(|cookie| cookie.value());
+
+    let has_cookie = cookie_token.is_some();
+    let has_header = parts.headers.contains_key(AUTHORIZATION);
+
+    // Fallback to Authorization header
+    let token = cookie_token
+        .or_else(|| bearer_token(&parts.headers))
+        .ok_or_else(|| {
+            log_missing_token(optional, has_cookie, has_header);
+            AuthRejection::unauthorized("no_token")
+        })?;
+
+    match jwt.decode(token) {
+        Ok(claims) => Ok((claims, keep(token))),
+        Err(err) => {
+            log_auth_failure("invalid_token", has_cookie, has_header);
+            Err(err.into())
+        }
+    }
+}
+
+/// Borrow the token from an `Authorization: Bearer <token>` header.
+///
+/// The scheme is matched case-insensitively, as the typed `headers`
+/// decoder does.
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    const SCHEME: &str = "Bearer ";
+
+    let value = headers.get(AUTHORIZATION)?.to_str().ok()?;
+    if value.len() <= SCHEME.len() || !value[..SCHEME.len()].eq_ignore_ascii_case(SCHEME) {
+        return None;
+    }
+    Some(&value[SCHEME.len()..])
+}
+
+/// Optional claims extractor for endpoints with optional authentication.
+///
+/// Unlike direct [`Claims`] extraction which rejects unauthenticated
+/// requests, this wrapper allows both authenticated and anonymous access.
+///
+/// # Use Cases
+///
+/// - Public content with personalization for logged-in users
+/// - Optional analytics tracking
+/// - Graceful degradation when auth is unavailable
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use axum::Json;
+/// use revelation_user::extract::axum::OptionalClaims;
+///
+/// async fn public_with_personalization(
+///     OptionalClaims(claims): OptionalClaims,
+/// ) -> Json<String> {
+///     match claims {
+///         Some(c) => Json(format!("Welcome back, user {}", c.user_id())),
+///         None => Json("Welcome, guest!".into()),
+///     }
+/// }
+///
+/// // Conditional premium content
+/// async fn content(OptionalClaims(claims): OptionalClaims) -> Json<String> {
+///     let is_premium = claims.as_ref().map(|c| c.is_premium()).unwrap_or(false);
+///
+///     if is_premium {
+///         Json("Premium content here".into())
+///     } else {
+///         Json("Basic content only".into())
+///     }
+/// }
+/// ```
+///
+/// [`Claims`]: crate::Claims
+#[derive(Debug, Clone)]
+pub struct OptionalClaims(pub Option<Claims>);
+
+impl OptionalClaims {
+    /// Returns the inner claims if present.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let OptionalClaims(claims) = optional_claims;
+    /// if let Some(c) = claims {
+    ///     println!("User: {}", c.user_id());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn into_inner(self) -> Option<Claims> {
+        self.0
+    }
+
+    /// Returns a reference to the inner claims if present.
+    #[must_use]
+    pub const fn as_ref(&self) -> Option<&Claims> {
+        self.0.as_ref()
+    }
+
+    /// Returns `true` if claims are present.
+    #[must_use]
+    pub const fn is_authenticated(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+impl<S> FromRequestParts<S> for OptionalClaims
+where
+    S: Send + Sync
+{
+    type Rejection = AuthRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(OptionalClaims(resolve_claims(parts, true).await.ok()))
+    }
+}
+
+/// Claims extractor that also keeps the raw JWT.
+///
+/// Use it for token relay, when a handler forwards the caller's token
+/// to a downstream service. The token is resolved the same way as for
+/// [`Claims`] (cookie first, then `Authorization: Bearer`) and always
+/// decoded, even when [`ClaimsLayer`] already ran.
+///
+/// # Errors
+///
+/// Rejects with the same [`AuthRejection`]s as the [`Claims`] extractor.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use revelation_user::extract::axum::AuthenticatedUser;
+///
+/// async fn relay(user: AuthenticatedUser) -> String {
+///     let res = http_client
+///         .get("https://billing.internal/v1/me")
+///         .bearer_auth(&user.token)
+///         .send()
+///         .await?;
+///     format!("{} -> {}", user.claims.user_id(), res.status())
+/// }
+/// ```
+///
+/// [`Claims`]: crate::Claims
+#[derive(Debug, Clone)]
+pub struct AuthenticatedUser {
+    /// Decoded and validated claims.
+    pub claims: Claims,
+
+    /// The JWT exactly as sent by the client (without `Bearer `).
+    pub token: String
+}
+
+impl<S> FromRequestParts<S> for AuthenticatedUser
+where
+    S: Send + Sync
+{
+    type Rejection = AuthRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let (claims, token) = authenticate(parts, false, str::to_owned).await?;
+
+        Ok(Self {
+            claims,
+            token
+        })
+    }
+}
+
+/// Decode claims smuggled in the `Sec-WebSocket-Protocol` header.
+///
+/// Browsers cannot set `Authorization` on WebSocket upgrades, so clients
+/// send the token as a subprotocol: `Sec-WebSocket-Protocol: bearer, <token>`.
+/// This helper finds the entry following `bearer` (case-insensitive) and
+/// decodes it with `validator`.
+///
+/// # Arguments
+///
+/// * `parts` - Request parts of the upgrade request
+/// * `validator` - Validator used to decode the token
+///
+/// # Errors
+///
+/// Returns an unauthorized [`AppError`] if the header is missing, not
+/// valid ASCII, or has no token after `bearer`, and forwards errors from
+/// [`JwtValidator::decode`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use axum::{
+///     extract::{FromRequestParts, Request, WebSocketUpgrade},
+///     response::Response
+/// };
+/// use revelation_user::extract::axum::extract_claims_from_ws_protocol;
+///
+/// async fn ws(req: Request) -> Result<Response, AppError> {
+///     let (mut parts, _) = req.into_parts();
+///     let claims = extract_claims_from_ws_protocol(&parts, jwt.as_ref())?;
+///     let upgrade = WebSocketUpgrade::from_request_parts(&mut parts, &()).await?;
+///     Ok(upgrade.protocols(["bearer"]).on_upgrade(move |socket| serve(socket, claims)))
+/// }
+/// ```
+pub fn extract_claims_from_ws_protocol(
+    parts: &Parts,
+    validator: &dyn JwtValidator
+) -> Result<Claims, AppError> {
+    let header = parts
+        .headers
+        .get(axum::http::header::SEC_WEBSOCKET_PROTOCOL)
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| AppError::unauthorized("Authentication required"))?;
+
+    let mut protocols = header.split(',').map(str::trim);
+    let token = protocols
+        .by_ref()
+        .find(|p| p.eq_ignore_ascii_case("bearer"))
+        .and_then(|_| protocols.next())
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| AppError::unauthorized("Authentication required"))?;
+
+    validator.decode(token)
+}
+
+/// Tower layer that validates JWT tokens once per request.
+///
+/// Performs the same cookie/header resolution as the [`Claims`]
+/// extractor and stores the outcome in request extensions, so
+/// downstream layers and handlers can read it without decoding
+/// the token again.
+///
+/// # Modes
+///
+/// | Mode | Valid token | Missing/invalid token |
+/// |------|-------------|-----------------------|
+/// | [`required`](Self::required) | Inserts `Claims` and `OptionalClaims` | Responds with 401 |
+/// | [`optional`](Self::optional) | Inserts `Claims` and `OptionalClaims` | Inserts `OptionalClaims(None)` |
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use axum::{Extension, Router, routing::get};
+/// use revelation_user::{Claims, extract::axum::ClaimsLayer};
+///
+/// let app = Router::new()
+///     .route("/me", get(|Extension(claims): Extension<Claims>| async move {
+///         claims.user_id().to_string()
+///     }))
+///     .layer(ClaimsLayer::required())
+///     .layer(Extension(jwt_validator))
+///     .layer(Extension(auth_config));
+/// ```
+///
+/// [`Claims`]: crate::Claims
+#[derive(Debug, Clone, Copy)]
+pub struct ClaimsLayer {
+    required: bool
+}
+
+impl ClaimsLayer {
+    /// Create a layer that rejects unauthenticated requests with 401.
+    #[must_use]
+    pub const fn required() -> Self {
+        Self {
+            required: true
+        }
+    }
+
+    /// Create a layer that lets unauthenticated requests through.
+    ///
+    /// Downstream handlers receive `OptionalClaims(None)` in extensions.
+    #[must_use]
+    pub const fn optional() -> Self {
+        Self {
+            required: false
+        }
+    }
+
+    /// Returns `true` if this layer rejects unauthenticated requests.
+    #[must_use]
+    pub const fn is_required(&self) -> bool {
+        self.required
+    }
+}
+
+impl<S> Layer<S> for ClaimsLayer {
+    type Service = ClaimsMiddleware<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ClaimsMiddleware {
+            inner,
+            required: self.required
+        }
+    }
+}
+
+/// Service produced by [`ClaimsLayer`].
+///
+/// You typically don't create this directly - use [`ClaimsLayer`].
+#[derive(Debug, Clone)]
+pub struct ClaimsMiddleware<S> {
+    inner:    S,
+    required: bool
+}
+
+impl<S> Service<Request> for ClaimsMiddleware<S>
+where
+    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        // Take the service that was driven to readiness, leave a clone behind
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let required = self.required;
+
+        Box::pin(async move {
+            let (mut parts, body) = req.into_parts();
+
+            match resolve_claims(&mut parts, !required).await {
+                Ok(claims) => {
+                    parts
+                        .extensions
+                        .insert(OptionalClaims(Some(claims.clone())));
+                    parts.extensions.insert(claims);
+                }
+                Err(err) if required => return Ok(err.into_response()),
+                Err(_) => {
+                    parts.extensions.insert(OptionalClaims(None));
+                }
+            }
+
+            inner.call(Request::from_parts(parts, body)).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Extension, Router, body::Body, http::StatusCode, routing::get};
+    use http::Request;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::RUserRole;
+
+    struct MockJwtValidator {
+        claims: Option<Claims>
+    }
+
+    impl JwtValidator for MockJwtValidator {
+        fn decode(&self, _token: &str) -> Result<Claims, AppError> {
+            self.claims
+                .clone()
+                .ok_or_else(|| AppError::unauthorized("Invalid token"))
+        }
+    }
+
+    struct MockAuthConfig;
+
+    impl AuthConfig for MockAuthConfig {
+        fn cookie_name(&self) -> &str {
+            "jwt"
+        }
+    }
+
+    fn make_parts_with_extensions(
+        jwt: Arc<dyn JwtValidator>,
+        config: Arc<dyn AuthConfig>
+    ) -> Parts {
+        let req = Request::builder()
+            .header("Authorization", "Bearer test-token")
+            .body(())
+            .unwrap();
+        let (mut parts, _) = req.into_parts();
+        parts.extensions.insert(jwt);
+        parts.extensions.insert(config);
+        parts
+    }
+
+    #[tokio::test]
+    async fn claims_extracts_from_bearer() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::User, usize::MAX);
+        let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+            claims: Some(claims.clone())
+        });
+        let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+        let mut parts = make_parts_with_extensions(jwt, config);
+
+        let result = Claims::from_request_parts(&mut parts, &()).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().sub, claims.sub);
+    }
+
+    /// Accepts only `expected` as the token.
+    struct ExactTokenValidator {
+        expected: &'static str
+    }
+
+    impl JwtValidator for ExactTokenValidator {
+        fn decode(&self, token: &str) -> Result<Claims, AppError> {
+            if token == self.expected {
+                Ok(Claims::new(Uuid::nil(), RUserRole::User, usize::MAX))
+            } else {
+                Err(AppError::unauthorized("Invalid token"))
+            }
+        }
+    }
+
+    fn parts_with_authorization(value: &str) -> Parts {
+        let jwt: Arc<dyn JwtValidator> = Arc::new(ExactTokenValidator {
+            expected: "abc.def.ghi"
+        });
+        let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+        let (mut parts, _) = Request::builder()
+            .header("Authorization", value)
+            .body(())
+            .unwrap()
+            .into_parts();
+        parts.extensions.insert(jwt);
+        parts.extensions.insert(config);
+        parts
+    }
+
+    #[tokio::test]
+    async fn bearer_header_token_is_passed_unchanged() {
+        for value in [
+            "Bearer abc.def.ghi",
+            "bearer abc.def.ghi",
+            "BEARER abc.def.ghi"
+        ] {
+            let mut parts = parts_with_authorization(value);
+            assert!(
+                Claims::from_request_parts(&mut parts, &()).await.is_ok(),
+                "{value}"
+            );
+
+            let mut parts = parts_with_authorization(value);
+            let user = AuthenticatedUser::from_request_parts(&mut parts, &())
+                .await
+                .unwrap();
+            assert_eq!(user.token, "abc.def.ghi");
+        }
+    }
+
+    #[tokio::test]
+    async fn non_bearer_authorization_is_no_token() {
+        for value in ["Basic abc.def.ghi", "Bearer ", "Bearerabc.def.ghi"] {
+            let mut parts = parts_with_authorization(value);
+            let err = Claims::from_request_parts(&mut parts, &())
+                .await
+                .unwrap_err();
+
+            assert_eq!(err.code(), "no_token", "{value}");
+        }
+    }
+
+    #[tokio::test]
+    async fn claims_extracts_from_cookie() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::Premium, usize::MAX);
+        let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+            claims: Some(claims.clone())
+        });
+        let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+
+        let req = Request::builder()
+            .header("Cookie", "jwt=test-cookie-token")
+            .body(())
+            .unwrap();
+        let (mut parts, _) = req.into_parts();
+        parts.extensions.insert(jwt);
+        parts.extensions.insert(config);
+
+        let result = Claims::from_request_parts(&mut parts, &()).await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().sub, claims.sub);
+    }
+
+    #[tokio::test]
+    async fn claims_fails_without_auth_config() {
+        let req = Request::builder().body(()).unwrap();
+        let (mut parts, _) = req.into_parts();
+        let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+            claims: None
+        });
+        parts.extensions.insert(jwt);
+
+        let result = Claims::from_request_parts(&mut parts, &()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn claims_fails_without_jwt_validator() {
+        let req = Request::builder().body(()).unwrap();
+        let (mut parts, _) = req.into_parts();
+        let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+        parts.extensions.insert(config);
+
+        let result = Claims::from_request_parts(&mut parts, &()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn claims_fails_without_token() {
+        let req = Request::builder().body(()).unwrap();
+        let (mut parts, _) = req.into_parts();
+        let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+            claims: None
+        });
+        let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+        parts.extensions.insert(jwt);
+        parts.extensions.insert(config);
+
+        let result = Claims::from_request_parts(&mut parts, &()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn claims_fails_with_invalid_token() {
+        let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+            claims: None
+        });
+        let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+        let mut parts = make_parts_with_extensions(jwt, config);
+
+        let result = Claims::from_request_parts(&mut parts, &()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn authenticated_user_keeps_raw_token() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::Premium, usize::MAX);
+        let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+            claims: Some(claims.clone())
+        });
+        let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+        let mut parts = make_parts_with_extensions(jwt, config);
+
+        let user = AuthenticatedUser::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+
+        assert_eq!(user.claims.sub, claims.sub);
+        assert_eq!(user.claims.role, RUserRole::Premium);
+        assert_eq!(user.token, "test-token");
+    }
+
+    #[tokio::test]
+    async fn authenticated_user_fails_without_token() {
+        let req = Request::builder().body(()).unwrap();
+        let (mut parts, _) = req.into_parts();
+        let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+            claims: None
+        });
+        let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+        parts.extensions.insert(jwt);
+        parts.extensions.insert(config);
+
+        let result = AuthenticatedUser::from_request_parts(&mut parts, &()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn ruser_auth_extracts_from_valid_token() {
+        let id = Uuid::now_v7();
+        let claims = Claims::new(id, RUserRole::Admin, usize::MAX);
+        let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+            claims: Some(claims)
+        });
+        let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+        let mut parts = make_parts_with_extensions(jwt, config);
+
+        let auth = RUserAuth::from_request_parts(&mut parts, &())
+            .await
+            .unwrap();
+
+        assert_eq!(auth.id, id);
+        assert_eq!(auth.telegram_id, None);
+        assert_eq!(auth.role, RUserRole::Admin);
+        assert!(auth.permissions.is_none());
+    }
+
+    #[tokio::test]
+    async fn ruser_auth_fails_without_token() {
+        let req = Request::builder().body(()).unwrap();
+        let (mut parts, _) = req.into_parts();
+        let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+            claims: None
+        });
+        let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+        parts.extensions.insert(jwt);
+        parts.extensions.insert(config);
+
+        assert!(
+            RUserAuth::from_request_parts(&mut parts, &())
+                .await
+                .is_err()
+        );
+    }
+
+    struct EchoValidator;
+
+    impl JwtValidator for EchoValidator {
+        fn decode(&self, token: &str) -> Result<Claims, AppError> {
+            if token == "ws-token" {
+                Ok(Claims::new(Uuid::nil(), RUserRole::User, usize::MAX))
+            } else {
+                Err(AppError::unauthorized("Invalid token"))
+            }
+        }
+    }
+
+    #[test]
+    fn ws_protocol_decodes_bearer_token() {
+        let req = Request::builder()
+            .header("Sec-WebSocket-Protocol", "Bearer, ws-token")
+            .body(())
+            .unwrap();
+        let (parts, _) = req.into_parts();
+
+        let claims = extract_claims_from_ws_protocol(&parts, &EchoValidator).unwrap();
+
+        assert!(claims.user_id().is_nil());
+    }
+
+    #[test]
+    fn ws_protocol_requires_token_after_bearer() {
+        for value in ["bearer", "chat, ws-token", "bearer, "] {
+            let req = Request::builder()
+                .header("Sec-WebSocket-Protocol", value)
+                .body(())
+                .unwrap();
+            let (parts, _) = req.into_parts();
+
+            assert!(extract_claims_from_ws_protocol(&parts, &EchoValidator).is_err());
+        }
+    }
+
+    #[test]
+    fn ws_protocol_fails_without_header() {
+        let (parts, _) = Request::builder().body(()).unwrap().into_parts();
+
+        assert!(extract_claims_from_ws_protocol(&parts, &EchoValidator).is_err());
+    }
+
+    #[tokio::test]
+    async fn optional_claims_extracts_valid() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::Admin, usize::MAX);
+        let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+            claims: Some(claims)
+        });
+        let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+        let mut parts = make_parts_with_extensions(jwt, config);
+
+        let result = OptionalClaims::from_request_parts(&mut parts, &()).await;
+
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_authenticated());
+    }
+
+    #[tokio::test]
+    async fn optional_claims_returns_none_on_failure() {
+        let req = Request::builder().body(()).unwrap();
+        let (mut parts, _) = req.into_parts();
+
+        let result = OptionalClaims::from_request_parts(&mut parts, &()).await;
+
+        assert!(result.is_ok());
+        assert!(!result.unwrap().is_authenticated());
+    }
+
+    #[test]
+    fn optional_claims_into_inner_some() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::User, 0);
+        let optional = OptionalClaims(Some(claims.clone()));
+        let inner = optional.into_inner();
+
+        assert!(inner.is_some());
+        assert_eq!(inner.unwrap().sub, claims.sub);
+    }
+
+    #[test]
+    fn optional_claims_into_inner_none() {
+        let optional = OptionalClaims(None);
+        assert!(optional.into_inner().is_none());
+    }
+
+    #[test]
+    fn optional_claims_as_ref_some() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::User, 0);
+        let optional = OptionalClaims(Some(claims));
+
+        assert!(optional.as_ref().is_some());
+    }
+
+    #[test]
+    fn optional_claims_as_ref_none() {
+        let optional = OptionalClaims(None);
+        assert!(optional.as_ref().is_none());
+    }
+
+    #[test]
+    fn optional_claims_is_authenticated_true() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::User, 0);
+        let optional = OptionalClaims(Some(claims));
+
+        assert!(optional.is_authenticated());
+    }
+
+    #[test]
+    fn optional_claims_is_authenticated_false() {
+        let optional = OptionalClaims(None);
+        assert!(!optional.is_authenticated());
+    }
+
+    #[test]
+    fn optional_claims_clone() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::Admin, 100);
+        let optional = OptionalClaims(Some(claims));
+        let cloned = optional.clone();
+
+        assert!(cloned.is_authenticated());
+        assert_eq!(cloned.as_ref().unwrap().role, RUserRole::Admin);
+    }
+
+    #[test]
+    fn optional_claims_debug() {
+        let optional = OptionalClaims(None);
+        let debug_str = format!("{:?}", optional);
+        assert!(debug_str.contains("OptionalClaims"));
+    }
+
+    fn layered_app(layer: ClaimsLayer, claims: Option<Claims>) -> Router {
+        let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+            claims
+        });
+        let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+
+        Router::new()
+            .route(
+                "/",
+                get(
+                    |Extension(optional): Extension<OptionalClaims>| async move {
+                        optional
+                            .into_inner()
+                            .map(|c| c.role.to_string())
+                            .unwrap_or_else(|| "anonymous".into())
+                    }
+                )
+            )
+            .layer(layer)
+            .layer(Extension(jwt))
+            .layer(Extension(config))
+    }
+
+    #[tokio::test]
+    async fn layer_inserts_claims_into_extensions() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::Premium, usize::MAX);
+        let app = layered_app(ClaimsLayer::required(), Some(claims));
+
+        let req = Request::builder()
+            .uri("/")
+            .header("Authorization", "Bearer test-token")
+            .body(Body::empty())
+            .unwrap();
+        let res = app.oneshot(req).await.unwrap();
+
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(res.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"premium");
+    }
+
+    #[tokio::test]
+    async fn layer_required_rejects_without_token() {
+        let app = layered_app(ClaimsLayer::required(), None);
+
+        let req = Request::builder().uri("/").body(Body::empty()).unwrap();
+        let res = app.oneshot(req).await.unwrap();
+
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+        let body = axum::body::to_bytes(res.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "error": "unauthorized", "code": "no_token" })
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_token_rejection_converts_both_ways() {
+        let mut parts = make_parts_with_extensions(
+            Arc::new(MockJwtValidator {
+                claims: None
+            }),
+            Arc::new(MockAuthConfig)
+        );
+
+        let rejection = Claims::from_request_parts(&mut parts, &())
+            .await
+            .unwrap_err();
+        assert_eq!(rejection, AuthRejection::unauthorized("invalid_token"));
+
+        let err: AppError = rejection.into();
+        assert_eq!(err.kind, AppErrorKind::Unauthorized);
+        assert_eq!(
+            AuthRejection::from(AppError::internal("boom")).status(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+
+    #[test]
+    fn app_error_rejection_keeps_kind_status() {
+        let cases = [
+            (AppErrorKind::Forbidden, StatusCode::FORBIDDEN, "forbidden"),
+            (AppErrorKind::NotFound, StatusCode::NOT_FOUND, "not_found"),
+            (
+                AppErrorKind::Validation,
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "validation"
+            ),
+            (
+                AppErrorKind::RateLimited,
+                StatusCode::TOO_MANY_REQUESTS,
+                "rate_limited"
+            ),
+            (
+                AppErrorKind::Timeout,
+                StatusCode::GATEWAY_TIMEOUT,
+                "timeout"
+            ),
+            (
+                AppErrorKind::Database,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "internal"
+            )
+        ];
+
+        for (kind, status, code) in cases {
+            let rejection = AuthRejection::from(AppError::new(kind, "boom"));
+            assert_eq!(rejection.status(), status, "{kind:?}");
+            assert_eq!(rejection.code(), code);
+        }
+
+        let err = AppError::from(AuthRejection::from(AppError::not_found("user")));
+        assert_eq!(err.kind, AppErrorKind::NotFound);
+    }
+
+    #[tokio::test]
+    async fn layer_optional_allows_anonymous() {
+        let app = layered_app(ClaimsLayer::optional(), None);
+
+        let req = Request::builder().uri("/").body(Body::empty()).unwrap();
+        let res = app.oneshot(req).await.unwrap();
+
+        assert_eq!(res.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(res.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"anonymous");
+    }
+
+    #[tokio::test]
+    async fn claims_extractor_reuses_layer_result() {
+        let claims = Claims::new(Uuid::nil(), RUserRole::Admin, usize::MAX);
+        let req = Request::builder().body(()).unwrap();
+        let (mut parts, _) = req.into_parts();
+        parts.extensions.insert(claims);
+
+        let result = Claims::from_request_parts(&mut parts, &()).await;
+
+        assert_eq!(result.unwrap().role, RUserRole::Admin);
+    }
+
+    #[test]
+    fn layer_modes() {
+        assert!(ClaimsLayer::required().is_required());
+        assert!(!ClaimsLayer::optional().is_required());
+    }
+
+    #[cfg(feature = "tracing")]
+    mod tracing_events {
+        use std::sync::Mutex;
+
+        use tracing::{Event, Level, Subscriber, field::Field};
+        use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+        use super::*;
+
+        #[derive(Clone, Default)]
+        struct Captured(Arc<Mutex<Vec<(Level, String, String)>>>);
+
+        #[derive(Default)]
+        struct Fields {
+            reason: String,
+            all:    String
+        }
+
+        impl tracing::field::Visit for Fields {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                if field.name() == "reason" {
+                    self.reason = value.to_owned();
+                }
+                self.all.push_str(value);
+            }
+
+            fn record_debug(&mut self, _field: &Field, value: &dyn core::fmt::Debug) {
+                self.all.push_str(&format!("{value:?}"));
+            }
+        }
+
+        impl<S: Subscriber> Layer<S> for Captured {
+            fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+                let mut fields = Fields::default();
+                event.record(&mut fields);
+                self.0.lock().unwrap().push((
+                    *event.metadata().level(),
+                    fields.reason,
+                    fields.all
+                ));
+            }
+        }
+
+        #[tokio::test]
+        async fn missing_token_emits_warn_with_reason() {
+            let captured = Captured::default();
+            let _guard = tracing::subscriber::set_default(
+                tracing_subscriber::registry().with(captured.clone())
+            );
+
+            let (mut parts, _) = Request::builder().body(()).unwrap().into_parts();
+            let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+                claims: None
+            });
+            let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+            parts.extensions.insert(jwt);
+            parts.extensions.insert(config);
+
+            assert!(Claims::from_request_parts(&mut parts, &()).await.is_err());
+
+            let events = captured.0.lock().unwrap();
+            assert!(
+                events
+                    .iter()
+                    .any(|(level, reason, _)| *level == Level::WARN && reason == "no_token")
+            );
+        }
+
+        #[tokio::test]
+        async fn optional_missing_token_does_not_warn() {
+            let captured = Captured::default();
+            let _guard = tracing::subscriber::set_default(
+                tracing_subscriber::registry().with(captured.clone())
+            );
+
+            let (mut parts, _) = Request::builder().body(()).unwrap().into_parts();
+            let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+                claims: None
+            });
+            let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+            parts.extensions.insert(jwt);
+            parts.extensions.insert(config);
+
+            let optional = OptionalClaims::from_request_parts(&mut parts, &())
+                .await
+                .unwrap();
+            assert!(!optional.is_authenticated());
+
+            let events = captured.0.lock().unwrap();
+            assert!(events.iter().all(|(level, _, _)| *level != Level::WARN));
+            assert!(
+                events
+                    .iter()
+                    .any(|(level, reason, _)| *level == Level::DEBUG && reason == "no_token")
+            );
+        }
+
+        #[tokio::test]
+        async fn optional_invalid_token_still_warns() {
+            let captured = Captured::default();
+            let _guard = tracing::subscriber::set_default(
+                tracing_subscriber::registry().with(captured.clone())
+            );
+
+            let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+                claims: None
+            });
+            let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+            let mut parts = make_parts_with_extensions(jwt, config);
+
+            let optional = OptionalClaims::from_request_parts(&mut parts, &())
+                .await
+                .unwrap();
+            assert!(!optional.is_authenticated());
+
+            let events = captured.0.lock().unwrap();
+            assert!(
+                events
+                    .iter()
+                    .any(|(level, reason, _)| *level == Level::WARN && reason == "invalid_token")
+            );
+        }
+
+        #[tokio::test]
+        async fn invalid_token_logs_without_token_value() {
+            let captured = Captured::default();
+            let _guard = tracing::subscriber::set_default(
+                tracing_subscriber::registry().with(captured.clone())
+            );
+
+            let jwt: Arc<dyn JwtValidator> = Arc::new(MockJwtValidator {
+                claims: None
+            });
+            let config: Arc<dyn AuthConfig> = Arc::new(MockAuthConfig);
+            let mut parts = make_parts_with_extensions(jwt, config);
+
+            assert!(Claims::from_request_parts(&mut parts, &()).await.is_err());
+
+            let events = captured.0.lock().unwrap();
+            assert!(
+                events
+                    .iter()
+                    .any(|(_, reason, _)| reason == "invalid_token")
+            );
+            assert!(events.iter().all(|(_, _, all)| !all.contains("test-token")));
+        }
+    }
+}
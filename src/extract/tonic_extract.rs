@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! gRPC (tonic) claims extraction.
+//!
+//! This module lets a [tonic](https://crates.io/crates/tonic) service
+//! reuse the same [`JwtValidator`] as the REST extractors, reading the
+//! token from `authorization` request metadata.
+//!
+//! Only the `tonic` feature is required, so gRPC-only services do not
+//! pull in a web framework.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use revelation_user::claims_from_metadata;
+//! use tonic::{Request, Response, Status};
+//!
+//! async fn get_profile(&self, req: Request<GetProfile>) -> Result<Response<Profile>, Status> {
+//!     let claims = claims_from_metadata(req.metadata(), self.jwt.as_ref())?;
+//!     // ...
+//! }
+//! ```
+//!
+//! [`JwtValidator`]: super::JwtValidator
+
+use tonic::{Status, metadata::MetadataMap};
+
+use super::JwtValidator;
+use crate::Claims;
+
+/// Decode claims from `authorization: Bearer <token>` gRPC metadata.
+///
+/// # Arguments
+///
+/// * `md` - Request metadata
+/// * `validator` - Validator used to decode the token
+///
+/// # Errors
+///
+/// Returns [`Status::unauthenticated`] when the metadata entry is
+/// missing or malformed, or when the validator rejects the token.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let claims = claims_from_metadata(request.metadata(), jwt.as_ref())?;
+/// ```
+#[allow(clippy::result_large_err)] // `Status` is what tonic handlers return
+pub fn claims_from_metadata(
+    md: &MetadataMap,
+    validator: &dyn JwtValidator
+) -> Result<Claims, Status> {
+    let value = md
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| Status::unauthenticated("Authentication required"))?;
+
+    let token = value
+        .split_once(' ')
+        .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("bearer"))
+        .map(|(_, token)| token.trim())
+        .filter(|token| !token.is_empty())
+        .ok_or_else(|| Status::unauthenticated("Authentication required"))?;
+
+    validator.decode(token).map_err(|err| {
+        Status::unauthenticated(err.message.as_deref().unwrap_or("Invalid token").to_owned())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use masterror::AppError;
+    use tonic::Code;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::RUserRole;
+
+    struct MockJwtValidator;
+
+    impl JwtValidator for MockJwtValidator {
+        fn decode(&self, token: &str) -> Result<Claims, AppError> {
+            if token == "grpc-token" {
+                Ok(Claims::new(Uuid::nil(), RUserRole::Admin, usize::MAX))
+            } else {
+                Err(AppError::unauthorized("Invalid token"))
+            }
+        }
+    }
+
+    fn metadata(value: &str) -> MetadataMap {
+        let mut md = MetadataMap::new();
+        md.insert("authorization", value.parse().unwrap());
+        md
+    }
+
+    #[test]
+    fn decodes_bearer_token() {
+        let md = metadata("Bearer grpc-token");
+        let claims = claims_from_metadata(&md, &MockJwtValidator).unwrap();
+
+        assert!(claims.is_admin());
+        assert!(claims.user_id().is_nil());
+    }
+
+    #[test]
+    fn missing_metadata_is_unauthenticated() {
+        let status = claims_from_metadata(&MetadataMap::new(), &MockJwtValidator).unwrap_err();
+
+        assert_eq!(status.code(), Code::Unauthenticated);
+    }
+
+    #[test]
+    fn non_bearer_scheme_is_unauthenticated() {
+        let status =
+            claims_from_metadata(&metadata("Basic grpc-token"), &MockJwtValidator).unwrap_err();
+
+        assert_eq!(status.code(), Code::Unauthenticated);
+    }
+
+    #[test]
+    fn invalid_token_is_unauthenticated() {
+        let md = metadata("Bearer nope");
+        let status = claims_from_metadata(&md, &MockJwtValidator).unwrap_err();
+
+        assert_eq!(status.code(), Code::Unauthenticated);
+        assert_eq!(status.message(), "Invalid token");
+    }
+}
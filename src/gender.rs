@@ -64,6 +64,7 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "db", derive(sqlx::Type))]
 #[cfg_attr(feature = "db", sqlx(type_name = "gender", rename_all = "snake_case"))]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Gender {
     /// Male gender.
     Male,
@@ -120,6 +121,78 @@ impl Gender {
             Self::Female => "female"
         }
     }
+
+    /// Count genders across a collection of optional values.
+    ///
+    /// `None` entries are counted as `unknown`.
+    ///
+    /// # Arguments
+    ///
+    /// * `iter` - Optional genders, e.g. `users.iter().map(|u| &u.gender)`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::Gender;
+    ///
+    /// let genders = [
+    ///     Some(Gender::Male),
+    ///     None,
+    ///     Some(Gender::Female),
+    ///     Some(Gender::Male)
+    /// ];
+    /// let stats = Gender::tally(&genders);
+    ///
+    /// assert_eq!(stats.male, 2);
+    /// assert_eq!(stats.female, 1);
+    /// assert_eq!(stats.unknown, 1);
+    /// assert_eq!(stats.total(), 4);
+    /// ```
+    #[must_use]
+    pub fn tally<'a, I>(iter: I) -> GenderStats
+    where
+        I: IntoIterator<Item = &'a Option<Gender>>
+    {
+        iter.into_iter()
+            .fold(GenderStats::default(), |mut stats, gender| {
+                match gender {
+                    Some(Self::Male) => stats.male += 1,
+                    Some(Self::Female) => stats.female += 1,
+                    None => stats.unknown += 1
+                }
+                stats
+            })
+    }
+}
+
+/// Gender counts produced by [`Gender::tally`].
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::GenderStats;
+///
+/// let stats = GenderStats::default();
+/// assert_eq!(stats.total(), 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct GenderStats {
+    /// Number of [`Gender::Male`] entries.
+    pub male: usize,
+
+    /// Number of [`Gender::Female`] entries.
+    pub female: usize,
+
+    /// Number of entries without a gender.
+    pub unknown: usize
+}
+
+impl GenderStats {
+    /// Total number of entries tallied.
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.male + self.female + self.unknown
+    }
 }
 
 impl core::fmt::Display for Gender {
@@ -159,6 +232,35 @@ mod tests {
         assert_eq!(format!("{}", Gender::Female), "female");
     }
 
+    #[test]
+    fn tally_counts_mixed_values() {
+        let genders = [
+            Some(Gender::Male),
+            None,
+            Some(Gender::Female),
+            Some(Gender::Male),
+            None,
+            None
+        ];
+
+        let stats = Gender::tally(&genders);
+
+        assert_eq!(
+            stats,
+            GenderStats {
+                male:    2,
+                female:  1,
+                unknown: 3
+            }
+        );
+        assert_eq!(stats.total(), genders.len());
+    }
+
+    #[test]
+    fn tally_empty_is_zero() {
+        assert_eq!(Gender::tally(core::iter::empty()), GenderStats::default());
+    }
+
     #[test]
     fn is_methods() {
         assert!(Gender::Male.is_male());
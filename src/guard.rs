@@ -0,0 +1,181 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! Permission guards for handler bodies.
+//!
+//! [`require_permission!`] replaces the repeated
+//! `if !claims.can(...) { return Err(forbidden) }` pattern with a single
+//! line. It works with anything exposing `effective_permissions()`, such
+//! as [`Claims`] and [`RUserAuth`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use masterror::AppResult;
+//! use revelation_user::{Claims, Permissions, RUserRole, require_permission};
+//! use uuid::Uuid;
+//!
+//! fn export_report(claims: &Claims) -> AppResult<&'static str> {
+//!     require_permission!(claims, Permissions::EXPORT);
+//!     Ok("report.csv")
+//! }
+//!
+//! let premium = Claims::new(Uuid::now_v7(), RUserRole::Premium, usize::MAX);
+//! assert!(export_report(&premium).is_ok());
+//!
+//! let user = Claims::new(Uuid::now_v7(), RUserRole::User, usize::MAX);
+//! let err = export_report(&user).unwrap_err();
+//! assert!(
+//!     err.message
+//!         .as_deref()
+//!         .unwrap_or_default()
+//!         .contains("export")
+//! );
+//! ```
+//!
+//! [`Claims`]: crate::Claims
+//! [`RUserAuth`]: crate::RUserAuth
+
+use masterror::AppError;
+
+use crate::Permissions;
+
+/// Return early with a 403 unless the subject has the permission(s).
+///
+/// Expands to a check of `$subject.effective_permissions()` against
+/// `$permission`. On failure the enclosing function returns
+/// `Err(missing_permission(..).into())`, so it works in any function
+/// whose error type implements `From<AppError>`.
+///
+/// # Arguments
+///
+/// * `$subject` - [`Claims`], [`RUserAuth`] or a reference to either
+/// * `$permission` - [`Permissions`] expression; all bits are required
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::{AppErrorKind, AppResult};
+/// use revelation_user::{Permissions, RUser, RUserAuth, RUserRole, require_permission};
+///
+/// fn delete_post(auth: &RUserAuth) -> AppResult<()> {
+///     require_permission!(auth, Permissions::READ | Permissions::DELETE);
+///     Ok(())
+/// }
+///
+/// let user = RUser::from_telegram(123);
+///
+/// let admin = RUserAuth::from_user(&user, RUserRole::Admin);
+/// assert!(delete_post(&admin).is_ok());
+///
+/// let regular = RUserAuth::from_user(&user, RUserRole::User);
+/// assert_eq!(
+///     delete_post(&regular).unwrap_err().kind,
+///     AppErrorKind::Forbidden
+/// );
+/// ```
+///
+/// [`Claims`]: crate::Claims
+/// [`RUserAuth`]: crate::RUserAuth
+/// [`Permissions`]: crate::Permissions
+#[macro_export]
+macro_rules! require_permission {
+    ($subject:expr, $permission:expr) => {{
+        let required: $crate::Permissions = $permission;
+        let granted: $crate::Permissions = $subject.effective_permissions();
+        if !granted.contains(required) {
+            return ::core::result::Result::Err(
+                $crate::missing_permission(required, granted).into()
+            );
+        }
+    }};
+}
+
+/// Build the 403 error used by [`require_permission!`].
+///
+/// The message names only the permissions that are missing.
+///
+/// # Arguments
+///
+/// * `required` - Permissions the operation needs
+/// * `granted` - Permissions the caller has
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::AppErrorKind;
+/// use revelation_user::{Permissions, missing_permission};
+///
+/// let err = missing_permission(Permissions::READ | Permissions::EXPORT, Permissions::READ);
+///
+/// assert_eq!(err.kind, AppErrorKind::Forbidden);
+/// assert_eq!(err.message.as_deref(), Some("Missing permission: export"));
+/// ```
+#[must_use]
+pub fn missing_permission(required: Permissions, granted: Permissions) -> AppError {
+    AppError::forbidden(format!(
+        "Missing permission: {}",
+        required.difference(granted)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use masterror::{AppErrorKind, AppResult};
+    use uuid::Uuid;
+
+    use crate::{Claims, Permissions, RUser, RUserAuth, RUserRole};
+
+    fn export(claims: &Claims) -> AppResult<()> {
+        require_permission!(claims, Permissions::EXPORT);
+        Ok(())
+    }
+
+    fn manage(auth: RUserAuth) -> AppResult<()> {
+        require_permission!(auth, Permissions::MANAGE_USERS | Permissions::AUDIT);
+        Ok(())
+    }
+
+    #[test]
+    fn passes_when_permission_granted() {
+        let claims = Claims::with_permissions(
+            Uuid::nil(),
+            RUserRole::User,
+            usize::MAX,
+            Permissions::READ | Permissions::EXPORT
+        );
+
+        assert!(export(&claims).is_ok());
+    }
+
+    #[test]
+    fn rejects_when_permission_missing() {
+        let claims =
+            Claims::with_permissions(Uuid::nil(), RUserRole::Admin, usize::MAX, Permissions::READ);
+
+        let err = export(&claims).unwrap_err();
+
+        assert_eq!(err.kind, AppErrorKind::Forbidden);
+        assert_eq!(err.message.as_deref(), Some("Missing permission: export"));
+    }
+
+    #[test]
+    fn works_with_ruser_auth() {
+        let user = RUser::from_telegram(123);
+
+        let granted = RUserAuth::from_user_with_permissions(
+            &user,
+            RUserRole::User,
+            Permissions::MANAGE_USERS | Permissions::AUDIT
+        );
+        assert!(manage(granted).is_ok());
+
+        let partial =
+            RUserAuth::from_user_with_permissions(&user, RUserRole::User, Permissions::AUDIT);
+        let err = manage(partial).unwrap_err();
+        assert_eq!(
+            err.message.as_deref(),
+            Some("Missing permission: manage_users")
+        );
+    }
+}
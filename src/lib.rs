@@ -32,16 +32,30 @@
 //! let user = RUser::from_email("john@example.com");
 //! ```
 //!
+//! OAuth sign-ins map through [`OAuthProfile`] and [`RUser::from_oauth`].
+//!
 //! ## Features
 //!
 //! | Feature | Description |
 //! |---------|-------------|
 //! | `db` | Database support via sqlx (PostgreSQL) |
 //! | `api` | OpenAPI schema generation via utoipa |
+//! | `schemars` | Plain JSON Schema via schemars (entity, projections, DTOs) |
 //! | `axum` | Axum framework extractors |
 //! | `actix` | Actix-web framework extractors |
-//!
-//! **Note**: `axum` and `actix` features are mutually exclusive.
+//! | `tonic` | gRPC metadata claims extraction |
+//! | `tracing` | Structured warnings for extractor authentication failures |
+//! | `telegram` | Telegram Login Widget hash verification |
+//! | `jwt-peek` | `Claims::peek_subject` for unverified subject lookup |
+//! | `arbitrary` | `arbitrary::Arbitrary` for `RUser`, `Claims` and `Permissions` |
+//! | `proptest-strategies` | `proptest` strategies for valid and invalid request DTOs |
+//! | `camel` | camelCase JSON keys for entities, projections and DTOs |
+//! | `strict` | Reject unknown JSON fields in request DTOs (not extended types) |
+//! | `testing` | Deterministic fixtures such as `RUser::fixture()` |
+//!
+//! `axum` and `actix` can be enabled together; their extractors live in
+//! `extract::axum` and `extract::actix`. With only one of them enabled,
+//! its types are also re-exported at the crate root.
 //!
 //! ## Core Types
 //!
@@ -49,6 +63,11 @@
 //!
 //! - [`RUser`] - The core user entity with all fields
 //! - [`Claims`] - JWT claims for authentication
+//! - [`UserId`] - Typed user identifier
+//! - [`ConfessionId`] - Typed confession/denomination identifier
+//! - [`ConfessionCount`] - Users per confession for reporting
+//! - [`PhoneNumber`] - Validated E.164 phone number
+//! - [`EmailAddress`] - Validated email address
 //!
 //! ### Projections
 //!
@@ -56,6 +75,8 @@
 //!
 //! - [`RUserPublic`] - Safe for API responses (excludes sensitive data)
 //! - [`RUserAuth`] - For JWT/session context (includes role)
+//! - [`RUserAdmin`] - Contact details for admin tools, see
+//!   [`RUser::redact_for`]
 //!
 //! ```rust
 //! use revelation_user::{RUser, RUserAuth, RUserPublic, RUserRole};
@@ -76,6 +97,7 @@
 //! - [`CreateUserRequest`] - Create new user
 //! - [`UpdateProfileRequest`] - Update user profile
 //! - [`BindTelegram`], [`BindEmail`], [`BindPhone`] - Bind contact methods
+//! - [`BindRequest`] - Any bind DTO, tagged by `method`
 //!
 //! ## Extending Users
 //!
@@ -144,15 +166,20 @@
 //! use validator::Validate;
 //!
 //! let req = UpdateProfileRequest {
-//!     name:          Some("J".into()), // Too short!
-//!     gender:        None,
-//!     birth_date:    None,
-//!     confession_id: None
+//!     name:               Some("J".into()), // Too short!
+//!     gender:             None,
+//!     birth_date:         None,
+//!     confession_id:      None,
+//!     notification_prefs: None
 //! };
 //!
 //! assert!(req.validate().is_err());
 //! ```
 //!
+//! Failures can be wrapped in [`UserValidationError`] for readable
+//! messages, or mapped straight into a 422 `AppError` with
+//! [`validate_into_app`].
+//!
 //! ## Module Structure
 //!
 //! - [`entity`] - Core user entity and JWT claims
@@ -166,30 +193,58 @@ use std::sync::LazyLock;
 
 use regex::Regex;
 
+mod confession;
 pub mod dto;
+mod email;
 pub mod entity;
+mod error;
 pub mod extend;
 mod gender;
+mod guard;
 mod notification;
+mod oauth;
+mod patch;
 mod permissions;
+mod phone;
 pub mod projections;
 mod role;
+#[cfg(feature = "telegram")]
+mod telegram;
+#[cfg(feature = "testing")]
+mod testing;
+mod validate;
 
-#[cfg(any(feature = "axum", feature = "actix"))]
+#[cfg(any(feature = "axum", feature = "actix", feature = "tonic"))]
 pub mod extract;
 
 pub mod ports;
 
 // Re-exports for convenience
+pub use confession::*;
 pub use dto::*;
+pub use email::*;
 pub use entity::*;
-#[cfg(any(feature = "axum", feature = "actix"))]
-pub use extract::*;
+pub use error::*;
+#[cfg(all(feature = "actix", not(feature = "axum")))]
+pub use extract::actix::*;
+#[cfg(all(feature = "axum", not(feature = "actix")))]
+pub use extract::axum::*;
+#[cfg(feature = "tonic")]
+pub use extract::claims_from_metadata;
+#[cfg(any(feature = "axum", feature = "actix", feature = "tonic"))]
+pub use extract::{AuthConfig, JwtValidator};
 pub use gender::*;
+pub use guard::*;
 pub use notification::*;
+pub use oauth::*;
+pub use patch::*;
 pub use permissions::*;
+pub use phone::*;
 pub use projections::*;
 pub use role::*;
+#[cfg(feature = "telegram")]
+pub use telegram::*;
+pub use validate::*;
 
 /// E.164 international phone number format regex.
 ///
@@ -205,6 +260,9 @@ pub use role::*;
 /// - `+442071234567` (UK)
 /// - `+79991234567` (Russia)
 ///
+/// Compiled once on first use. Prefer [`validate_phone`], which every
+/// phone check in this crate goes through.
+///
 /// # Usage
 ///
 /// ```rust
@@ -214,9 +272,41 @@ pub use role::*;
 /// assert!(!PHONE_REGEX.is_match("14155551234")); // Missing +
 /// assert!(!PHONE_REGEX.is_match("+1234")); // Too short
 /// ```
+#[allow(clippy::disallowed_methods)]
 pub static PHONE_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^\+[1-9]\d{9,14}$").expect("valid phone regex"));
 
+/// Check whether `phone` is a valid E.164 number.
+///
+/// Single entry point for phone validation; reuses [`PHONE_REGEX`]
+/// instead of compiling a new regex.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::validate_phone;
+///
+/// assert!(validate_phone("+14155551234"));
+/// assert!(!validate_phone("+1 415 555 1234"));
+/// ```
+#[must_use]
+pub fn validate_phone(phone: &str) -> bool {
+    PHONE_REGEX.is_match(phone)
+}
+
+/// `validator` hook around [`validate_phone`] for DTO fields.
+///
+/// Keeps the `regex` error code (and `value` param) that
+/// `#[validate(regex(..))]` produced, so API error bodies are unchanged.
+pub(crate) fn phone_field(phone: &str) -> Result<(), validator::ValidationError> {
+    if validate_phone(phone) {
+        return Ok(());
+    }
+    let mut err = validator::ValidationError::new("regex");
+    err.add_param("value".into(), &phone);
+    Err(err)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,4 +325,26 @@ mod tests {
         assert!(!PHONE_REGEX.is_match("+0123456789"));
         assert!(!PHONE_REGEX.is_match("not a phone"));
     }
+
+    #[test]
+    fn validate_phone_checks_e164() {
+        for (phone, valid) in [
+            ("+14155551234", true),
+            ("+442071234567", true),
+            ("+0123456789", false),
+            ("+1234", false),
+            ("14155551234", false),
+            ("+1415555123456789", false),
+            ("", false),
+            ("not a phone", false)
+        ] {
+            assert_eq!(validate_phone(phone), valid, "{phone}");
+        }
+    }
+
+    #[test]
+    fn phone_field_keeps_regex_code() {
+        assert!(phone_field("+14155551234").is_ok());
+        assert_eq!(phone_field("+1234").unwrap_err().code, "regex");
+    }
 }
@@ -1,7 +1,7 @@
 // SPDX-FileCopyrightText: 2025 Revelation Team
 // SPDX-License-Identifier: MIT
 
-//! Notification recipient types.
+//! Notification recipient types and channel preferences.
 //!
 //! This module provides types for notification delivery targets.
 //! Currently supports Telegram notifications with planned expansion
@@ -12,6 +12,9 @@
 //! | Type | Description |
 //! |------|-------------|
 //! | [`TelegramRecipient`] | Telegram chat/user as notification target |
+//! | [`Channel`] | Delivery channel (Telegram, email, push) |
+//! | [`NotificationPrefs`] | Per-user channel opt-ins stored on [`RUser`] |
+//! | [`BroadcastReport`] | Outcome of [`broadcast_telegram`] |
 //!
 //! # Use Cases
 //!
@@ -57,9 +60,15 @@
 //! ```
 //!
 //! [`NotificationRepository`]: crate::ports::NotificationRepository
+//! [`RUser`]: crate::RUser
 
+use std::{collections::HashSet, future::Future};
+
+use masterror::AppResult;
 use serde::{Deserialize, Serialize};
 
+use crate::{RUser, ports::NotificationRepository};
+
 /// Telegram notification recipient.
 ///
 /// Represents a Telegram user or chat that can receive
@@ -134,7 +143,28 @@ use serde::{Deserialize, Serialize};
 ///     println!("Sending to chat: {}", recipient.chat_id);
 /// }
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// ## Sorting and Deduplication
+///
+/// Recipients order by `chat_id`, so they can be sorted or
+/// collected into a `BTreeSet`:
+///
+/// ```rust
+/// use std::collections::BTreeSet;
+///
+/// use revelation_user::TelegramRecipient;
+///
+/// let recipients = vec![
+///     TelegramRecipient::new(333),
+///     TelegramRecipient::new(111),
+///     TelegramRecipient::new(333),
+/// ];
+///
+/// let unique: BTreeSet<_> = recipients.into_iter().collect();
+/// assert_eq!(unique.len(), 2);
+/// assert_eq!(unique.first(), Some(&TelegramRecipient::new(111)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct TelegramRecipient {
     /// Telegram chat or user ID.
     ///
@@ -203,6 +233,40 @@ impl TelegramRecipient {
     pub const fn is_group(&self) -> bool {
         self.chat_id < 0
     }
+
+    /// Drop invalid recipients and duplicates, keeping first-seen order.
+    ///
+    /// Recipients with `chat_id == 0` are removed since Telegram never
+    /// issues that ID. Useful when merging recipients from several
+    /// sources before a broadcast.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::TelegramRecipient;
+    ///
+    /// let recipients = vec![
+    ///     TelegramRecipient::new(222),
+    ///     TelegramRecipient::new(0),
+    ///     TelegramRecipient::new(111),
+    ///     TelegramRecipient::new(222),
+    /// ];
+    ///
+    /// let cleaned = TelegramRecipient::dedup_valid(recipients);
+    /// assert_eq!(
+    ///     cleaned,
+    ///     vec![TelegramRecipient::new(222), TelegramRecipient::new(111)]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn dedup_valid(recipients: Vec<TelegramRecipient>) -> Vec<TelegramRecipient> {
+        let mut seen = HashSet::with_capacity(recipients.len());
+
+        recipients
+            .into_iter()
+            .filter(|r| r.chat_id != 0 && seen.insert(r.chat_id))
+            .collect()
+    }
 }
 
 impl From<i64> for TelegramRecipient {
@@ -221,8 +285,210 @@ impl From<i64> for TelegramRecipient {
     }
 }
 
+/// Notification delivery channel.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::Channel;
+///
+/// let json = serde_json::to_string(&Channel::Push).unwrap();
+/// assert_eq!(json, "\"push\"");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Channel {
+    /// Telegram bot messages.
+    Telegram,
+
+    /// Email messages.
+    Email,
+
+    /// Mobile or web push notifications.
+    Push
+}
+
+/// Which notification channels a user accepts.
+///
+/// Defaults to Telegram and email on, push off: push requires a device
+/// registration, so it is opt-in. Missing keys in JSON fall back to
+/// these defaults.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{Channel, NotificationPrefs};
+///
+/// let mut prefs = NotificationPrefs::default();
+/// assert!(prefs.accepts(Channel::Telegram));
+/// assert!(!prefs.accepts(Channel::Push));
+///
+/// prefs.email = false;
+/// assert!(!prefs.accepts(Channel::Email));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NotificationPrefs {
+    /// Accept Telegram messages.
+    pub telegram: bool,
+
+    /// Accept email messages.
+    pub email: bool,
+
+    /// Accept push notifications.
+    pub push: bool
+}
+
+impl NotificationPrefs {
+    /// Check if the given channel is enabled.
+    #[must_use]
+    pub const fn accepts(&self, channel: Channel) -> bool {
+        match channel {
+            Channel::Telegram => self.telegram,
+            Channel::Email => self.email,
+            Channel::Push => self.push
+        }
+    }
+}
+
+impl Default for NotificationPrefs {
+    fn default() -> Self {
+        Self {
+            telegram: true,
+            email:    true,
+            push:     false
+        }
+    }
+}
+
+impl RUser {
+    /// Check if the user accepts notifications on the given channel.
+    ///
+    /// Only reflects [`NotificationPrefs`]; whether the user has the
+    /// matching contact (e.g. an email address) is a separate check.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Channel, RUser};
+    ///
+    /// let mut user = RUser::from_telegram(123);
+    /// assert!(user.accepts(Channel::Telegram));
+    ///
+    /// user.notification_prefs.telegram = false;
+    /// assert!(!user.accepts(Channel::Telegram));
+    /// ```
+    #[must_use]
+    pub const fn accepts(&self, channel: Channel) -> bool {
+        self.notification_prefs.accepts(channel)
+    }
+}
+
+/// Outcome of [`broadcast_telegram`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct BroadcastReport {
+    /// Recipients the callback was invoked for.
+    pub attempted: u64,
+
+    /// Callbacks that returned `Ok`.
+    pub succeeded: u64
+}
+
+impl BroadcastReport {
+    /// Callbacks that returned `Err`.
+    #[must_use]
+    pub const fn failed(&self) -> u64 {
+        self.attempted - self.succeeded
+    }
+}
+
+/// Send to every Telegram recipient, one page at a time.
+///
+/// Pages through [`NotificationRepository::get_telegram_recipients_page`]
+/// so only `batch` recipients are held in memory, and awaits `f` for
+/// each recipient in turn. A failing callback is counted and the
+/// broadcast continues.
+///
+/// # Arguments
+///
+/// * `repo` - Recipient source
+/// * `batch` - Page size; `0` is treated as `1`
+/// * `f` - Delivery callback, e.g. a Telegram `sendMessage` call
+///
+/// # Errors
+///
+/// Returns the repository error if loading a page fails; recipients
+/// already handled are not reported in that case.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::AppResult;
+/// use revelation_user::{TelegramRecipient, broadcast_telegram, ports::NotificationRepository};
+///
+/// struct Repo;
+///
+/// impl NotificationRepository for Repo {
+///     async fn get_telegram_recipients(&self) -> AppResult<Vec<TelegramRecipient>> {
+///         Ok(vec![TelegramRecipient::new(1), TelegramRecipient::new(-2)])
+///     }
+/// }
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let report = broadcast_telegram(&Repo, 100, |r| async move {
+///     if r.is_user() {
+///         Ok(())
+///     } else {
+///         Err("groups muted")
+///     }
+/// })
+/// .await
+/// .unwrap();
+///
+/// assert_eq!((report.attempted, report.succeeded), (2, 1));
+/// # });
+/// ```
+///
+/// [`NotificationRepository::get_telegram_recipients_page`]: crate::ports::NotificationRepository::get_telegram_recipients_page
+pub async fn broadcast_telegram<F, Fut, E>(
+    repo: &impl NotificationRepository,
+    batch: u32,
+    f: F
+) -> AppResult<BroadcastReport>
+where
+    F: Fn(TelegramRecipient) -> Fut,
+    Fut: Future<Output = Result<(), E>>
+{
+    let batch = batch.max(1);
+    let mut report = BroadcastReport::default();
+    let mut offset = 0_u64;
+
+    loop {
+        let page = repo.get_telegram_recipients_page(offset, batch).await?;
+        let len = page.len() as u64;
+
+        for recipient in page {
+            report.attempted += 1;
+            if f(recipient).await.is_ok() {
+                report.succeeded += 1;
+            }
+        }
+
+        if len < u64::from(batch) {
+            return Ok(report);
+        }
+        offset += len;
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
     use super::*;
 
     #[test]
@@ -259,10 +525,173 @@ mod tests {
         assert_eq!(original, parsed);
     }
 
+    #[test]
+    fn sorts_by_chat_id() {
+        let mut recipients = vec![
+            TelegramRecipient::new(333),
+            TelegramRecipient::new(-100),
+            TelegramRecipient::new(111),
+        ];
+        recipients.sort();
+
+        assert_eq!(
+            recipients,
+            vec![
+                TelegramRecipient::new(-100),
+                TelegramRecipient::new(111),
+                TelegramRecipient::new(333),
+            ]
+        );
+    }
+
+    #[test]
+    fn btree_set_deduplicates() {
+        use std::collections::BTreeSet;
+
+        let set: BTreeSet<TelegramRecipient> = [222, 111, 222, 111]
+            .into_iter()
+            .map(TelegramRecipient::new)
+            .collect();
+
+        assert_eq!(set.len(), 2);
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            vec![TelegramRecipient::new(111), TelegramRecipient::new(222)]
+        );
+    }
+
+    #[test]
+    fn dedup_valid_removes_duplicates() {
+        let recipients = [111, 222, 111, 333, 222]
+            .into_iter()
+            .map(TelegramRecipient::new)
+            .collect();
+
+        let cleaned = TelegramRecipient::dedup_valid(recipients);
+
+        assert_eq!(cleaned.len(), 3);
+    }
+
+    #[test]
+    fn dedup_valid_drops_zero_id() {
+        let recipients = vec![TelegramRecipient::new(0), TelegramRecipient::new(111)];
+
+        let cleaned = TelegramRecipient::dedup_valid(recipients);
+
+        assert_eq!(cleaned, vec![TelegramRecipient::new(111)]);
+    }
+
+    #[test]
+    fn dedup_valid_preserves_first_seen_order() {
+        let recipients = [333, -100, 111, 333, -100]
+            .into_iter()
+            .map(TelegramRecipient::new)
+            .collect();
+
+        let cleaned = TelegramRecipient::dedup_valid(recipients);
+
+        assert_eq!(
+            cleaned,
+            vec![
+                TelegramRecipient::new(333),
+                TelegramRecipient::new(-100),
+                TelegramRecipient::new(111),
+            ]
+        );
+    }
+
+    #[test]
+    fn dedup_valid_empty_input() {
+        assert!(TelegramRecipient::dedup_valid(Vec::new()).is_empty());
+    }
+
     #[test]
     fn copy_semantics() {
         let original = TelegramRecipient::new(123);
         let copied = original;
         assert_eq!(original.chat_id, copied.chat_id);
     }
+
+    #[test]
+    fn prefs_default_to_telegram_and_email() {
+        let prefs = NotificationPrefs::default();
+
+        assert!(prefs.accepts(Channel::Telegram));
+        assert!(prefs.accepts(Channel::Email));
+        assert!(!prefs.accepts(Channel::Push));
+    }
+
+    #[test]
+    fn prefs_missing_keys_use_defaults() {
+        let prefs: NotificationPrefs = serde_json::from_str(r#"{"push":true}"#).unwrap();
+
+        assert_eq!(
+            prefs,
+            NotificationPrefs {
+                telegram: true,
+                email:    true,
+                push:     true
+            }
+        );
+    }
+
+    #[test]
+    fn user_accepts_follows_prefs() {
+        let mut user = RUser::from_email("user@example.com");
+        assert_eq!(user.notification_prefs, NotificationPrefs::default());
+        assert!(user.accepts(Channel::Email));
+
+        user.notification_prefs.email = false;
+        assert!(!user.accepts(Channel::Email));
+        assert!(user.accepts(Channel::Telegram));
+    }
+
+    struct PagedRepo {
+        recipients: Vec<TelegramRecipient>
+    }
+
+    impl NotificationRepository for PagedRepo {
+        async fn get_telegram_recipients(&self) -> AppResult<Vec<TelegramRecipient>> {
+            Ok(self.recipients.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn broadcast_invokes_callback_per_recipient() {
+        let repo = PagedRepo {
+            recipients: (1..=7).map(TelegramRecipient::new).collect()
+        };
+        let calls = AtomicU64::new(0);
+
+        let report = broadcast_telegram(&repo, 3, |recipient| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            async move {
+                if recipient.chat_id % 2 == 0 {
+                    Err("blocked")
+                } else {
+                    Ok(())
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(calls.load(Ordering::Relaxed), 7);
+        assert_eq!(report.attempted, 7);
+        assert_eq!(report.succeeded, 4);
+        assert_eq!(report.failed(), 3);
+    }
+
+    #[tokio::test]
+    async fn broadcast_over_empty_repo_reports_nothing() {
+        let repo = PagedRepo {
+            recipients: Vec::new()
+        };
+
+        let report = broadcast_telegram(&repo, 0, |_| async { Ok::<_, ()>(()) })
+            .await
+            .unwrap();
+
+        assert_eq!(report, BroadcastReport::default());
+    }
 }
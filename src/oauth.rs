@@ -0,0 +1,206 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! OAuth / OpenID Connect profile mapping.
+//!
+//! This module provides [`OAuthProfile`], a provider-agnostic view of
+//! the user info returned by Google, Apple, GitHub and similar sign-in
+//! providers, and [`RUser::from_oauth`] to turn it into a user.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use revelation_user::{OAuthProfile, RUser};
+//!
+//! let profile = OAuthProfile {
+//!     provider:   "google".into(),
+//!     subject:    "109876543210987654321".into(),
+//!     email:      Some("alice@gmail.com".into()),
+//!     name:       Some("Alice".into()),
+//!     avatar_url: Some("https://lh3.googleusercontent.com/a/photo".into())
+//! };
+//!
+//! let user = RUser::from_oauth(&profile);
+//!
+//! assert_eq!(user.email.as_deref(), Some("alice@gmail.com"));
+//! assert!(user.telegram_id.is_none());
+//! ```
+//!
+//! [`RUser::from_oauth`]: crate::RUser::from_oauth
+
+use serde::{Deserialize, Serialize};
+
+use crate::{RUser, SignupSource};
+
+/// Normalized user profile from an OAuth provider.
+///
+/// Fill this from the provider's ID token or userinfo endpoint;
+/// the crate does not talk to providers itself.
+///
+/// # Fields
+///
+/// | Field | Type | Description |
+/// |-------|------|-------------|
+/// | `provider` | `String` | Provider key (e.g., `"google"`, `"apple"`) |
+/// | `subject` | `String` | Provider's stable user ID (`sub` claim) |
+/// | `email` | `Option<String>` | Email address, if shared |
+/// | `name` | `Option<String>` | Display name, if shared |
+/// | `avatar_url` | `Option<String>` | Profile picture URL |
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::OAuthProfile;
+///
+/// // Apple may hide the name after the first sign-in
+/// let profile = OAuthProfile::new("apple", "001234.abcdef");
+///
+/// assert_eq!(profile.provider, "apple");
+/// assert!(profile.name.is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
+pub struct OAuthProfile {
+    /// Provider key, lowercase by convention.
+    pub provider: String,
+
+    /// Provider-specific stable user identifier.
+    pub subject: String,
+
+    /// Email address shared by the provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Display name shared by the provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Profile picture URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>
+}
+
+impl OAuthProfile {
+    /// Create a profile with only provider and subject set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::OAuthProfile;
+    ///
+    /// let profile = OAuthProfile::new("github", "583231");
+    /// assert_eq!(profile.subject, "583231");
+    /// assert!(profile.email.is_none());
+    /// ```
+    #[must_use]
+    pub fn new(provider: impl Into<String>, subject: impl Into<String>) -> Self {
+        Self {
+            provider:   provider.into(),
+            subject:    subject.into(),
+            email:      None,
+            name:       None,
+            avatar_url: None
+        }
+    }
+}
+
+impl RUser {
+    /// Create user from an OAuth provider profile.
+    ///
+    /// Copies email, name and avatar URL, and links the provider
+    /// subject in [`external_ids`](RUser::external_ids). Telegram ID
+    /// and phone are left unset; `signup_source` is
+    /// [`SignupSource::OAuth`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{OAuthProfile, RUser};
+    ///
+    /// let mut profile = OAuthProfile::new("google", "1098765");
+    /// profile.email = Some("bob@gmail.com".into());
+    ///
+    /// let user = RUser::from_oauth(&profile);
+    /// assert_eq!(user.email.as_deref(), Some("bob@gmail.com"));
+    /// assert_eq!(user.external_id("google"), Some("1098765"));
+    /// assert!(user.phone.is_none());
+    /// ```
+    #[must_use]
+    pub fn from_oauth(profile: &OAuthProfile) -> Self {
+        let mut user = Self {
+            email: profile.email.clone(),
+            name: profile.name.clone(),
+            avatar_url: profile.avatar_url.clone(),
+            signup_source: SignupSource::OAuth,
+            ..Self::empty()
+        };
+        user.external_ids
+            .insert(profile.provider.clone(), profile.subject.clone());
+        user
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn google_profile() -> OAuthProfile {
+        OAuthProfile {
+            provider:   "google".into(),
+            subject:    "109876543210987654321".into(),
+            email:      Some("alice@gmail.com".into()),
+            name:       Some("Alice Smith".into()),
+            avatar_url: Some("https://lh3.googleusercontent.com/a/photo".into())
+        }
+    }
+
+    #[test]
+    fn from_oauth_copies_profile_fields() {
+        let user = RUser::from_oauth(&google_profile());
+
+        assert_eq!(user.email.as_deref(), Some("alice@gmail.com"));
+        assert_eq!(user.name.as_deref(), Some("Alice Smith"));
+        assert_eq!(
+            user.avatar_url.as_deref(),
+            Some("https://lh3.googleusercontent.com/a/photo")
+        );
+    }
+
+    #[test]
+    fn from_oauth_links_provider_subject() {
+        let user = RUser::from_oauth(&google_profile());
+
+        assert_eq!(user.external_id("google"), Some("109876543210987654321"));
+        assert_eq!(user.version, 1);
+    }
+
+    #[test]
+    fn from_oauth_leaves_other_identifiers_empty() {
+        let user = RUser::from_oauth(&google_profile());
+
+        assert!(user.telegram_id.is_none());
+        assert!(user.phone.is_none());
+    }
+
+    #[test]
+    fn new_sets_only_provider_and_subject() {
+        let profile = OAuthProfile::new("apple", "001234.abcdef");
+
+        assert_eq!(profile.provider, "apple");
+        assert_eq!(profile.subject, "001234.abcdef");
+        assert!(profile.email.is_none());
+        assert!(profile.name.is_none());
+        assert!(profile.avatar_url.is_none());
+    }
+
+    #[test]
+    fn serialization_roundtrip() {
+        let profile = google_profile();
+        let json = serde_json::to_string(&profile).unwrap();
+        let parsed: OAuthProfile = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(profile, parsed);
+    }
+}
@@ -0,0 +1,207 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! Tri-state values for PATCH requests.
+//!
+//! `Option<T>` cannot tell "field omitted" from "field set to `null`".
+//! [`Patch<T>`] keeps the two apart:
+//!
+//! | JSON | Variant | Meaning |
+//! |------|---------|---------|
+//! | key missing | [`Patch::Unchanged`] | Leave the field as is |
+//! | `null` | [`Patch::Clear`] | Reset the field to `None` |
+//! | value | [`Patch::Set`] | Replace the field |
+//!
+//! A missing key only maps to `Unchanged` when the field carries
+//! `#[serde(default)]`, since serde never calls `Deserialize` for absent
+//! keys. Pair it with `skip_serializing_if = "Patch::is_unchanged"` so
+//! serialization round-trips.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use revelation_user::Patch;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Req {
+//!     #[serde(default)]
+//!     nickname: Patch<String>
+//! }
+//!
+//! let req: Req = serde_json::from_str("{}").unwrap();
+//! assert_eq!(req.nickname, Patch::Unchanged);
+//!
+//! let req: Req = serde_json::from_str(r#"{"nickname":null}"#).unwrap();
+//! assert_eq!(req.nickname, Patch::Clear);
+//!
+//! let req: Req = serde_json::from_str(r#"{"nickname":"neo"}"#).unwrap();
+//! assert_eq!(req.nickname, Patch::Set("neo".into()));
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Field update in a PATCH request.
+///
+/// A missing key reads as [`Patch::Unchanged`] (the field needs
+/// `#[serde(default)]`), `null` as [`Patch::Clear`] and any other value
+/// as [`Patch::Set`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Patch<T> {
+    /// Key was absent; keep the current value.
+    #[default]
+    Unchanged,
+
+    /// Key held a value; replace the current value with it.
+    Set(T),
+
+    /// Key was `null`; reset the current value to `None`.
+    Clear
+}
+
+impl<T> Patch<T> {
+    /// Returns `true` for [`Patch::Unchanged`].
+    ///
+    /// Intended for `#[serde(skip_serializing_if = "Patch::is_unchanged")]`.
+    #[must_use]
+    pub const fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+
+    /// Returns the new value if this is [`Patch::Set`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::Patch;
+    ///
+    /// assert_eq!(Patch::Set(5).as_set(), Some(&5));
+    /// assert_eq!(Patch::<i32>::Clear.as_set(), None);
+    /// ```
+    #[must_use]
+    pub const fn as_set(&self) -> Option<&T> {
+        match self {
+            Self::Set(value) => Some(value),
+            Self::Unchanged | Self::Clear => None
+        }
+    }
+
+    /// Convert into a nested option.
+    ///
+    /// `Unchanged` becomes `None`, `Clear` becomes `Some(None)` and
+    /// `Set(v)` becomes `Some(Some(v))`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::Patch;
+    ///
+    /// assert_eq!(Patch::<i32>::Unchanged.into_option(), None);
+    /// assert_eq!(Patch::<i32>::Clear.into_option(), Some(None));
+    /// assert_eq!(Patch::Set(5).into_option(), Some(Some(5)));
+    /// ```
+    #[must_use]
+    pub fn into_option(self) -> Option<Option<T>> {
+        match self {
+            Self::Unchanged => None,
+            Self::Set(value) => Some(Some(value)),
+            Self::Clear => Some(None)
+        }
+    }
+
+    /// Apply this patch to a field.
+    ///
+    /// Returns `true` if the field was written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::Patch;
+    ///
+    /// let mut field = Some(1);
+    ///
+    /// assert!(!Patch::Unchanged.apply_to(&mut field));
+    /// assert_eq!(field, Some(1));
+    ///
+    /// assert!(Patch::Clear.apply_to(&mut field));
+    /// assert_eq!(field, None);
+    /// ```
+    pub fn apply_to(self, field: &mut Option<T>) -> bool {
+        match self.into_option() {
+            Some(value) => {
+                *field = value;
+                true
+            }
+            None => false
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Patch<T> {
+    /// `Some(v)` becomes [`Patch::Set`], `None` becomes [`Patch::Clear`].
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Clear, Self::Set)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Patch<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        Option::<T>::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<T: Serialize> Serialize for Patch<T> {
+    /// Serializes `Set(v)` as `v` and everything else as `null`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        match self {
+            Self::Set(value) => serializer.serialize_some(value),
+            Self::Unchanged | Self::Clear => serializer.serialize_none()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Wrapper {
+        #[serde(default, skip_serializing_if = "Patch::is_unchanged")]
+        value: Patch<i32>
+    }
+
+    #[test]
+    fn default_is_unchanged() {
+        assert_eq!(Patch::<i32>::default(), Patch::Unchanged);
+    }
+
+    #[test]
+    fn round_trips_all_variants() {
+        for (patch, json) in [
+            (Patch::Unchanged, "{}"),
+            (Patch::Clear, r#"{"value":null}"#),
+            (Patch::Set(7), r#"{"value":7}"#)
+        ] {
+            let wrapper = Wrapper {
+                value: patch
+            };
+
+            assert_eq!(serde_json::to_string(&wrapper).unwrap(), json);
+            assert_eq!(serde_json::from_str::<Wrapper>(json).unwrap(), wrapper);
+        }
+    }
+
+    #[test]
+    fn apply_to_sets_value() {
+        let mut field = None;
+
+        assert!(Patch::Set(3).apply_to(&mut field));
+        assert_eq!(field, Some(3));
+    }
+}
@@ -45,6 +45,12 @@
 //! let editor_perms = Permissions::READ | Permissions::WRITE;
 //! assert!(editor_perms.contains(Permissions::READ));
 //!
+//! // Or collect them; `FromIterator` and `Extend` OR the values together
+//! let collected: Permissions = [Permissions::READ, Permissions::WRITE]
+//!     .into_iter()
+//!     .collect();
+//! assert_eq!(collected, editor_perms);
+//!
 //! // Check multiple permissions at once
 //! let required = Permissions::READ | Permissions::WRITE;
 //! assert!(admin.permissions().contains(required));
@@ -146,7 +152,8 @@ bitflags::bitflags! {
     /// # Serialization
     ///
     /// - **Serialize**: Always outputs a number (compact for JWT/DB)
-    /// - **Deserialize**: Accepts both number and string formats
+    /// - **Deserialize**: Accepts a number, a name string (`"read, write"`)
+    ///   or a list of names; floats and out-of-range numbers are rejected
     /// - **Display**: Human-readable format for logs
     ///
     /// ```rust
@@ -287,7 +294,7 @@ impl serde::Serialize for Permissions {
     }
 }
 
-// Custom deserialization: accept both number and string
+// Custom deserialization: accept a number, a string or a list of names
 impl<'de> serde::Deserialize<'de> for Permissions {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -295,19 +302,20 @@ impl<'de> serde::Deserialize<'de> for Permissions {
     {
         struct PermissionsVisitor;
 
-        impl serde::de::Visitor<'_> for PermissionsVisitor {
+        impl<'de> serde::de::Visitor<'de> for PermissionsVisitor {
             type Value = Permissions;
 
             fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
-                formatter.write_str("a number or permission string")
+                formatter.write_str("a number, permission string or list of permission names")
             }
 
             fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
             where
                 E: serde::de::Error
             {
-                Permissions::from_bits(value as u32)
-                    .ok_or_else(|| E::custom(format!("invalid permission bits: {value}")))
+                let bits = u32::try_from(value)
+                    .map_err(|_| E::custom(format!("invalid permission bits: {value}")))?;
+                Permissions::try_from_bits(bits).map_err(E::custom)
             }
 
             fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
@@ -315,44 +323,90 @@ impl<'de> serde::Deserialize<'de> for Permissions {
                 E: serde::de::Error
             {
                 if value < 0 {
-                    return Err(E::custom("permissions cannot be negative"));
+                    return Err(E::custom(PermissionError::Negative));
                 }
                 self.visit_u64(value as u64)
             }
 
+            fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error
+            {
+                Err(E::custom(format!(
+                    "permissions must be an integer, got {value}"
+                )))
+            }
+
             fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
             where
                 E: serde::de::Error
             {
                 parse_permissions(value).map_err(E::custom)
             }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>
+            {
+                let mut result = Permissions::empty();
+                while let Some(name) = seq.next_element::<String>()? {
+                    result |= parse_permissions(&name).map_err(serde::de::Error::custom)?;
+                }
+                Ok(result)
+            }
         }
 
         deserializer.deserialize_any(PermissionsVisitor)
     }
 }
 
+// JSON Schema mirroring the serde impls: bits out, bits, names or name lists in
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Permissions {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "Permissions".into()
+    }
+
+    fn json_schema(_generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Permission bit flags as a number, or names like \"read, write\"",
+            "oneOf": [
+                {
+                    "type": "integer",
+                    "format": "uint32",
+                    "minimum": 0,
+                    "maximum": Permissions::all().bits()
+                },
+                {
+                    "type": "string"
+                },
+                {
+                    "type": "array",
+                    "items": { "type": "string" }
+                }
+            ]
+        })
+    }
+}
+
 /// Parse permissions from a string like "read, write" or "READ | WRITE".
-fn parse_permissions(s: &str) -> Result<Permissions, String> {
+///
+/// Names are matched ASCII case-insensitively without allocating;
+/// non-ASCII input is never a valid name. Only the unknown-name error
+/// path allocates, to report the lowercased name.
+pub(crate) fn parse_permissions(s: &str) -> Result<Permissions, PermissionError> {
     let mut result = Permissions::empty();
 
     for part in s.split([',', '|']) {
-        let name = part.trim().to_lowercase();
-        let perm = match name.as_str() {
-            "read" => Permissions::READ,
-            "write" => Permissions::WRITE,
-            "delete" => Permissions::DELETE,
-            "admin" => Permissions::ADMIN,
-            "manage_users" => Permissions::MANAGE_USERS,
-            "manage_roles" => Permissions::MANAGE_ROLES,
-            "billing" => Permissions::BILLING,
-            "audit" => Permissions::AUDIT,
-            "export" => Permissions::EXPORT,
-            "import" => Permissions::IMPORT,
-            "api_access" => Permissions::API_ACCESS,
-            "premium" => Permissions::PREMIUM,
-            "" => continue,
-            _ => return Err(format!("unknown permission: {name}"))
+        let name = part.trim();
+        if name.is_empty() {
+            continue;
+        }
+        let Some(&(perm, _)) = FLAG_NAMES
+            .iter()
+            .find(|(_, known)| name.eq_ignore_ascii_case(known))
+        else {
+            return Err(PermissionError::UnknownName(name.to_lowercase()));
         };
         result |= perm;
     }
@@ -404,6 +458,34 @@ impl Permissions {
         Self::from_bits(bits)
     }
 
+    /// Create permissions from a raw bits value, reporting invalid bits.
+    ///
+    /// Like [`Permissions::from_bits_checked`], but with an error to
+    /// propagate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PermissionError::InvalidBits`] if `bits` contains bits
+    /// that do not correspond to a permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{PermissionError, Permissions};
+    ///
+    /// assert_eq!(Permissions::try_from_bits(0x0001), Ok(Permissions::READ));
+    /// assert_eq!(
+    ///     Permissions::try_from_bits(0x8000_0000),
+    ///     Err(PermissionError::InvalidBits(0x8000_0000))
+    /// );
+    /// ```
+    pub const fn try_from_bits(bits: u32) -> Result<Self, PermissionError> {
+        match Self::from_bits(bits) {
+            Some(permissions) => Ok(permissions),
+            None => Err(PermissionError::InvalidBits(bits))
+        }
+    }
+
     /// Check if no permissions are set.
     ///
     /// # Examples
@@ -457,6 +539,118 @@ impl Permissions {
     pub const fn from_bits_truncating(bits: u32) -> Self {
         Self::from_bits_truncate(bits)
     }
+
+    /// Build permissions from a list of permission names.
+    ///
+    /// Names are case-insensitive and use the same spelling as the
+    /// string deserializer (`"read"`, `"manage_users"`, ...).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PermissionError::UnknownName`] for the first name that
+    /// does not match a permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{PermissionError, Permissions};
+    ///
+    /// let form = vec!["read".to_string(), "Export".to_string()];
+    /// let perms = Permissions::try_from_names(&form).unwrap();
+    /// assert_eq!(perms, Permissions::READ | Permissions::EXPORT);
+    ///
+    /// let err = Permissions::try_from_names(["read", "fly"]).unwrap_err();
+    /// assert_eq!(err, PermissionError::UnknownName("fly".into()));
+    /// ```
+    pub fn try_from_names<I, S>(names: I) -> Result<Self, PermissionError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>
+    {
+        names.into_iter().try_fold(Self::empty(), |acc, name| {
+            parse_permissions(name.as_ref()).map(|p| acc | p)
+        })
+    }
+
+    /// Add permissions to this set.
+    ///
+    /// Equivalent to `*self |= permissions`; granting an already
+    /// present permission is a no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::Permissions;
+    ///
+    /// let mut perms = Permissions::READ;
+    /// perms.grant(Permissions::WRITE);
+    /// assert_eq!(perms, Permissions::READ | Permissions::WRITE);
+    /// ```
+    #[inline]
+    pub const fn grant(&mut self, permissions: Self) {
+        *self = self.union(permissions);
+    }
+
+    /// Remove permissions from this set.
+    ///
+    /// Equivalent to `*self &= !permissions`; revoking a missing
+    /// permission is a no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::Permissions;
+    ///
+    /// let mut perms = Permissions::READ | Permissions::WRITE;
+    /// perms.revoke(Permissions::WRITE);
+    /// assert_eq!(perms, Permissions::READ);
+    /// ```
+    #[inline]
+    pub const fn revoke(&mut self, permissions: Self) {
+        *self = self.difference(permissions);
+    }
+
+    /// Every single-bit permission, in bit order.
+    ///
+    /// Composite presets like [`Permissions::EDITOR`] are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::Permissions;
+    ///
+    /// let flags = Permissions::all_flags();
+    /// assert_eq!(flags[0], Permissions::READ);
+    /// assert_eq!(
+    ///     flags.into_iter().collect::<Permissions>(),
+    ///     Permissions::all()
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn all_flags() -> [Self; 12] {
+        [
+            Self::READ,
+            Self::WRITE,
+            Self::DELETE,
+            Self::ADMIN,
+            Self::MANAGE_USERS,
+            Self::MANAGE_ROLES,
+            Self::BILLING,
+            Self::AUDIT,
+            Self::EXPORT,
+            Self::IMPORT,
+            Self::API_ACCESS,
+            Self::PREMIUM
+        ]
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Permissions {
+    /// Any combination of defined flags; undefined bits are dropped.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_bits_truncate(u.arbitrary()?))
+    }
 }
 
 impl Default for Permissions {
@@ -469,55 +663,136 @@ impl Default for Permissions {
     }
 }
 
-impl core::fmt::Display for Permissions {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+/// Display names of the single-bit flags, in bit order.
+const FLAG_NAMES: [(Permissions, &str); 12] = [
+    (Permissions::READ, "read"),
+    (Permissions::WRITE, "write"),
+    (Permissions::DELETE, "delete"),
+    (Permissions::ADMIN, "admin"),
+    (Permissions::MANAGE_USERS, "manage_users"),
+    (Permissions::MANAGE_ROLES, "manage_roles"),
+    (Permissions::BILLING, "billing"),
+    (Permissions::AUDIT, "audit"),
+    (Permissions::EXPORT, "export"),
+    (Permissions::IMPORT, "import"),
+    (Permissions::API_ACCESS, "api_access"),
+    (Permissions::PREMIUM, "premium")
+];
+
+impl Permissions {
+    /// Write the `Display` form straight into `out`, without allocating.
+    ///
+    /// Produces the comma-separated flag names, or `none` when empty.
+    /// Useful on hot paths such as audit logging into a reused buffer.
+    ///
+    /// # Errors
+    ///
+    /// Propagates errors from `out`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::Permissions;
+    ///
+    /// let mut buf = String::new();
+    /// (Permissions::READ | Permissions::EXPORT)
+    ///     .write_to(&mut buf)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(buf, "read, export");
+    /// ```
+    pub fn write_to(&self, out: &mut impl core::fmt::Write) -> core::fmt::Result {
         if self.is_empty() {
-            return write!(f, "none");
+            return out.write_str("none");
         }
 
-        let mut parts = Vec::new();
-
-        if self.contains(Self::READ) {
-            parts.push("read");
-        }
-        if self.contains(Self::WRITE) {
-            parts.push("write");
-        }
-        if self.contains(Self::DELETE) {
-            parts.push("delete");
-        }
-        if self.contains(Self::ADMIN) {
-            parts.push("admin");
-        }
-        if self.contains(Self::MANAGE_USERS) {
-            parts.push("manage_users");
-        }
-        if self.contains(Self::MANAGE_ROLES) {
-            parts.push("manage_roles");
-        }
-        if self.contains(Self::BILLING) {
-            parts.push("billing");
-        }
-        if self.contains(Self::AUDIT) {
-            parts.push("audit");
-        }
-        if self.contains(Self::EXPORT) {
-            parts.push("export");
-        }
-        if self.contains(Self::IMPORT) {
-            parts.push("import");
-        }
-        if self.contains(Self::API_ACCESS) {
-            parts.push("api_access");
+        let mut first = true;
+        for (flag, name) in FLAG_NAMES {
+            if self.contains(flag) {
+                if !first {
+                    out.write_str(", ")?;
+                }
+                out.write_str(name)?;
+                first = false;
+            }
         }
-        if self.contains(Self::PREMIUM) {
-            parts.push("premium");
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for Permissions {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write_to(f)
+    }
+}
+
+impl core::str::FromStr for Permissions {
+    type Err = PermissionError;
+
+    /// Parse names separated by `,` or `|`, e.g. `"read, write"`.
+    ///
+    /// Names are case-insensitive; an empty string yields no permissions.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_permissions(s)
+    }
+}
+
+impl TryFrom<i32> for Permissions {
+    type Error = PermissionError;
+
+    /// Convert a signed integer, e.g. a PostgreSQL `INTEGER` column.
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        if value < 0 {
+            return Err(PermissionError::Negative);
         }
+        Self::try_from_bits(value as u32)
+    }
+}
+
+/// Error returned when permissions cannot be parsed or converted.
+///
+/// Serde deserialization reports the same messages via
+/// `Error::custom`.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{PermissionError, Permissions};
+///
+/// match "read, fly".parse::<Permissions>() {
+///     Err(PermissionError::UnknownName(name)) => assert_eq!(name, "fly"),
+///     other => panic!("unexpected result: {other:?}")
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PermissionError {
+    /// A permission name is not recognized (lowercased name attached).
+    UnknownName(String),
+
+    /// Raw bits contain flags outside the defined permissions.
+    InvalidBits(u32),
+
+    /// A signed value was negative.
+    Negative,
 
-        write!(f, "{}", parts.join(", "))
+    /// A role name is not recognized (lowercased name attached), as
+    /// reported by [`parse_role_with_extras`](crate::parse_role_with_extras).
+    UnknownRole(String)
+}
+
+impl core::fmt::Display for PermissionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownName(name) => write!(f, "unknown permission: {name}"),
+            Self::InvalidBits(bits) => write!(f, "invalid permission bits: {bits}"),
+            Self::Negative => f.write_str("permissions cannot be negative"),
+            Self::UnknownRole(name) => write!(f, "unknown role: {name}")
+        }
     }
 }
 
+impl std::error::Error for PermissionError {}
+
 /// Trait for types that represent a role with permissions.
 ///
 /// Implement this trait for custom role enums to integrate
@@ -632,6 +907,115 @@ pub trait Role: Send + Sync {
 mod tests {
     use super::*;
 
+    /// The `Vec` + `join` implementation `Display` used before `write_to`.
+    fn legacy_display(perms: Permissions) -> String {
+        if perms.is_empty() {
+            return "none".to_owned();
+        }
+
+        let parts: Vec<&str> = [
+            (Permissions::READ, "read"),
+            (Permissions::WRITE, "write"),
+            (Permissions::DELETE, "delete"),
+            (Permissions::ADMIN, "admin"),
+            (Permissions::MANAGE_USERS, "manage_users"),
+            (Permissions::MANAGE_ROLES, "manage_roles"),
+            (Permissions::BILLING, "billing"),
+            (Permissions::AUDIT, "audit"),
+            (Permissions::EXPORT, "export"),
+            (Permissions::IMPORT, "import"),
+            (Permissions::API_ACCESS, "api_access"),
+            (Permissions::PREMIUM, "premium")
+        ]
+        .into_iter()
+        .filter(|(flag, _)| perms.contains(*flag))
+        .map(|(_, name)| name)
+        .collect();
+
+        parts.join(", ")
+    }
+
+    #[test]
+    fn parse_is_case_insensitive_for_mixed_separators() {
+        assert_eq!(
+            parse_permissions("READ | write").unwrap(),
+            Permissions::READ | Permissions::WRITE
+        );
+        assert_eq!(
+            parse_permissions("Api_Access").unwrap(),
+            Permissions::API_ACCESS
+        );
+        assert_eq!(
+            parse_permissions("READ, Bogus"),
+            Err(PermissionError::UnknownName("bogus".into()))
+        );
+    }
+
+    #[test]
+    fn display_matches_legacy_output_byte_for_byte() {
+        for bits in 0..=Permissions::all().bits() {
+            let perms = Permissions::from_bits_truncate(bits);
+            let mut buf = String::new();
+            perms.write_to(&mut buf).unwrap();
+
+            assert_eq!(
+                buf.as_bytes(),
+                legacy_display(perms).as_bytes(),
+                "{bits:#x}"
+            );
+            assert_eq!(perms.to_string(), buf);
+        }
+    }
+
+    #[test]
+    fn collects_from_iterator_with_bitwise_or() {
+        let perms: Permissions = [Permissions::READ, Permissions::WRITE, Permissions::DELETE]
+            .into_iter()
+            .collect();
+
+        assert_eq!(
+            perms,
+            Permissions::READ | Permissions::WRITE | Permissions::DELETE
+        );
+        assert_eq!(
+            core::iter::empty().collect::<Permissions>(),
+            Permissions::empty()
+        );
+    }
+
+    #[test]
+    fn extend_ors_into_existing_set() {
+        let mut perms = Permissions::READ;
+        perms.extend([Permissions::WRITE, Permissions::DELETE, Permissions::READ]);
+
+        assert_eq!(
+            perms,
+            Permissions::READ | Permissions::WRITE | Permissions::DELETE
+        );
+    }
+
+    #[test]
+    fn all_flags_are_single_bits_covering_all() {
+        let flags = Permissions::all_flags();
+
+        assert!(flags.iter().all(|flag| flag.bits().is_power_of_two()));
+        assert_eq!(
+            flags.into_iter().collect::<Permissions>(),
+            Permissions::all()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "schemars")]
+    fn json_schema_accepts_number_or_string() {
+        let schema = serde_json::to_value(schemars::schema_for!(Permissions)).unwrap();
+        let variants = schema["oneOf"].as_array().unwrap();
+
+        assert_eq!(variants[0]["type"], "integer");
+        assert_eq!(variants[0]["maximum"], Permissions::all().bits());
+        assert_eq!(variants[1]["type"], "string");
+    }
+
     #[test]
     fn permissions_bitwise_operations() {
         let read_write = Permissions::READ | Permissions::WRITE;
@@ -794,6 +1178,118 @@ mod tests {
         assert_eq!(perms, Permissions::WRITE);
     }
 
+    #[test]
+    fn try_from_names_folds_valid_list() {
+        let names = vec![
+            "read".to_string(),
+            "WRITE".to_string(),
+            "api_access".to_string(),
+        ];
+        let perms = Permissions::try_from_names(names).unwrap();
+
+        assert_eq!(
+            perms,
+            Permissions::READ | Permissions::WRITE | Permissions::API_ACCESS
+        );
+    }
+
+    #[test]
+    fn try_from_names_reports_first_unknown() {
+        let err = Permissions::try_from_names(["read", "fly", "swim"]).unwrap_err();
+
+        assert_eq!(err, PermissionError::UnknownName("fly".into()));
+        assert_eq!(err.to_string(), "unknown permission: fly");
+    }
+
+    #[test]
+    fn from_str_reports_unknown_name() {
+        assert_eq!(
+            "read | write".parse::<Permissions>(),
+            Ok(Permissions::READ | Permissions::WRITE)
+        );
+        assert!(matches!(
+            "read, Teleport".parse::<Permissions>(),
+            Err(PermissionError::UnknownName(name)) if name == "teleport"
+        ));
+    }
+
+    #[test]
+    fn try_from_bits_reports_invalid_bits() {
+        assert_eq!(
+            Permissions::try_from_bits(0x0C00),
+            Ok(Permissions::API_ACCESS | Permissions::PREMIUM)
+        );
+        assert!(matches!(
+            Permissions::try_from_bits(0x1000),
+            Err(PermissionError::InvalidBits(0x1000))
+        ));
+    }
+
+    #[test]
+    fn try_from_i32_reports_negative() {
+        assert_eq!(
+            Permissions::try_from(3_i32),
+            Ok(Permissions::READ | Permissions::WRITE)
+        );
+        assert!(matches!(
+            Permissions::try_from(-1_i32),
+            Err(PermissionError::Negative)
+        ));
+        assert!(matches!(
+            Permissions::try_from(i32::MAX),
+            Err(PermissionError::InvalidBits(bits)) if bits == i32::MAX as u32
+        ));
+    }
+
+    #[test]
+    fn serde_errors_use_permission_error_messages() {
+        let negative = serde_json::from_value::<Permissions>(serde_json::json!(-1)).unwrap_err();
+        assert_eq!(negative.to_string(), PermissionError::Negative.to_string());
+
+        let invalid = serde_json::from_value::<Permissions>(serde_json::json!(4096)).unwrap_err();
+        assert_eq!(
+            invalid.to_string(),
+            PermissionError::InvalidBits(4096).to_string()
+        );
+
+        let unknown = serde_json::from_value::<Permissions>(serde_json::json!("fly")).unwrap_err();
+        assert!(unknown.to_string().contains("unknown permission: fly"));
+    }
+
+    #[test]
+    fn try_from_names_empty_is_empty() {
+        let names: [&str; 0] = [];
+
+        assert_eq!(
+            Permissions::try_from_names(names).unwrap(),
+            Permissions::empty()
+        );
+    }
+
+    #[test]
+    fn grant_then_revoke() {
+        let mut perms = Permissions::READ;
+
+        perms.grant(Permissions::EXPORT);
+        assert!(perms.contains(Permissions::EXPORT));
+
+        perms.revoke(Permissions::EXPORT);
+        assert_eq!(perms, Permissions::READ);
+    }
+
+    #[test]
+    fn grant_and_revoke_are_idempotent() {
+        let mut perms = Permissions::READ;
+
+        perms.grant(Permissions::WRITE);
+        perms.grant(Permissions::WRITE);
+        assert_eq!(perms, Permissions::READ | Permissions::WRITE);
+
+        perms.revoke(Permissions::WRITE);
+        perms.revoke(Permissions::WRITE);
+        assert_eq!(perms, Permissions::READ);
+    }
+
     #[test]
     fn permissions_presets() {
         assert_eq!(Permissions::VIEWER, Permissions::READ);
@@ -908,4 +1404,117 @@ mod tests {
         // Zero i64 path
         assert_de_tokens(&Permissions::empty(), &[Token::I64(0)]);
     }
+
+    #[test]
+    fn permissions_deserialize_token_matrix() {
+        use serde_test::{Token, assert_de_tokens, assert_de_tokens_error};
+
+        let read_write = Permissions::READ | Permissions::WRITE;
+
+        assert_de_tokens(&read_write, &[Token::U64(3)]);
+        assert_de_tokens(&read_write, &[Token::U32(3)]);
+        assert_de_tokens(&read_write, &[Token::I64(3)]);
+        assert_de_tokens(&read_write, &[Token::Str("read, write")]);
+        assert_de_tokens(&read_write, &[Token::String("READ | WRITE")]);
+        assert_de_tokens(
+            &read_write,
+            &[
+                Token::Seq {
+                    len: Some(2)
+                },
+                Token::Str("read"),
+                Token::Str("write"),
+                Token::SeqEnd
+            ]
+        );
+
+        assert_de_tokens_error::<Permissions>(
+            &[Token::F64(3.0)],
+            "permissions must be an integer, got 3"
+        );
+        assert_de_tokens_error::<Permissions>(&[Token::I64(-3)], "permissions cannot be negative");
+        assert_de_tokens_error::<Permissions>(
+            &[Token::Bool(true)],
+            "invalid type: boolean `true`, expected a number, permission string or list of \
+             permission names"
+        );
+    }
+
+    #[test]
+    fn permissions_deserialize_rejects_float_json() {
+        assert!(serde_json::from_str::<Permissions>("3.0").is_err());
+        assert!(serde_json::from_str::<Permissions>("1.5").is_err());
+        assert!(serde_json::from_value::<Permissions>(serde_json::json!(3.0)).is_err());
+    }
+
+    #[test]
+    fn permissions_deserialize_rejects_bits_beyond_u32() {
+        // 2^32 + 1 must not truncate to READ
+        assert!(serde_json::from_str::<Permissions>("4294967297").is_err());
+    }
+
+    #[test]
+    fn permissions_deserialize_from_seq() {
+        let perms: Permissions = serde_json::from_str(r#"["read", "export"]"#).unwrap();
+        assert_eq!(perms, Permissions::READ | Permissions::EXPORT);
+
+        let empty: Permissions = serde_json::from_str("[]").unwrap();
+        assert!(empty.is_empty());
+
+        assert!(serde_json::from_str::<Permissions>(r#"["read", "nope"]"#).is_err());
+        assert!(serde_json::from_str::<Permissions>("[1, 2]").is_err());
+    }
+
+    #[test]
+    fn permissions_deserialize_from_value_matrix() {
+        use serde_json::json;
+
+        let read_write = Permissions::READ | Permissions::WRITE;
+
+        for value in [
+            json!(3u64),
+            json!(3i64),
+            json!("read, write"),
+            json!(["read", "write"])
+        ] {
+            assert_eq!(
+                serde_json::from_value::<Permissions>(value).unwrap(),
+                read_write
+            );
+        }
+        for value in [json!(3.5), json!(-1), json!(true), json!(null), json!({})] {
+            assert!(serde_json::from_value::<Permissions>(value).is_err());
+        }
+    }
+
+    #[test]
+    fn permissions_deserialize_mixed_stream() {
+        let stream = r#"3 "read, write" ["read", "write"] 1"#;
+        let decoded: Vec<Permissions> = serde_json::Deserializer::from_str(stream)
+            .into_iter::<Permissions>()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let read_write = Permissions::READ | Permissions::WRITE;
+        assert_eq!(
+            decoded,
+            vec![read_write, read_write, read_write, Permissions::READ]
+        );
+    }
+
+    #[test]
+    fn permissions_field_accepts_either_form_in_one_document() {
+        #[derive(serde::Deserialize)]
+        struct Row {
+            permissions: Permissions
+        }
+
+        let rows: Vec<Row> = serde_json::from_str(
+            r#"[{"permissions": 3}, {"permissions": "read, write"}, {"permissions": 0}]"#
+        )
+        .unwrap();
+
+        assert_eq!(rows[0].permissions, rows[1].permissions);
+        assert!(rows[2].permissions.is_empty());
+    }
 }
@@ -0,0 +1,280 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! Validated phone numbers.
+//!
+//! This module provides [`PhoneNumber`], a newtype that always holds a
+//! normalized E.164 number, so code receiving one never re-validates.
+//!
+//! # Normalization
+//!
+//! [`PhoneNumber::parse`] trims the input and strips common formatting
+//! characters (spaces, `-`, `.`, `(`, `)`) before checking it against
+//! [`PHONE_REGEX`].
+//!
+//! | Input | Result |
+//! |-------|--------|
+//! | `"+14155551234"` | `+14155551234` |
+//! | `"+1 (415) 555-1234"` | `+14155551234` |
+//! | `"14155551234"` | Error (missing `+`) |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use revelation_user::PhoneNumber;
+//!
+//! let phone = PhoneNumber::parse("+1 (415) 555-1234").unwrap();
+//! assert_eq!(phone.as_str(), "+14155551234");
+//!
+//! // Rejected on deserialize as well
+//! assert!(serde_json::from_str::<PhoneNumber>("\"12345\"").is_err());
+//! ```
+//!
+//! [`PHONE_REGEX`]: crate::PHONE_REGEX
+
+use core::ops::Deref;
+
+use masterror::AppError;
+use serde::{Deserialize, Serialize};
+
+use crate::{RUser, validate_phone};
+
+/// Phone number in normalized E.164 format.
+///
+/// Can only be built through [`PhoneNumber::parse`] (or deserialization,
+/// which parses), so every value satisfies [`PHONE_REGEX`].
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::PhoneNumber;
+///
+/// let phone = PhoneNumber::parse("+44 20 7946 0958").unwrap();
+///
+/// // Derefs to str
+/// assert!(phone.starts_with("+44"));
+/// assert_eq!(phone.to_string(), "+442079460958");
+/// ```
+///
+/// [`PHONE_REGEX`]: crate::PHONE_REGEX
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+#[cfg_attr(feature = "db", derive(sqlx::Type))]
+#[cfg_attr(feature = "db", sqlx(transparent))]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PhoneNumber(String);
+
+impl PhoneNumber {
+    /// Normalize and validate a phone number.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - Phone number, optionally with spaces, dashes, dots or
+    ///   parentheses
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PhoneError`] if the normalized value is not E.164.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{PhoneError, PhoneNumber};
+    ///
+    /// assert!(PhoneNumber::parse("+14155551234").is_ok());
+    /// assert_eq!(PhoneNumber::parse("  "), Err(PhoneError::Empty));
+    /// assert!(PhoneNumber::parse("+1234").is_err());
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, PhoneError> {
+        let normalized: String = input
+            .trim()
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+            .collect();
+
+        if normalized.is_empty() {
+            return Err(PhoneError::Empty);
+        }
+        if !validate_phone(&normalized) {
+            return Err(PhoneError::Invalid(input.to_owned()));
+        }
+
+        Ok(Self(normalized))
+    }
+
+    /// Returns the number as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Consumes the number and returns the inner string.
+    #[must_use]
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for PhoneNumber {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for PhoneNumber {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::fmt::Display for PhoneNumber {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl core::str::FromStr for PhoneNumber {
+    type Err = PhoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<String> for PhoneNumber {
+    type Error = PhoneError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<PhoneNumber> for String {
+    fn from(phone: PhoneNumber) -> Self {
+        phone.0
+    }
+}
+
+/// Error returned when a phone number is not valid E.164.
+///
+/// # Conversions
+///
+/// - `Into<AppError>` - 422 Unprocessable Entity for HTTP responses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhoneError {
+    /// Input was empty after normalization.
+    Empty,
+
+    /// Input does not match E.164 (original input attached).
+    Invalid(String)
+}
+
+impl core::fmt::Display for PhoneError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => f.write_str("phone number is empty"),
+            Self::Invalid(input) => write!(f, "invalid E.164 phone number: {input}")
+        }
+    }
+}
+
+impl std::error::Error for PhoneError {}
+
+impl From<PhoneError> for AppError {
+    /// Converts into a 422 Unprocessable Entity error.
+    fn from(err: PhoneError) -> Self {
+        AppError::validation(err.to_string())
+    }
+}
+
+impl RUser {
+    /// Get the phone number as a validated [`PhoneNumber`].
+    ///
+    /// Returns `None` if no phone is set or the stored value is not
+    /// valid E.164.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let user = RUser::from_phone("+14155551234");
+    /// assert_eq!(user.phone_parsed().unwrap().as_str(), "+14155551234");
+    ///
+    /// assert!(RUser::empty().phone_parsed().is_none());
+    /// ```
+    #[must_use]
+    pub fn phone_parsed(&self) -> Option<PhoneNumber> {
+        self.phone
+            .as_deref()
+            .and_then(|phone| PhoneNumber::parse(phone).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use masterror::AppErrorKind;
+
+    use super::*;
+
+    #[test]
+    fn parse_accepts_e164() {
+        let phone = PhoneNumber::parse("+14155551234").unwrap();
+
+        assert_eq!(phone.as_str(), "+14155551234");
+        assert_eq!(&*phone, "+14155551234");
+    }
+
+    #[test]
+    fn parse_normalizes_formatting() {
+        let phone = PhoneNumber::parse(" +1 (415) 555-12.34 ").unwrap();
+
+        assert_eq!(phone.as_str(), "+14155551234");
+    }
+
+    #[test]
+    fn parse_rejects_invalid() {
+        assert_eq!(PhoneNumber::parse(""), Err(PhoneError::Empty));
+        assert_eq!(
+            PhoneNumber::parse("14155551234"),
+            Err(PhoneError::Invalid("14155551234".into()))
+        );
+        assert!(PhoneNumber::parse("+1234").is_err());
+        assert!(PhoneNumber::parse("+0123456789").is_err());
+        assert!(PhoneNumber::parse("+1415555abcd").is_err());
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let phone = PhoneNumber::parse("+14155551234").unwrap();
+        let json = serde_json::to_string(&phone).unwrap();
+
+        assert_eq!(json, "\"+14155551234\"");
+        assert_eq!(serde_json::from_str::<PhoneNumber>(&json).unwrap(), phone);
+    }
+
+    #[test]
+    fn deserialize_rejects_bad_number() {
+        assert!(serde_json::from_str::<PhoneNumber>("\"not-a-phone\"").is_err());
+        assert!(serde_json::from_str::<PhoneNumber>("\"+1234\"").is_err());
+    }
+
+    #[test]
+    fn phone_parsed_from_user() {
+        let mut user = RUser::from_phone("+14155551234");
+        assert_eq!(user.phone_parsed().unwrap().as_str(), "+14155551234");
+
+        user.phone = Some("garbage".into());
+        assert!(user.phone_parsed().is_none());
+    }
+
+    #[test]
+    fn converts_into_validation_app_error() {
+        let app: AppError = PhoneNumber::parse("123").unwrap_err().into();
+
+        assert_eq!(app.kind, AppErrorKind::Validation);
+    }
+}
@@ -33,7 +33,9 @@
 //!
 //! | Trait | Purpose |
 //! |-------|---------|
-//! | [`NotificationRepository`] | Load notification recipients |
+//! | [`NotificationRepository`] | Load, count and page notification recipients |
+//! | [`UserLookup`] | Fetch user data missing from JWT claims |
+//! | [`ConfessionStatsRepository`] | Count users per confession |
 //!
 //! # Design Principles
 //!
@@ -92,7 +94,7 @@ use std::future::Future;
 
 use masterror::AppResult;
 
-use crate::TelegramRecipient;
+use crate::{ConfessionId, TelegramRecipient, UserId};
 
 /// Repository trait for notification operations.
 ///
@@ -206,4 +208,259 @@ pub trait NotificationRepository: Send + Sync {
     fn get_telegram_recipients(
         &self
     ) -> impl Future<Output = AppResult<Vec<TelegramRecipient>>> + Send;
+
+    /// Count active Telegram notification recipients.
+    ///
+    /// The default implementation loads all recipients and returns
+    /// the length. Database-backed implementations should override
+    /// it with a `SELECT COUNT(*)` query.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// impl NotificationRepository for PgNotificationRepo {
+    ///     // ...
+    ///
+    ///     async fn count_telegram_recipients(&self) -> AppResult<usize> {
+    ///         let count: i64 = sqlx::query_scalar(
+    ///             "SELECT COUNT(*) FROM telegram_recipients WHERE notifications_enabled = true"
+    ///         )
+    ///         .fetch_one(&self.pool)
+    ///         .await?;
+    ///         Ok(count as usize)
+    ///     }
+    /// }
+    /// ```
+    fn count_telegram_recipients(&self) -> impl Future<Output = AppResult<usize>> + Send {
+        async move { self.get_telegram_recipients().await.map(|r| r.len()) }
+    }
+
+    /// Retrieve one page of active Telegram notification recipients.
+    ///
+    /// Lets broadcasts stream through large recipient lists without
+    /// loading everything into memory.
+    ///
+    /// The default implementation loads all recipients and slices the
+    /// result, which is only suitable for in-memory stores. Database
+    /// implementations should push pagination into SQL (`ORDER BY`
+    /// with `LIMIT`/`OFFSET` or keyset pagination) so the ordering is
+    /// stable between pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Number of recipients to skip
+    /// * `limit` - Maximum number of recipients to return
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// impl NotificationRepository for PgNotificationRepo {
+    ///     // ...
+    ///
+    ///     async fn get_telegram_recipients_page(
+    ///         &self,
+    ///         offset: u64,
+    ///         limit: u32
+    ///     ) -> AppResult<Vec<TelegramRecipient>> {
+    ///         sqlx::query_as!(
+    ///             TelegramRecipient,
+    ///             "SELECT chat_id FROM telegram_recipients
+    ///              WHERE notifications_enabled = true
+    ///              ORDER BY chat_id LIMIT $1 OFFSET $2",
+    ///             i64::from(limit),
+    ///             offset as i64
+    ///         )
+    ///         .fetch_all(&self.pool)
+    ///         .await
+    ///         .map_err(Into::into)
+    ///     }
+    /// }
+    /// ```
+    fn get_telegram_recipients_page(
+        &self,
+        offset: u64,
+        limit: u32
+    ) -> impl Future<Output = AppResult<Vec<TelegramRecipient>>> + Send {
+        async move {
+            let recipients = self.get_telegram_recipients().await?;
+            let offset = usize::try_from(offset).unwrap_or(usize::MAX);
+            let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+
+            Ok(recipients.into_iter().skip(offset).take(limit).collect())
+        }
+    }
+}
+
+/// Lookup port for user data that JWT claims do not carry.
+///
+/// Used by [`RUserAuth::from_claims_with_lookup`] to recover the
+/// Telegram ID without putting it in every token.
+///
+/// # Examples
+///
+/// ```rust
+/// use masterror::AppResult;
+/// use revelation_user::{UserId, ports::UserLookup};
+///
+/// struct NoTelegram;
+///
+/// impl UserLookup for NoTelegram {
+///     async fn telegram_id(&self, _id: UserId) -> AppResult<Option<i64>> {
+///         Ok(None)
+///     }
+/// }
+/// ```
+///
+/// [`RUserAuth::from_claims_with_lookup`]: crate::RUserAuth::from_claims_with_lookup
+pub trait UserLookup: Send + Sync {
+    /// Fetch the Telegram ID of a user.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(id))` - The user has a Telegram ID
+    /// - `Ok(None)` - The user has no Telegram ID bound
+    /// - `Err(AppError)` - User not found or infrastructure error
+    fn telegram_id(&self, id: UserId) -> impl Future<Output = AppResult<Option<i64>>> + Send;
+}
+
+/// Reporting port for per-confession user statistics.
+///
+/// Pairs map directly onto
+/// [`ConfessionCount`](crate::ConfessionCount) via `From`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// impl ConfessionStatsRepository for PgConfessionStats {
+///     async fn counts(&self) -> AppResult<Vec<(ConfessionId, u64)>> {
+///         let rows: Vec<(ConfessionId, i64)> = sqlx::query_as(
+///             "SELECT confession_id, COUNT(*) FROM users
+///              WHERE confession_id IS NOT NULL GROUP BY confession_id"
+///         )
+///         .fetch_all(&self.pool)
+///         .await?;
+///         Ok(rows.into_iter().map(|(id, n)| (id, n as u64)).collect())
+///     }
+/// }
+/// ```
+pub trait ConfessionStatsRepository: Send + Sync {
+    /// Count users grouped by confession.
+    ///
+    /// Users without a confession are not included. Order is up to
+    /// the implementation.
+    fn counts(&self) -> impl Future<Output = AppResult<Vec<(ConfessionId, u64)>>> + Send;
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::ConfessionCount;
+
+    struct InMemoryRepo {
+        recipients: Vec<TelegramRecipient>
+    }
+
+    impl NotificationRepository for InMemoryRepo {
+        async fn get_telegram_recipients(&self) -> AppResult<Vec<TelegramRecipient>> {
+            Ok(self.recipients.clone())
+        }
+    }
+
+    struct CountingRepo;
+
+    impl NotificationRepository for CountingRepo {
+        async fn get_telegram_recipients(&self) -> AppResult<Vec<TelegramRecipient>> {
+            Ok(Vec::new())
+        }
+
+        async fn count_telegram_recipients(&self) -> AppResult<usize> {
+            Ok(1_000_000)
+        }
+    }
+
+    fn repo_with(ids: impl IntoIterator<Item = i64>) -> InMemoryRepo {
+        InMemoryRepo {
+            recipients: ids.into_iter().map(TelegramRecipient::new).collect()
+        }
+    }
+
+    #[tokio::test]
+    async fn count_defaults_to_len() {
+        let repo = repo_with([111, 222, 333]);
+
+        assert_eq!(repo.count_telegram_recipients().await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn page_returns_slice() {
+        let repo = repo_with([111, 222, 333, 444, 555]);
+
+        let page = repo.get_telegram_recipients_page(1, 2).await.unwrap();
+
+        assert_eq!(
+            page,
+            vec![TelegramRecipient::new(222), TelegramRecipient::new(333)]
+        );
+    }
+
+    #[tokio::test]
+    async fn page_truncates_at_end() {
+        let repo = repo_with([111, 222, 333]);
+
+        let page = repo.get_telegram_recipients_page(2, 10).await.unwrap();
+
+        assert_eq!(page, vec![TelegramRecipient::new(333)]);
+    }
+
+    #[tokio::test]
+    async fn page_past_end_is_empty() {
+        let repo = repo_with([111, 222]);
+
+        let page = repo.get_telegram_recipients_page(5, 10).await.unwrap();
+
+        assert!(page.is_empty());
+    }
+
+    struct MockStats {
+        counts: Vec<(ConfessionId, u64)>
+    }
+
+    impl ConfessionStatsRepository for MockStats {
+        async fn counts(&self) -> AppResult<Vec<(ConfessionId, u64)>> {
+            Ok(self.counts.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn confession_stats_return_counts() {
+        let orthodox = ConfessionId(Uuid::now_v7());
+        let catholic = ConfessionId(Uuid::now_v7());
+        let stats = MockStats {
+            counts: vec![(orthodox, 12), (catholic, 5)]
+        };
+
+        let counts: Vec<ConfessionCount> = stats
+            .counts()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(ConfessionCount::from)
+            .collect();
+
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[0].confession_id, orthodox);
+        assert_eq!(counts[0].count, 12);
+        assert_eq!(counts[1].confession_id, catholic);
+        assert_eq!(counts[1].count, 5);
+    }
+
+    #[tokio::test]
+    async fn count_can_be_overridden() {
+        assert_eq!(
+            CountingRepo.count_telegram_recipients().await.unwrap(),
+            1_000_000
+        );
+    }
 }
@@ -14,6 +14,7 @@
 //! |------------|---------|----------|
 //! | [`RUserPublic`] | API responses | email, phone, telegram_id |
 //! | [`RUserAuth`] | JWT/session context | personal data, includes role |
+//! | [`RUserAdmin`] | Admin tools | birth date, confession, settings |
 //!
 //! # Design Philosophy
 //!
@@ -83,8 +84,10 @@
 //!
 //! [`RUser`]: crate::RUser
 
+mod admin;
 mod auth;
 mod public;
 
+pub use admin::*;
 pub use auth::*;
 pub use public::*;
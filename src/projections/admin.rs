@@ -0,0 +1,188 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! Admin user projection and viewer-based redaction.
+//!
+//! This module provides [`RUserAdmin`], the view of a user shown to
+//! administrators, and [`RedactedUser`], returned by
+//! [`RUser::redact_for`] to pick the right view for a viewer.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use revelation_user::{RUser, RUserRole, RedactedUser};
+//!
+//! let user = RUser::from_email("alice@example.com");
+//!
+//! match user.redact_for(RUserRole::Admin, false) {
+//!     RedactedUser::Admin(admin) => {
+//!         assert_eq!(admin.email.as_deref(), Some("alice@example.com"))
+//!     }
+//!     _ => unreachable!()
+//! }
+//! ```
+//!
+//! [`RUser`]: crate::RUser
+//! [`RUser::redact_for`]: crate::RUser::redact_for
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{Gender, RUser, RUserPublic, UserId};
+
+/// User data shown to administrators.
+///
+/// Adds contact details and account state to the public fields, for
+/// support and moderation tools.
+///
+/// # Fields
+///
+/// | Field | Type | Description |
+/// |-------|------|-------------|
+/// | `id` | `UserId` | Unique user identifier |
+/// | `name` | `Option<String>` | Display name |
+/// | `gender` | `Option<Gender>` | User's gender |
+/// | `email` | `Option<String>` | Email address |
+/// | `phone` | `Option<String>` | Phone number |
+/// | `telegram_id` | `Option<i64>` | Telegram user ID |
+/// | `email_verified` | `bool` | Whether the email is verified |
+/// | `phone_verified` | `bool` | Whether the phone is verified |
+/// | `created_at` | `DateTime<Utc>` | Registration time |
+/// | `deleted_at` | `Option<DateTime<Utc>>` | Soft-deletion time |
+///
+/// # Excluded Fields
+///
+/// Personal profile data (`birth_date`, `confession_id`), private
+/// settings and internal metadata stay owner-only.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{RUser, RUserAdmin};
+///
+/// let user = RUser::from_phone("+14155551234");
+/// let admin = RUserAdmin::from(&user);
+///
+/// assert_eq!(admin.phone.as_deref(), Some("+14155551234"));
+/// assert!(admin.phone_verified);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
+pub struct RUserAdmin {
+    /// Unique user identifier.
+    pub id: UserId,
+
+    /// Display name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// User's gender.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gender: Option<Gender>,
+
+    /// Email address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// Phone number in E.164 format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+
+    /// Telegram user ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub telegram_id: Option<i64>,
+
+    /// Whether the user proved control of `email`.
+    #[serde(default)]
+    pub email_verified: bool,
+
+    /// Whether the user proved control of `phone`.
+    #[serde(default)]
+    pub phone_verified: bool,
+
+    /// Registration time.
+    pub created_at: DateTime<Utc>,
+
+    /// Soft-deletion time, `None` for active users.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>
+}
+
+impl From<&RUser> for RUserAdmin {
+    fn from(user: &RUser) -> Self {
+        Self {
+            id:             user.id,
+            name:           user.name.clone(),
+            gender:         user.gender,
+            email:          user.email.clone(),
+            phone:          user.phone.clone(),
+            telegram_id:    user.telegram_id,
+            email_verified: user.email_verified,
+            phone_verified: user.phone_verified,
+            created_at:     user.created_at,
+            deleted_at:     user.deleted_at
+        }
+    }
+}
+
+/// A user redacted for a particular viewer.
+///
+/// Serializes as the wrapped view, without a tag.
+///
+/// | Variant | Viewer |
+/// |---------|--------|
+/// | [`RedactedUser::Full`] | The user themselves |
+/// | [`RedactedUser::Admin`] | Administrators |
+/// | [`RedactedUser::Public`] | Everyone else |
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum RedactedUser {
+    /// Every field; shown to the owner.
+    Full(Box<RUser>),
+
+    /// Contact details and account state; shown to admins.
+    Admin(RUserAdmin),
+
+    /// Public fields only.
+    Public(RUserPublic)
+}
+
+impl RedactedUser {
+    /// Returns the user ID, present in every view.
+    #[must_use]
+    pub fn id(&self) -> UserId {
+        match self {
+            Self::Full(user) => user.id,
+            Self::Admin(admin) => admin.id,
+            Self::Public(public) => public.id
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn admin_projection_copies_contacts() {
+        let mut user = RUser::from_email("alice@example.com");
+        user.telegram_id = Some(123);
+
+        let admin = RUserAdmin::from(&user);
+
+        assert_eq!(admin.id, user.id);
+        assert_eq!(admin.email.as_deref(), Some("alice@example.com"));
+        assert_eq!(admin.telegram_id, Some(123));
+    }
+
+    #[test]
+    fn redacted_serializes_untagged() {
+        let user = RUser::from_email("alice@example.com");
+        let json = serde_json::to_value(RedactedUser::Admin(RUserAdmin::from(&user))).unwrap();
+
+        assert_eq!(json["email"], "alice@example.com");
+        assert!(json.get("Admin").is_none());
+    }
+}
@@ -54,12 +54,42 @@
 //! }
 //! ```
 //!
+//! ## Centralized Role Assignment
+//!
+//! Implement [`RoleResolver`] to keep role-assignment logic in one place:
+//!
+//! ```rust
+//! use revelation_user::{RUser, RUserAuth, RUserRole, RoleResolver};
+//!
+//! struct OwnerResolver {
+//!     owner_telegram_id: i64
+//! }
+//!
+//! impl RoleResolver for OwnerResolver {
+//!     fn resolve(&self, user: &RUser) -> RUserRole {
+//!         if user.telegram_id == Some(self.owner_telegram_id) {
+//!             RUserRole::Admin
+//!         } else {
+//!             RUserRole::User
+//!         }
+//!     }
+//! }
+//!
+//! let resolver = OwnerResolver {
+//!     owner_telegram_id: 42
+//! };
+//! let auth = RUserAuth::from_user_with(&RUser::from_telegram(42), &resolver);
+//! assert!(auth.is_admin());
+//! ```
+//!
 //! [`RUserPublic`]: crate::RUserPublic
 
+use masterror::AppResult;
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
-use crate::{RUser, RUserRole};
+use crate::{
+    Claims, Permissions, RUser, RUserRole, Role, UserId, missing_permission, ports::UserLookup
+};
 
 /// User data for authentication and authorization context.
 ///
@@ -70,22 +100,23 @@ use crate::{RUser, RUserRole};
 ///
 /// | Field | Type | Description |
 /// |-------|------|-------------|
-/// | `id` | `Uuid` | Unique user identifier |
+/// | `id` | `UserId` | Unique user identifier |
 /// | `telegram_id` | `Option<i64>` | Telegram ID (if authenticated via Telegram) |
 /// | `role` | `RUserRole` | User's authorization role |
+/// | `permissions` | `Option<Permissions>` | Override of role permissions |
 ///
 /// # Role-Based Access Control
 ///
 /// The `role` field supports hierarchical permissions:
 ///
 /// ```rust
-/// use revelation_user::{RUserAuth, RUserRole};
-/// use uuid::Uuid;
+/// use revelation_user::{RUserAuth, RUserRole, UserId};
 ///
 /// let admin_auth = RUserAuth {
-///     id:          Uuid::now_v7(),
+///     id:          UserId::new(),
 ///     telegram_id: Some(123),
-///     role:        RUserRole::Admin
+///     role:        RUserRole::Admin,
+///     permissions: None
 /// };
 ///
 /// // Admins have all permissions
@@ -93,9 +124,10 @@ use crate::{RUser, RUserRole};
 /// assert!(admin_auth.role.is_premium());
 ///
 /// let user_auth = RUserAuth {
-///     id:          Uuid::now_v7(),
+///     id:          UserId::new(),
 ///     telegram_id: None,
-///     role:        RUserRole::User
+///     role:        RUserRole::User,
+///     permissions: None
 /// };
 ///
 /// // Regular users have basic permissions only
@@ -137,39 +169,44 @@ use crate::{RUser, RUserRole};
 /// ## Equality Comparison
 ///
 /// ```rust
-/// use revelation_user::{RUserAuth, RUserRole};
-/// use uuid::Uuid;
+/// use revelation_user::{RUserAuth, RUserRole, UserId};
 ///
-/// let id = Uuid::now_v7();
+/// let id = UserId::new();
 ///
 /// let auth1 = RUserAuth {
 ///     id,
 ///     telegram_id: Some(123),
-///     role: RUserRole::User
+///     role: RUserRole::User,
+///     permissions: None
 /// };
 ///
 /// let auth2 = RUserAuth {
 ///     id,
 ///     telegram_id: Some(123),
-///     role: RUserRole::User
+///     role: RUserRole::User,
+///     permissions: None
 /// };
 ///
 /// assert_eq!(auth1, auth2);
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
 pub struct RUserAuth {
     /// Unique user identifier.
     ///
     /// This is the same UUID from the source [`RUser`].
     ///
     /// [`RUser`]: crate::RUser
-    pub id: Uuid,
+    pub id: UserId,
 
     /// Telegram user ID if authenticated via Telegram.
     ///
     /// Useful for verifying Telegram-based authentication
-    /// or linking with Telegram bot interactions.
+    /// or linking with Telegram bot interactions. Omitted from JSON
+    /// when `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub telegram_id: Option<i64>,
 
     /// User's authorization role.
@@ -179,7 +216,73 @@ pub struct RUserAuth {
     /// - [`is_admin()`](RUserRole::is_admin) - Admin only
     /// - [`is_premium()`](RUserRole::is_premium) - Premium or Admin
     /// - [`is_user()`](RUserRole::is_user) - Regular user only
-    pub role: RUserRole
+    pub role: RUserRole,
+
+    /// Per-user permission override (optional).
+    ///
+    /// When set, these permissions replace the role's defaults, mirroring
+    /// [`Claims::permissions`]. Omitted from JSON when `None`.
+    ///
+    /// [`Claims::permissions`]: crate::Claims::permissions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "api", schema(value_type = Option<u32>))]
+    pub permissions: Option<Permissions>
+}
+
+/// Strategy for deciding which role a user receives.
+///
+/// Used by [`RUserAuth::from_user_with`] so applications can centralize
+/// role-assignment logic (owner lists, database lookups cached in memory,
+/// feature flags) instead of passing roles around by hand.
+///
+/// # Thread Safety
+///
+/// Implementations must be `Send + Sync` so a single resolver can be
+/// shared across async handlers.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{RUser, RUserRole, RoleResolver};
+///
+/// struct EmailDomainResolver;
+///
+/// impl RoleResolver for EmailDomainResolver {
+///     fn resolve(&self, user: &RUser) -> RUserRole {
+///         match user.email.as_deref() {
+///             Some(email) if email.ends_with("@revelation.dev") => RUserRole::Admin,
+///             _ => RUserRole::User
+///         }
+///     }
+/// }
+///
+/// let user = RUser::from_email("staff@revelation.dev");
+/// assert_eq!(EmailDomainResolver.resolve(&user), RUserRole::Admin);
+/// ```
+pub trait RoleResolver: Send + Sync {
+    /// Determine the role for the given user.
+    fn resolve(&self, user: &RUser) -> RUserRole;
+}
+
+/// [`RoleResolver`] that assigns the same role to every user.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{ConstRoleResolver, RUser, RUserAuth, RUserRole};
+///
+/// let resolver = ConstRoleResolver(RUserRole::Premium);
+/// let auth = RUserAuth::from_user_with(&RUser::empty(), &resolver);
+///
+/// assert_eq!(auth.role, RUserRole::Premium);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConstRoleResolver(pub RUserRole);
+
+impl RoleResolver for ConstRoleResolver {
+    fn resolve(&self, _user: &RUser) -> RUserRole {
+        self.0
+    }
 }
 
 impl RUserAuth {
@@ -208,7 +311,8 @@ impl RUserAuth {
         Self {
             id: user.id,
             telegram_id: user.telegram_id,
-            role
+            role,
+            permissions: None
         }
     }
 
@@ -239,6 +343,185 @@ impl RUserAuth {
         Self::from_user(user, RUserRole::User)
     }
 
+    /// Create authentication projection with a role chosen by a resolver.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - Reference to the source [`RUser`]
+    /// * `resolver` - Strategy that decides the user's role
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{ConstRoleResolver, RUser, RUserAuth, RUserRole};
+    ///
+    /// let user = RUser::from_telegram(123);
+    /// let auth = RUserAuth::from_user_with(&user, &ConstRoleResolver(RUserRole::Admin));
+    ///
+    /// assert!(auth.is_admin());
+    /// ```
+    ///
+    /// [`RUser`]: crate::RUser
+    #[must_use]
+    pub fn from_user_with(user: &RUser, resolver: &impl RoleResolver) -> Self {
+        Self::from_user(user, resolver.resolve(user))
+    }
+
+    /// Create authentication projection with custom permissions.
+    ///
+    /// The permissions override the role's defaults, e.g. for per-user
+    /// grants stored alongside the session.
+    ///
+    /// # Arguments
+    ///
+    /// * `user` - Reference to the source [`RUser`]
+    /// * `role` - Authorization role to assign
+    /// * `permissions` - Permissions to use instead of the role defaults
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Permissions, RUser, RUserAuth, RUserRole};
+    ///
+    /// let user = RUser::from_telegram(123);
+    /// let auth = RUserAuth::from_user_with_permissions(
+    ///     &user,
+    ///     RUserRole::User,
+    ///     Permissions::READ | Permissions::EXPORT
+    /// );
+    ///
+    /// assert!(auth.effective_permissions().contains(Permissions::EXPORT));
+    /// ```
+    ///
+    /// [`RUser`]: crate::RUser
+    #[must_use]
+    pub fn from_user_with_permissions(
+        user: &RUser,
+        role: RUserRole,
+        permissions: Permissions
+    ) -> Self {
+        Self {
+            permissions: Some(permissions),
+            ..Self::from_user(user, role)
+        }
+    }
+
+    /// Create authentication projection from JWT claims.
+    ///
+    /// Copies `sub`, role and custom permissions. Claims carry no
+    /// Telegram ID, so `telegram_id` is `None`; use
+    /// [`RUserAuth::from_claims_with_lookup`] to recover it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Claims, RUserAuth, RUserRole};
+    /// use uuid::Uuid;
+    ///
+    /// let claims = Claims::new(Uuid::nil(), RUserRole::Premium, 0);
+    /// let auth = RUserAuth::from_claims(&claims);
+    ///
+    /// assert!(auth.id.is_nil());
+    /// assert!(auth.telegram_id.is_none());
+    /// ```
+    #[must_use]
+    pub const fn from_claims(claims: &Claims) -> Self {
+        Self {
+            id:          claims.sub,
+            telegram_id: None,
+            role:        claims.role,
+            permissions: claims.permissions
+        }
+    }
+
+    /// Create authentication projection from JWT claims, fetching the
+    /// Telegram ID through a [`UserLookup`].
+    ///
+    /// Keeps tokens small while still giving handlers the Telegram ID
+    /// when they need it. A user without a Telegram ID yields `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `claims` - Validated JWT claims
+    /// * `lookup` - Port used to load the Telegram ID by user id
+    ///
+    /// # Errors
+    ///
+    /// Returns whatever error the lookup fails with.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// use revelation_user::{Claims, RUserAuth};
+    ///
+    /// async fn handler(claims: Claims, repo: PgUserRepo) -> AppResult<()> {
+    ///     let auth = RUserAuth::from_claims_with_lookup(&claims, &repo).await?;
+    ///     notify(auth.telegram_id).await
+    /// }
+    /// ```
+    pub async fn from_claims_with_lookup(
+        claims: &Claims,
+        lookup: &impl UserLookup
+    ) -> AppResult<Self> {
+        let telegram_id = lookup.telegram_id(claims.sub).await?;
+
+        Ok(Self {
+            telegram_id,
+            ..Self::from_claims(claims)
+        })
+    }
+
+    /// Get the effective permissions for this session.
+    ///
+    /// Returns custom permissions if set, otherwise derives
+    /// permissions from the role.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Permissions, RUser, RUserAuth, RUserRole, Role};
+    ///
+    /// let user = RUser::from_telegram(123);
+    ///
+    /// let auth = RUserAuth::from_user(&user, RUserRole::Admin);
+    /// assert_eq!(auth.effective_permissions(), RUserRole::Admin.permissions());
+    ///
+    /// let auth = RUserAuth::from_user_with_permissions(&user, RUserRole::Admin, Permissions::READ);
+    /// assert_eq!(auth.effective_permissions(), Permissions::READ);
+    /// ```
+    #[must_use]
+    pub fn effective_permissions(&self) -> Permissions {
+        self.permissions.unwrap_or_else(|| self.role.permissions())
+    }
+
+    /// Require a permission, failing with 403 if it is missing.
+    ///
+    /// Function form of [`require_permission!`](crate::require_permission).
+    ///
+    /// # Errors
+    ///
+    /// Returns a forbidden [`AppError`](masterror::AppError) naming the
+    /// missing permissions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Permissions, RUser, RUserAuth, RUserRole};
+    ///
+    /// let auth = RUserAuth::from_user(&RUser::from_telegram(123), RUserRole::User);
+    ///
+    /// assert!(auth.require(Permissions::READ).is_ok());
+    /// assert!(auth.require(Permissions::MANAGE_USERS).is_err());
+    /// ```
+    pub fn require(&self, permission: Permissions) -> AppResult<()> {
+        let granted = self.effective_permissions();
+        if granted.contains(permission) {
+            Ok(())
+        } else {
+            Err(missing_permission(permission, granted))
+        }
+    }
+
     /// Check if this user has admin privileges.
     ///
     /// Convenience method that delegates to [`RUserRole::is_admin`].
@@ -285,12 +568,166 @@ impl RUserAuth {
     pub const fn is_premium(&self) -> bool {
         self.role.is_premium()
     }
+
+    /// Build JWT claims for this session.
+    ///
+    /// Copies `id` into `sub`, the role and any custom permissions.
+    /// `iat` and `aud` are left unset.
+    ///
+    /// # Arguments
+    ///
+    /// * `exp` - Expiration time as a Unix timestamp
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{RUser, RUserAuth, RUserRole};
+    ///
+    /// let user = RUser::from_telegram(123);
+    /// let auth = RUserAuth::from_user(&user, RUserRole::Premium);
+    ///
+    /// let claims = auth.to_claims(1_700_000_000);
+    /// assert_eq!(claims.user_id(), user.id);
+    /// assert_eq!(claims.role, RUserRole::Premium);
+    /// ```
+    #[must_use]
+    pub fn to_claims(&self, exp: usize) -> Claims {
+        Claims {
+            permissions: self.permissions,
+            ..Claims::new(self.id, self.role, exp)
+        }
+    }
+
+    /// Convert this session into JWT claims, consuming it.
+    ///
+    /// Owned counterpart of [`RUserAuth::to_claims`].
+    ///
+    /// # Arguments
+    ///
+    /// * `exp` - Expiration time as a Unix timestamp
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{RUser, RUserAuth, RUserRole};
+    ///
+    /// let user = RUser::from_telegram(123);
+    /// let claims = RUserAuth::from_user(&user, RUserRole::Admin).into_claims(0);
+    ///
+    /// assert_eq!(claims.user_id(), user.id);
+    /// assert!(claims.is_admin());
+    /// ```
+    #[must_use]
+    pub fn into_claims(self, exp: usize) -> Claims {
+        self.to_claims(exp)
+    }
+}
+
+/// Anonymous identity for guest sessions.
+///
+/// Produces a nil id, no Telegram ID and [`RUserRole::User`]. Use it for
+/// unauthenticated requests that still need an [`RUserAuth`] value; the
+/// nil id never matches a real user.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{RUserAuth, RUserRole};
+///
+/// let guest = RUserAuth::default();
+///
+/// assert!(guest.id.is_nil());
+/// assert!(guest.telegram_id.is_none());
+/// assert_eq!(guest.role, RUserRole::User);
+/// ```
+impl Default for RUserAuth {
+    fn default() -> Self {
+        Self {
+            id:          UserId::nil(),
+            telegram_id: None,
+            role:        RUserRole::User,
+            permissions: None
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use masterror::{AppError, AppErrorKind};
+
     use super::*;
 
+    struct MockLookup {
+        known: UserId
+    }
+
+    impl UserLookup for MockLookup {
+        async fn telegram_id(&self, id: UserId) -> AppResult<Option<i64>> {
+            if id == self.known {
+                Ok(Some(123456789))
+            } else {
+                Err(AppError::not_found("User not found"))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn from_claims_with_lookup_populates_telegram_id() {
+        let user = RUser::from_email("user@example.com");
+        let claims = Claims::new(user.id, RUserRole::Premium, 0);
+        let lookup = MockLookup {
+            known: user.id
+        };
+
+        let auth = RUserAuth::from_claims_with_lookup(&claims, &lookup)
+            .await
+            .unwrap();
+
+        assert_eq!(auth.id, user.id);
+        assert_eq!(auth.telegram_id, Some(123456789));
+        assert_eq!(auth.role, RUserRole::Premium);
+        assert!(RUserAuth::from_claims(&claims).telegram_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn from_claims_with_lookup_propagates_errors() {
+        let claims = Claims::new(UserId::new(), RUserRole::User, 0);
+        let lookup = MockLookup {
+            known: UserId::nil()
+        };
+
+        let result = RUserAuth::from_claims_with_lookup(&claims, &lookup).await;
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn into_claims_carries_id_and_role() {
+        let user = RUser::from_telegram(123456789);
+        let auth = RUserAuth::from_user(&user, RUserRole::Premium);
+
+        let claims = auth.into_claims(1_700_000_000);
+
+        assert_eq!(claims.sub, user.id);
+        assert_eq!(claims.role, RUserRole::Premium);
+        assert_eq!(claims.exp, 1_700_000_000);
+        assert!(claims.permissions.is_none());
+        assert!(claims.iat.is_none());
+    }
+
+    #[test]
+    fn to_claims_carries_custom_permissions() {
+        let user = RUser::from_telegram(123);
+        let auth =
+            RUserAuth::from_user_with_permissions(&user, RUserRole::User, Permissions::EXPORT);
+
+        let claims = auth.to_claims(0);
+
+        assert_eq!(claims.permissions, Some(Permissions::EXPORT));
+        assert_eq!(claims.effective_permissions(), auth.effective_permissions());
+        assert_eq!(auth.into_claims(0).sub, claims.sub);
+    }
+
     #[test]
     fn from_user_copies_fields() {
         let user = RUser::from_telegram(123456789);
@@ -309,6 +746,100 @@ mod tests {
         assert_eq!(auth.role, RUserRole::User);
     }
 
+    struct OwnerResolver {
+        owner_telegram_id: i64
+    }
+
+    impl RoleResolver for OwnerResolver {
+        fn resolve(&self, user: &RUser) -> RUserRole {
+            if user.telegram_id == Some(self.owner_telegram_id) {
+                RUserRole::Admin
+            } else {
+                RUserRole::User
+            }
+        }
+    }
+
+    #[test]
+    fn from_user_with_uses_resolver() {
+        let resolver = OwnerResolver {
+            owner_telegram_id: 42
+        };
+
+        let owner = RUserAuth::from_user_with(&RUser::from_telegram(42), &resolver);
+        assert_eq!(owner.role, RUserRole::Admin);
+
+        let other = RUserAuth::from_user_with(&RUser::from_telegram(7), &resolver);
+        assert_eq!(other.role, RUserRole::User);
+    }
+
+    #[test]
+    fn const_resolver_returns_fixed_role() {
+        let user = RUser::from_email("test@example.com");
+        let auth = RUserAuth::from_user_with(&user, &ConstRoleResolver(RUserRole::Premium));
+
+        assert_eq!(auth.id, user.id);
+        assert_eq!(auth.role, RUserRole::Premium);
+        assert_eq!(ConstRoleResolver::default().0, RUserRole::User);
+    }
+
+    #[test]
+    fn default_is_anonymous_user() {
+        let guest = RUserAuth::default();
+
+        assert!(guest.id.is_nil());
+        assert!(guest.telegram_id.is_none());
+        assert_eq!(guest.role, RUserRole::User);
+    }
+
+    #[test]
+    fn effective_permissions_derive_from_role() {
+        let auth = RUserAuth::from_user(&RUser::from_telegram(123), RUserRole::Premium);
+
+        assert!(auth.permissions.is_none());
+        assert_eq!(
+            auth.effective_permissions(),
+            RUserRole::Premium.permissions()
+        );
+    }
+
+    #[test]
+    fn effective_permissions_use_override() {
+        let user = RUser::from_telegram(123);
+        let custom = Permissions::READ | Permissions::EXPORT;
+        let auth = RUserAuth::from_user_with_permissions(&user, RUserRole::User, custom);
+
+        assert_eq!(auth.id, user.id);
+        assert_eq!(auth.role, RUserRole::User);
+        assert_eq!(auth.effective_permissions(), custom);
+    }
+
+    #[test]
+    fn permissions_skipped_when_none() {
+        let auth = RUserAuth::default();
+        let json = serde_json::to_string(&auth).unwrap();
+        assert!(!json.contains("permissions"));
+
+        let with = RUserAuth {
+            permissions: Some(Permissions::READ),
+            ..RUserAuth::default()
+        };
+        let decoded: RUserAuth =
+            serde_json::from_str(&serde_json::to_string(&with).unwrap()).unwrap();
+        assert_eq!(decoded, with);
+    }
+
+    #[test]
+    #[cfg(feature = "schemars")]
+    fn json_schema_lists_auth_properties() {
+        let schema = serde_json::to_value(schemars::schema_for!(RUserAuth)).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+
+        assert!(properties.contains_key("id"));
+        assert!(properties.contains_key("role"));
+        assert!(properties.contains_key("permissions"));
+    }
+
     #[test]
     fn is_admin_works() {
         let user = RUser::from_telegram(123);
@@ -336,18 +867,20 @@ mod tests {
 
     #[test]
     fn equality_works() {
-        let id = Uuid::now_v7();
+        let id = UserId::new();
 
         let auth1 = RUserAuth {
             id,
             telegram_id: Some(123),
-            role: RUserRole::User
+            role: RUserRole::User,
+            permissions: None
         };
 
         let auth2 = RUserAuth {
             id,
             telegram_id: Some(123),
-            role: RUserRole::User
+            role: RUserRole::User,
+            permissions: None
         };
 
         assert_eq!(auth1, auth2);
@@ -356,9 +889,10 @@ mod tests {
     #[test]
     fn serialization_roundtrip() {
         let auth = RUserAuth {
-            id:          Uuid::nil(),
+            id:          UserId::nil(),
             telegram_id: Some(123),
-            role:        RUserRole::Admin
+            role:        RUserRole::Admin,
+            permissions: None
         };
 
         let json = serde_json::to_string(&auth).unwrap();
@@ -366,4 +900,46 @@ mod tests {
 
         assert_eq!(auth, decoded);
     }
+
+    #[test]
+    #[cfg(feature = "camel")]
+    fn serializes_camel_case_keys() {
+        let auth = RUserAuth {
+            id:          UserId::nil(),
+            telegram_id: Some(123),
+            role:        RUserRole::User,
+            permissions: None
+        };
+
+        let json = serde_json::to_string(&auth).unwrap();
+        assert!(json.contains("\"telegramId\":123"));
+
+        let decoded: RUserAuth = serde_json::from_str(&json).unwrap();
+        assert_eq!(auth, decoded);
+    }
+
+    #[test]
+    fn require_allows_granted_permission() {
+        let auth = RUserAuth::from_user(&RUser::from_telegram(123), RUserRole::Admin);
+
+        assert!(auth.require(Permissions::MANAGE_USERS).is_ok());
+    }
+
+    #[test]
+    fn require_denies_with_permission_name() {
+        let auth = RUserAuth::from_user_with_permissions(
+            &RUser::from_telegram(123),
+            RUserRole::User,
+            Permissions::READ
+        );
+
+        let err = auth.require(Permissions::EXPORT).unwrap_err();
+        assert_eq!(err.kind, AppErrorKind::Forbidden);
+        assert!(
+            err.message
+                .as_deref()
+                .unwrap_or_default()
+                .contains("export")
+        );
+    }
 }
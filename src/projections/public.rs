@@ -21,7 +21,7 @@
 //!
 //! let public: RUserPublic = user.into();
 //!
-//! // JSON output: {"id":"...","name":null,"gender":null}
+//! // JSON output: {"id":"...","has_confession":false}
 //! // Note: email and telegram_id are NOT included
 //! ```
 //!
@@ -58,9 +58,8 @@
 //! [`RUser`]: crate::RUser
 
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
 
-use crate::{Gender, RUser};
+use crate::{Gender, RUser, RUserRole, UserId};
 
 /// Public user data safe for API responses.
 ///
@@ -71,9 +70,11 @@ use crate::{Gender, RUser};
 ///
 /// | Field | Type | Description |
 /// |-------|------|-------------|
-/// | `id` | `Uuid` | Unique user identifier |
+/// | `id` | `UserId` | Unique user identifier |
 /// | `name` | `Option<String>` | Display name |
 /// | `gender` | `Option<Gender>` | User's gender |
+/// | `has_confession` | `bool` | Whether a confession is set (id not exposed) |
+/// | `role` | `Option<RUserRole>` | Role for badges, only when provided |
 ///
 /// # Excluded Fields
 ///
@@ -81,9 +82,16 @@ use crate::{Gender, RUser};
 /// - `telegram_id` - Authentication identifier
 /// - `email` - Personal contact information
 /// - `phone` - Personal contact information
+/// - `email_verified`, `phone_verified` - Contact verification state
 /// - `birth_date` - Sensitive personal data
-/// - `confession_id` - Religious information
+/// - `confession_id` - Religious information (only `has_confession` is exposed)
 /// - `created_at` - Internal metadata
+/// - `deleted_at` - Internal metadata
+/// - `external_ids` - Identity provider subjects
+/// - `signup_source` - Analytics metadata
+/// - `metadata` - Application-defined data
+/// - `version` - Concurrency control metadata
+/// - `notification_prefs` - Private user settings
 ///
 /// # Examples
 ///
@@ -129,24 +137,43 @@ use crate::{Gender, RUser};
 /// [`RUser`]: crate::RUser
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "camel", serde(rename_all = "camelCase"))]
 pub struct RUserPublic {
     /// Unique user identifier.
     ///
     /// This is the same UUID from the source [`RUser`].
     ///
     /// [`RUser`]: crate::RUser
-    pub id: Uuid,
+    pub id: UserId,
 
     /// Display name.
     ///
     /// User's chosen display name, if set. May be `None` for
-    /// users who haven't completed their profile.
+    /// users who haven't completed their profile. Omitted from JSON
+    /// when `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 
     /// User's gender.
     ///
-    /// Optional gender information, if provided by the user.
-    pub gender: Option<Gender>
+    /// Optional gender information, if provided by the user. Omitted
+    /// from JSON when `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gender: Option<Gender>,
+
+    /// Whether the user has set a confession.
+    ///
+    /// Derived from `confession_id`, which itself stays private.
+    #[serde(default)]
+    pub has_confession: bool,
+
+    /// User's role, for rendering Premium/Admin badges.
+    ///
+    /// `None` unless built via `From<(&RUser, RUserRole)>`; omitted from
+    /// JSON when `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<RUserRole>
 }
 
 impl From<RUser> for RUserPublic {
@@ -164,9 +191,11 @@ impl From<RUser> for RUserPublic {
     /// [`RUser`]: crate::RUser
     fn from(user: RUser) -> Self {
         Self {
-            id:     user.id,
-            name:   user.name,
-            gender: user.gender
+            id:             user.id,
+            name:           user.name,
+            gender:         user.gender,
+            has_confession: user.confession_id.is_some(),
+            role:           None
         }
     }
 }
@@ -192,15 +221,99 @@ impl From<&RUser> for RUserPublic {
     /// [`RUser`]: crate::RUser
     fn from(user: &RUser) -> Self {
         Self {
-            id:     user.id,
-            name:   user.name.clone(),
-            gender: user.gender
+            id:             user.id,
+            name:           user.name.clone(),
+            gender:         user.gender,
+            has_confession: user.confession_id.is_some(),
+            role:           None
         }
     }
 }
 
+impl From<(&RUser, RUserRole)> for RUserPublic {
+    /// Converts a user and its role into [`RUserPublic`] with `role` set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{RUser, RUserPublic, RUserRole};
+    ///
+    /// let user = RUser::from_telegram(123456);
+    /// let public = RUserPublic::from((&user, RUserRole::Premium));
+    ///
+    /// assert_eq!(public.role, Some(RUserRole::Premium));
+    /// ```
+    fn from((user, role): (&RUser, RUserRole)) -> Self {
+        Self {
+            role: Some(role),
+            ..Self::from(user)
+        }
+    }
+}
+
+impl RUserPublic {
+    /// Compute a strong ETag over the public fields.
+    ///
+    /// Hashes the JSON serialization with 64-bit FNV-1a, so the value is
+    /// stable across processes, platforms and compiler versions. Returns
+    /// the quoted form ready for an `ETag` header.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{RUser, RUserPublic};
+    /// use uuid::Uuid;
+    ///
+    /// let user = RUser::with_id(Uuid::nil());
+    /// let etag = RUserPublic::from(&user).etag();
+    ///
+    /// assert_eq!(etag, RUserPublic::from(&user).etag());
+    /// assert!(etag.starts_with('"') && etag.ends_with('"'));
+    /// ```
+    #[must_use]
+    pub fn etag(&self) -> String {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let json = serde_json::to_vec(self).expect("public projection serializes to JSON");
+        let hash = json.iter().fold(OFFSET_BASIS, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+        });
+
+        format!("\"{hash:016x}\"")
+    }
+}
+
+/// Responds with the projection as JSON and a short private cache.
+///
+/// Sets `Cache-Control: private, max-age=60` so browsers may briefly
+/// reuse public profile data while shared caches do not store it.
+/// Wrapping in `axum::Json` still works and omits the header.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use revelation_user::{RUser, RUserPublic};
+///
+/// async fn profile() -> RUserPublic {
+///     RUser::from_telegram(123456789).into()
+/// }
+/// ```
+#[cfg(feature = "axum")]
+impl axum::response::IntoResponse for RUserPublic {
+    fn into_response(self) -> axum::response::Response {
+        (
+            [(axum::http::header::CACHE_CONTROL, "private, max-age=60")],
+            axum::Json(self)
+        )
+            .into_response()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use uuid::Uuid;
+
     use super::*;
 
     #[test]
@@ -238,4 +351,161 @@ mod tests {
         assert!(!json.contains("secret@test.com"));
         assert!(!json.contains("telegram_id"));
     }
+
+    #[test]
+    fn serialization_excludes_external_ids() {
+        let mut user = RUser::with_id(Uuid::nil());
+        user.link_external("google", "1098765");
+
+        let public: RUserPublic = user.into();
+        let json = serde_json::to_string(&public).unwrap();
+
+        assert!(!json.contains("1098765"));
+    }
+
+    #[test]
+    fn serialization_excludes_deleted_at() {
+        let mut user = RUser::with_id(Uuid::nil());
+        user.soft_delete();
+
+        let public: RUserPublic = user.into();
+        let json = serde_json::to_string(&public).unwrap();
+
+        assert!(!json.contains("deleted_at"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "camel"))]
+    fn has_confession_without_exposing_id() {
+        let confession_id = crate::ConfessionId(Uuid::now_v7());
+        let mut user = RUser::with_id(Uuid::nil());
+        user.confession_id = Some(confession_id);
+
+        let public = RUserPublic::from(&user);
+        assert!(public.has_confession);
+
+        let json = serde_json::to_value(&public).unwrap();
+        assert_eq!(json["has_confession"], true);
+        assert!(json.get("confession_id").is_none());
+        assert!(!json.to_string().contains(&confession_id.to_string()));
+
+        let without: RUserPublic = RUser::empty().into();
+        assert!(!without.has_confession);
+    }
+
+    #[test]
+    #[cfg(feature = "camel")]
+    fn has_confession_camel_key() {
+        let mut user = RUser::with_id(Uuid::nil());
+        user.confession_id = Some(crate::ConfessionId(Uuid::now_v7()));
+
+        let json = serde_json::to_value(RUserPublic::from(&user)).unwrap();
+        assert_eq!(json["hasConfession"], true);
+        assert!(json.get("confessionId").is_none());
+    }
+
+    #[test]
+    fn from_user_leaves_role_unset() {
+        let public: RUserPublic = RUser::with_id(Uuid::nil()).into();
+        assert!(public.role.is_none());
+
+        let json = serde_json::to_value(&public).unwrap();
+        assert!(json.get("role").is_none());
+    }
+
+    #[test]
+    fn from_user_and_role_sets_role() {
+        let user = RUser::from_telegram(123);
+        let public = RUserPublic::from((&user, RUserRole::Admin));
+
+        assert_eq!(public.id, user.id);
+        assert_eq!(public.role, Some(RUserRole::Admin));
+
+        let json = serde_json::to_value(&public).unwrap();
+        assert_eq!(json["role"], "admin");
+        assert!(json.get("telegram_id").is_none());
+    }
+
+    #[test]
+    fn serialization_omits_unset_optionals() {
+        let public: RUserPublic = RUser::with_id(Uuid::nil()).into();
+        let json = serde_json::to_value(&public).unwrap();
+
+        assert!(json.get("name").is_none());
+        assert!(json.get("gender").is_none());
+
+        let decoded: RUserPublic = serde_json::from_value(json).unwrap();
+        assert!(decoded.name.is_none());
+        assert!(decoded.gender.is_none());
+    }
+
+    #[test]
+    fn serialization_keeps_set_optionals() {
+        let mut user = RUser::with_id(Uuid::nil());
+        user.name = Some("Alice".into());
+        user.gender = Some(Gender::Female);
+
+        let json = serde_json::to_value(RUserPublic::from(&user)).unwrap();
+
+        assert_eq!(json["name"], "Alice");
+        assert_eq!(json["gender"], "female");
+    }
+
+    #[test]
+    fn etag_is_deterministic() {
+        let mut user = RUser::with_id(Uuid::nil());
+        user.name = Some("Alice".into());
+
+        let etag = RUserPublic::from(&user).etag();
+
+        assert_eq!(etag, RUserPublic::from(user.clone()).etag());
+        assert_eq!(etag.len(), 18);
+
+        user.email = Some("changed@example.com".into());
+        user.touch();
+        assert_eq!(etag, RUserPublic::from(&user).etag());
+    }
+
+    #[test]
+    fn etag_changes_with_name() {
+        let mut user = RUser::with_id(Uuid::nil());
+        user.name = Some("Alice".into());
+        let before = RUserPublic::from(&user).etag();
+
+        user.name = Some("Alicia".into());
+        let after = RUserPublic::from(&user).etag();
+
+        assert_ne!(before, after);
+        assert_ne!(after, RUserPublic::from((&user, RUserRole::Admin)).etag());
+    }
+
+    #[test]
+    fn serialization_excludes_version() {
+        let public: RUserPublic = RUser::with_id(Uuid::nil()).into();
+        let json = serde_json::to_string(&public).unwrap();
+
+        assert!(!json.contains("version"));
+    }
+
+    #[cfg(feature = "axum")]
+    #[tokio::test]
+    async fn into_response_sets_cache_control_and_json_body() {
+        use axum::{http::header, response::IntoResponse};
+
+        let mut user = RUser::with_id(Uuid::nil());
+        user.name = Some("Alice".into());
+        let public = RUserPublic::from(&user);
+        let expected = serde_json::to_vec(&public).unwrap();
+
+        let res = public.into_response();
+
+        assert_eq!(res.status(), 200);
+        assert_eq!(res.headers()[header::CACHE_CONTROL], "private, max-age=60");
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "application/json");
+
+        let body = axum::body::to_bytes(res.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body.as_ref(), expected.as_slice());
+    }
 }
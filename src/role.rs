@@ -35,6 +35,12 @@
 //! assert!(!user.can(Permissions::ADMIN));
 //! ```
 //!
+//! # Configurable Policy
+//!
+//! The role → permission mapping can be loaded from configuration with
+//! [`RolePolicy`] and installed once at startup, e.g. to grant `IMPORT`
+//! to Premium without recompiling.
+//!
 //! # Database Integration
 //!
 //! With the `db` feature, [`RUserRole`] maps to PostgreSQL enum:
@@ -60,9 +66,11 @@
 //!
 //! [`Role`]: crate::Role
 
-use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Deserializer, Serialize, de::Error};
 
-use crate::{Permissions, Role};
+use crate::{PermissionError, Permissions, Role, permissions::parse_permissions};
 
 /// User role for authorization decisions.
 ///
@@ -113,7 +121,29 @@ use crate::{Permissions, Role};
 ///     "\"admin\""
 /// );
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+///
+/// Legacy values `"basic"` and `"administrator"` are still accepted
+/// when deserializing:
+///
+/// ```rust
+/// use revelation_user::RUserRole;
+///
+/// let role: RUserRole = serde_json::from_str("\"administrator\"").unwrap();
+/// assert_eq!(role, RUserRole::Admin);
+/// ```
+///
+/// Roles order by privilege, so `role >= RUserRole::Premium` reads as
+/// "premium or above":
+///
+/// ```rust
+/// use revelation_user::RUserRole;
+///
+/// assert!(RUserRole::User < RUserRole::Premium);
+/// assert!(RUserRole::Admin >= RUserRole::Premium);
+/// ```
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default,
+)]
 #[serde(rename_all = "snake_case")]
 #[cfg_attr(feature = "db", derive(sqlx::Type))]
 #[cfg_attr(
@@ -121,11 +151,14 @@ use crate::{Permissions, Role};
     sqlx(type_name = "user_role", rename_all = "snake_case")
 )]
 #[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum RUserRole {
     /// Regular user with basic access.
     ///
     /// This is the default role for new users.
+    /// Also accepts the legacy `"basic"` value when deserializing.
     #[default]
+    #[serde(alias = "basic")]
     User,
 
     /// Premium user with access to premium features.
@@ -136,6 +169,8 @@ pub enum RUserRole {
     /// Administrator with full access.
     ///
     /// Has all Premium capabilities plus admin functions.
+    /// Also accepts the legacy `"administrator"` value when deserializing.
+    #[serde(alias = "administrator")]
     Admin
 }
 
@@ -213,6 +248,186 @@ impl RUserRole {
             Self::Admin => "admin"
         }
     }
+
+    /// Returns the compact integer code for this role.
+    ///
+    /// | Role | Code |
+    /// |------|------|
+    /// | `User` | `0` |
+    /// | `Premium` | `1` |
+    /// | `Admin` | `2` |
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUserRole;
+    ///
+    /// assert_eq!(RUserRole::User.as_u8(), 0);
+    /// assert_eq!(RUserRole::Admin.as_u8(), 2);
+    /// ```
+    #[must_use]
+    pub const fn as_u8(&self) -> u8 {
+        match self {
+            Self::User => 0,
+            Self::Premium => 1,
+            Self::Admin => 2
+        }
+    }
+
+    /// Parse a role from its integer code.
+    ///
+    /// Returns `None` for codes outside `0..=2`.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - Integer code as produced by [`RUserRole::as_u8`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUserRole;
+    ///
+    /// assert_eq!(RUserRole::from_u8(1), Some(RUserRole::Premium));
+    /// assert_eq!(RUserRole::from_u8(42), None);
+    /// ```
+    #[must_use]
+    pub const fn from_u8(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(Self::User),
+            1 => Some(Self::Premium),
+            2 => Some(Self::Admin),
+            _ => None
+        }
+    }
+
+    /// All roles, from least to most privileged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUserRole;
+    ///
+    /// assert_eq!(RUserRole::all()[2], RUserRole::Admin);
+    /// ```
+    #[must_use]
+    pub const fn all() -> [Self; 3] {
+        [Self::User, Self::Premium, Self::Admin]
+    }
+
+    /// Get the lowest-privilege role whose permissions contain `required`.
+    ///
+    /// Follows the installed [`RolePolicy`], so overrides are taken into
+    /// account. Returns `None` if even [`RUserRole::Admin`] lacks them.
+    ///
+    /// # Arguments
+    ///
+    /// * `required` - Permissions to look up, e.g. for a "requires Premium"
+    ///   hint
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Permissions, RUserRole};
+    ///
+    /// assert_eq!(
+    ///     RUserRole::minimum_for(Permissions::READ),
+    ///     Some(RUserRole::User)
+    /// );
+    /// assert_eq!(
+    ///     RUserRole::minimum_for(Permissions::DELETE),
+    ///     Some(RUserRole::Admin)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn minimum_for(required: Permissions) -> Option<Self> {
+        RolePolicy::current().minimum_role(required)
+    }
+
+    /// Roles × permissions grid for documentation and admin UIs.
+    ///
+    /// One row per [`RUserRole::all`] entry, one cell per
+    /// [`Permissions::all_flags`] entry. Cells follow the installed
+    /// [`RolePolicy`], like [`Role::can`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Permissions, RUserRole};
+    ///
+    /// let matrix = RUserRole::capability_matrix();
+    /// let (role, cells) = &matrix[0];
+    ///
+    /// assert_eq!(*role, RUserRole::User);
+    /// assert_eq!(cells[0], (Permissions::READ, true));
+    /// ```
+    #[must_use]
+    pub fn capability_matrix() -> Vec<(Self, Vec<(Permissions, bool)>)> {
+        Self::all()
+            .into_iter()
+            .map(|role| {
+                let cells = Permissions::all_flags()
+                    .into_iter()
+                    .map(|permission| (permission, role.can(permission)))
+                    .collect();
+                (role, cells)
+            })
+            .collect()
+    }
+}
+
+/// Serde adapter storing [`RUserRole`] as its integer code.
+///
+/// Use with `#[serde(with = "role_as_int")]` where a compact numeric
+/// representation is required.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{RUserRole, role_as_int};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Row {
+///     #[serde(with = "role_as_int")]
+///     role: RUserRole
+/// }
+///
+/// let json = serde_json::to_string(&Row {
+///     role: RUserRole::Admin
+/// })
+/// .unwrap();
+/// assert_eq!(json, r#"{"role":2}"#);
+/// ```
+pub mod role_as_int {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    use super::RUserRole;
+
+    /// Serialize a role as its integer code.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer's error if writing fails.
+    pub fn serialize<S>(role: &RUserRole, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        serializer.serialize_u8(role.as_u8())
+    }
+
+    /// Deserialize a role from its integer code.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a `u8` or is out of range.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<RUserRole, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let code = u8::deserialize(deserializer)?;
+        RUserRole::from_u8(code)
+            .ok_or_else(|| D::Error::custom(format!("invalid role code: {code}")))
+    }
 }
 
 impl core::fmt::Display for RUserRole {
@@ -221,6 +436,317 @@ impl core::fmt::Display for RUserRole {
     }
 }
 
+/// Parses a role name, trimmed and case-insensitive.
+///
+/// Goes through the serde definition, so the legacy names `basic` and
+/// `administrator` are accepted exactly as when deserializing.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::RUserRole;
+///
+/// assert_eq!(" Administrator ".parse::<RUserRole>(), Ok(RUserRole::Admin));
+/// assert!("wizard".parse::<RUserRole>().is_err());
+/// ```
+impl core::str::FromStr for RUserRole {
+    type Err = ParseRoleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use serde::de::{IntoDeserializer, value::Error as ValueError};
+
+        let name = s.trim().to_lowercase();
+        Self::deserialize(name.as_str().into_deserializer())
+            .map_err(|_: ValueError| ParseRoleError(name))
+    }
+}
+
+/// Error returned when a string is not a role name.
+///
+/// Holds the trimmed, lowercased input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRoleError(pub String);
+
+impl core::fmt::Display for ParseRoleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown role: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseRoleError {}
+
+impl From<ParseRoleError> for PermissionError {
+    fn from(err: ParseRoleError) -> Self {
+        Self::UnknownRole(err.0)
+    }
+}
+
+/// Parse a role name followed by `+`-joined extra permissions.
+///
+/// Accepts config strings such as `"premium + export + audit"`. The first
+/// token is the role, parsed like [`RUserRole`]'s `FromStr`; the rest
+/// are permission names. All tokens are trimmed and case-insensitive.
+/// The returned permissions hold only the extras, not the role's own.
+///
+/// # Arguments
+///
+/// * `s` - Role name, optionally followed by `+ permission` tokens
+///
+/// # Errors
+///
+/// - [`PermissionError::UnknownRole`] if the role token is not a role
+/// - [`PermissionError::UnknownName`] if an extra is not a permission
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{Permissions, RUserRole, parse_role_with_extras};
+///
+/// let (role, extras) = parse_role_with_extras("premium + export + audit").unwrap();
+///
+/// assert_eq!(role, RUserRole::Premium);
+/// assert_eq!(extras, Permissions::EXPORT | Permissions::AUDIT);
+/// ```
+pub fn parse_role_with_extras(s: &str) -> Result<(RUserRole, Permissions), PermissionError> {
+    let mut tokens = s.split('+').map(str::trim);
+
+    let role: RUserRole = tokens.next().unwrap_or_default().parse()?;
+
+    let extras = tokens.try_fold(Permissions::empty(), |acc, name| {
+        if name.is_empty() {
+            return Err(PermissionError::UnknownName(String::new()));
+        }
+        parse_permissions(name).map(|p| acc | p)
+    })?;
+
+    Ok((role, extras))
+}
+
+/// Data-driven mapping from roles to permissions.
+///
+/// Deserializes from JSON, TOML or any serde format. Each role takes
+/// either a list of permission names, a comma/pipe separated string, or
+/// `"all"`. Missing roles keep their built-in permissions.
+///
+/// Install a policy once at startup with [`RolePolicy::install`]; from
+/// then on [`RUserRole::permissions`](Role::permissions) follows it.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{Permissions, RUserRole, RolePolicy};
+///
+/// let policy: RolePolicy = serde_json::from_str(
+///     r#"{
+///         "user": ["read", "api_access"],
+///         "premium": ["read", "write", "api_access", "premium", "export", "import"],
+///         "admin": "all"
+///     }"#
+/// )
+/// .unwrap();
+///
+/// assert!(
+///     policy
+///         .permissions(RUserRole::Premium)
+///         .contains(Permissions::IMPORT)
+/// );
+/// assert_eq!(policy.permissions(RUserRole::Admin), Permissions::all());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct RolePolicy {
+    /// Permissions of [`RUserRole::User`].
+    #[serde(deserialize_with = "deserialize_permission_spec")]
+    pub user: Permissions,
+
+    /// Permissions of [`RUserRole::Premium`].
+    #[serde(deserialize_with = "deserialize_permission_spec")]
+    pub premium: Permissions,
+
+    /// Permissions of [`RUserRole::Admin`].
+    #[serde(deserialize_with = "deserialize_permission_spec")]
+    pub admin: Permissions
+}
+
+static ROLE_POLICY: OnceLock<RolePolicy> = OnceLock::new();
+
+/// Per-role permissions resolved from the installed [`RolePolicy`].
+static RESOLVED: RoleCache = RoleCache::new();
+
+/// Resolved permissions, one slot per role, filled on first query.
+struct RoleCache([OnceLock<Permissions>; 3]);
+
+impl RoleCache {
+    const fn new() -> Self {
+        Self([OnceLock::new(), OnceLock::new(), OnceLock::new()])
+    }
+
+    /// Cached permissions of `role`, resolved from `policy` if the slot
+    /// is still empty.
+    fn get_or_resolve(&self, role: RUserRole, policy: &RolePolicy) -> Permissions {
+        let index = match role {
+            RUserRole::User => 0,
+            RUserRole::Premium => 1,
+            RUserRole::Admin => 2
+        };
+        *self.0[index].get_or_init(|| policy.permissions(role))
+    }
+}
+
+impl RolePolicy {
+    /// Get the permissions this policy grants to a role.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - Role to look up
+    #[must_use]
+    pub const fn permissions(&self, role: RUserRole) -> Permissions {
+        match role {
+            RUserRole::User => self.user,
+            RUserRole::Premium => self.premium,
+            RUserRole::Admin => self.admin
+        }
+    }
+
+    /// Get the lowest-privilege role this policy grants all of
+    /// `required` to.
+    ///
+    /// Returns `None` if no role, not even Admin, holds them all.
+    ///
+    /// # Arguments
+    ///
+    /// * `required` - Permissions the role must hold
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Permissions, RUserRole, RolePolicy};
+    ///
+    /// let policy = RolePolicy::BUILTIN;
+    /// assert_eq!(
+    ///     policy.minimum_role(Permissions::EXPORT),
+    ///     Some(RUserRole::Premium)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn minimum_role(&self, required: Permissions) -> Option<RUserRole> {
+        RUserRole::all()
+            .into_iter()
+            .find(|&role| self.permissions(role).contains(required))
+    }
+
+    /// Install this policy process-wide.
+    ///
+    /// Can only be done once; call it during startup before handling
+    /// requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy back if one is already installed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Permissions, RUserRole, Role, RolePolicy};
+    ///
+    /// let mut policy = RolePolicy::default();
+    /// policy.premium.grant(Permissions::IMPORT);
+    /// policy.install().unwrap();
+    ///
+    /// assert!(RUserRole::Premium.can(Permissions::IMPORT));
+    /// assert!(RolePolicy::default().install().is_err());
+    /// ```
+    pub fn install(self) -> Result<(), Self> {
+        ROLE_POLICY.set(self)
+    }
+
+    /// Returns the installed policy, or the built-in one if none is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Permissions, RUserRole, RolePolicy};
+    ///
+    /// let policy = RolePolicy::current();
+    /// assert!(
+    ///     policy
+    ///         .permissions(RUserRole::User)
+    ///         .contains(Permissions::READ)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn current() -> Self {
+        ROLE_POLICY.get().copied().unwrap_or_default()
+    }
+}
+
+impl RolePolicy {
+    /// The built-in mapping used when no policy is installed.
+    ///
+    /// Usable in `const` contexts, see [`role_grants`].
+    pub const BUILTIN: Self = Self {
+        user:    Permissions::READ.union(Permissions::API_ACCESS),
+        premium: Permissions::READ
+            .union(Permissions::WRITE)
+            .union(Permissions::API_ACCESS)
+            .union(Permissions::PREMIUM)
+            .union(Permissions::EXPORT),
+        admin:   Permissions::all()
+    };
+}
+
+impl Default for RolePolicy {
+    /// The built-in mapping used when no policy is installed.
+    fn default() -> Self {
+        Self::BUILTIN
+    }
+}
+
+/// Check at compile time whether a role's built-in permissions include
+/// `permissions`.
+///
+/// Evaluates against [`RolePolicy::BUILTIN`], not an installed policy,
+/// so downstream crates can pin role expectations in `const` assertions.
+/// Runtime checks should keep using [`Role::can`].
+///
+/// # Arguments
+///
+/// * `role` - Role to check
+/// * `permissions` - Permissions the role must hold (all of them)
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{Permissions, RUserRole, role_grants};
+///
+/// const _: () = assert!(role_grants(RUserRole::Admin, Permissions::DELETE));
+/// const _: () = assert!(!role_grants(RUserRole::User, Permissions::DELETE));
+/// ```
+#[must_use]
+pub const fn role_grants(role: RUserRole, permissions: Permissions) -> bool {
+    RolePolicy::BUILTIN.permissions(role).contains(permissions)
+}
+
+/// Deserialize `"all"`, a permission string, or a list of names.
+fn deserialize_permission_spec<'de, D>(deserializer: D) -> Result<Permissions, D::Error>
+where
+    D: Deserializer<'de>
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Spec {
+        Text(String),
+        Names(Vec<String>)
+    }
+
+    match Spec::deserialize(deserializer)? {
+        Spec::Text(text) if text.trim().eq_ignore_ascii_case("all") => Ok(Permissions::all()),
+        Spec::Text(text) => parse_permissions(&text),
+        Spec::Names(names) => Permissions::try_from_names(names)
+    }
+    .map_err(D::Error::custom)
+}
+
 /// Implementation of [`Role`] trait for permission-based access control.
 ///
 /// # Permission Mapping
@@ -231,6 +757,8 @@ impl core::fmt::Display for RUserRole {
 /// | `Premium` | READ, WRITE, API_ACCESS, PREMIUM, EXPORT |
 /// | `Admin` | All permissions |
 ///
+/// This is the built-in mapping; an installed [`RolePolicy`] overrides it.
+///
 /// # Examples
 ///
 /// ```rust
@@ -246,17 +774,17 @@ impl core::fmt::Display for RUserRole {
 /// assert!(!premium.can(Permissions::ADMIN));
 /// ```
 impl Role for RUserRole {
+    /// Permissions granted by the current [`RolePolicy`].
+    ///
+    /// Once a policy is installed, each role's permissions are resolved
+    /// on first query and cached. A policy can only be installed once,
+    /// so the cache never goes stale, but queries made before
+    /// [`RolePolicy::install`] see [`RolePolicy::BUILTIN`]: install the
+    /// policy before the first permission query.
     fn permissions(&self) -> Permissions {
-        match self {
-            Self::User => Permissions::READ | Permissions::API_ACCESS,
-            Self::Premium => {
-                Permissions::READ
-                    | Permissions::WRITE
-                    | Permissions::API_ACCESS
-                    | Permissions::PREMIUM
-                    | Permissions::EXPORT
-            }
-            Self::Admin => Permissions::all()
+        match ROLE_POLICY.get() {
+            Some(policy) => RESOLVED.get_or_resolve(*self, policy),
+            None => RolePolicy::BUILTIN.permissions(*self)
         }
     }
 
@@ -269,6 +797,57 @@ impl Role for RUserRole {
 mod tests {
     use super::*;
 
+    const _: () = assert!(role_grants(RUserRole::Admin, Permissions::DELETE));
+    const _: () = assert!(role_grants(RUserRole::Premium, Permissions::EXPORT));
+    const _: () = assert!(!role_grants(RUserRole::User, Permissions::DELETE));
+    const _: () = assert!(!role_grants(RUserRole::Premium, Permissions::ADMIN));
+
+    #[test]
+    fn parse_role_with_extras_splits_role_and_permissions() {
+        assert_eq!(
+            parse_role_with_extras("user + write"),
+            Ok((RUserRole::User, Permissions::WRITE))
+        );
+        assert_eq!(
+            parse_role_with_extras(" Admin "),
+            Ok((RUserRole::Admin, Permissions::empty()))
+        );
+        assert_eq!(
+            parse_role_with_extras("premium+export+AUDIT"),
+            Ok((RUserRole::Premium, Permissions::EXPORT | Permissions::AUDIT))
+        );
+    }
+
+    #[test]
+    fn parse_role_with_extras_rejects_unknown_tokens() {
+        assert_eq!(
+            parse_role_with_extras("Wizard + write"),
+            Err(PermissionError::UnknownRole("wizard".into()))
+        );
+        assert_eq!(
+            parse_role_with_extras("user + fly"),
+            Err(PermissionError::UnknownName("fly".into()))
+        );
+        assert_eq!(
+            PermissionError::from(ParseRoleError("wizard".into())).to_string(),
+            "unknown role: wizard"
+        );
+        assert!(parse_role_with_extras("").is_err());
+        assert!(parse_role_with_extras("user + ").is_err());
+    }
+
+    #[test]
+    fn role_grants_matches_builtin_policy() {
+        for role in [RUserRole::User, RUserRole::Premium, RUserRole::Admin] {
+            let builtin = RolePolicy::default().permissions(role);
+            assert!(role_grants(role, builtin));
+            assert_eq!(
+                role_grants(role, Permissions::all()),
+                builtin == Permissions::all()
+            );
+        }
+    }
+
     #[test]
     fn default_is_user() {
         assert_eq!(RUserRole::default(), RUserRole::User);
@@ -301,6 +880,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_str_matches_serde_names() {
+        for role in RUserRole::all() {
+            assert_eq!(role.to_string().parse::<RUserRole>(), Ok(role));
+        }
+        assert_eq!("BASIC".parse::<RUserRole>(), Ok(RUserRole::User));
+        assert_eq!("administrator".parse::<RUserRole>(), Ok(RUserRole::Admin));
+        assert_eq!(
+            "root".parse::<RUserRole>(),
+            Err(ParseRoleError("root".into()))
+        );
+    }
+
     #[test]
     fn deserializes_correctly() {
         assert_eq!(
@@ -313,6 +905,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserializes_legacy_aliases() {
+        assert_eq!(
+            serde_json::from_str::<RUserRole>("\"basic\"").unwrap(),
+            RUserRole::User
+        );
+        assert_eq!(
+            serde_json::from_str::<RUserRole>("\"administrator\"").unwrap(),
+            RUserRole::Admin
+        );
+    }
+
+    #[test]
+    fn legacy_aliases_serialize_canonically() {
+        let user: RUserRole = serde_json::from_str("\"basic\"").unwrap();
+        let admin: RUserRole = serde_json::from_str("\"administrator\"").unwrap();
+
+        assert_eq!(serde_json::to_string(&user).unwrap(), "\"user\"");
+        assert_eq!(serde_json::to_string(&admin).unwrap(), "\"admin\"");
+    }
+
     #[test]
     fn display_impl() {
         assert_eq!(format!("{}", RUserRole::User), "user");
@@ -333,6 +946,42 @@ mod tests {
         assert_eq!(RUserRole::Admin.as_str(), "admin");
     }
 
+    #[test]
+    fn u8_roundtrip() {
+        for role in [RUserRole::User, RUserRole::Premium, RUserRole::Admin] {
+            assert_eq!(RUserRole::from_u8(role.as_u8()), Some(role));
+        }
+    }
+
+    #[test]
+    fn from_u8_out_of_range_is_none() {
+        assert_eq!(RUserRole::from_u8(3), None);
+        assert_eq!(RUserRole::from_u8(u8::MAX), None);
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct IntRow {
+        #[serde(with = "role_as_int")]
+        role: RUserRole
+    }
+
+    #[test]
+    fn role_as_int_roundtrip() {
+        let row = IntRow {
+            role: RUserRole::Premium
+        };
+        let json = serde_json::to_string(&row).unwrap();
+        assert_eq!(json, r#"{"role":1}"#);
+
+        let parsed: IntRow = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, row);
+    }
+
+    #[test]
+    fn role_as_int_rejects_out_of_range() {
+        assert!(serde_json::from_str::<IntRow>(r#"{"role":7}"#).is_err());
+    }
+
     #[test]
     fn display_matches_as_str() {
         assert_eq!(format!("{}", RUserRole::Premium), "premium");
@@ -391,4 +1040,113 @@ mod tests {
         assert_eq!(RUserRole::Premium.name(), "premium");
         assert_eq!(RUserRole::Admin.name(), "admin");
     }
+
+    #[test]
+    fn role_policy_deserializes_lists_strings_and_all() {
+        let policy: RolePolicy = serde_json::from_str(
+            r#"{"user": ["read"], "premium": "read, write, import", "admin": "all"}"#
+        )
+        .unwrap();
+
+        assert_eq!(policy.user, Permissions::READ);
+        assert_eq!(
+            policy.premium,
+            Permissions::READ | Permissions::WRITE | Permissions::IMPORT
+        );
+        assert_eq!(policy.admin, Permissions::all());
+    }
+
+    #[test]
+    fn role_policy_missing_roles_use_defaults() {
+        let policy: RolePolicy = serde_json::from_str(r#"{"user": ["read"]}"#).unwrap();
+
+        assert_eq!(policy.premium, RolePolicy::default().premium);
+        assert_eq!(policy.admin, Permissions::all());
+    }
+
+    #[test]
+    fn role_policy_rejects_unknown_permission() {
+        assert!(serde_json::from_str::<RolePolicy>(r#"{"user": ["raed"]}"#).is_err());
+    }
+
+    #[test]
+    fn minimum_for_picks_lowest_sufficient_role() {
+        assert_eq!(
+            RUserRole::minimum_for(Permissions::WRITE),
+            Some(RUserRole::Premium)
+        );
+        assert_eq!(
+            RUserRole::minimum_for(Permissions::DELETE),
+            Some(RUserRole::Admin)
+        );
+        assert_eq!(
+            RUserRole::minimum_for(Permissions::READ | Permissions::API_ACCESS),
+            Some(RUserRole::User)
+        );
+    }
+
+    #[test]
+    fn minimum_for_returns_none_when_no_role_suffices() {
+        let undefined = Permissions::from_bits_retain(1 << 31);
+        assert_eq!(RUserRole::minimum_for(undefined), None);
+
+        let mut policy = RolePolicy::BUILTIN;
+        policy.admin.revoke(Permissions::BILLING);
+        assert_eq!(policy.minimum_role(Permissions::BILLING), None);
+        assert_eq!(
+            policy.minimum_role(Permissions::EXPORT),
+            Some(RUserRole::Premium)
+        );
+    }
+
+    #[test]
+    fn capability_matrix_covers_roles_and_flags() {
+        let matrix = RUserRole::capability_matrix();
+        assert_eq!(matrix.len(), RUserRole::all().len());
+
+        let (role, admin) = &matrix[2];
+        assert_eq!(*role, RUserRole::Admin);
+        assert_eq!(admin.len(), Permissions::all_flags().len());
+        assert!(admin.iter().all(|&(_, granted)| granted));
+
+        let (role, user) = &matrix[0];
+        assert_eq!(*role, RUserRole::User);
+        assert!(user.contains(&(Permissions::DELETE, false)));
+        assert!(user.contains(&(Permissions::READ, true)));
+    }
+
+    #[test]
+    fn resolved_permissions_are_cached_per_role() {
+        // A local cache: installing a policy would leak into other tests.
+        let cache = RoleCache::new();
+        let mut policy = RolePolicy::default();
+        policy.premium.grant(Permissions::IMPORT);
+
+        let first = cache.get_or_resolve(RUserRole::Premium, &policy);
+        assert!(first.contains(Permissions::IMPORT));
+        assert_eq!(
+            cache.get_or_resolve(RUserRole::Premium, &RolePolicy::BUILTIN),
+            first
+        );
+        assert_eq!(
+            cache.get_or_resolve(RUserRole::User, &policy),
+            RolePolicy::BUILTIN.user
+        );
+    }
+
+    #[test]
+    fn policy_overrides_only_granted_role() {
+        // Not installed: the policy is process-wide and would leak into
+        // other tests. `RolePolicy::install` is covered by its doctest.
+        let mut policy = RolePolicy::default();
+        policy.premium.grant(Permissions::IMPORT);
+
+        assert!(
+            policy
+                .permissions(RUserRole::Premium)
+                .contains(Permissions::IMPORT)
+        );
+        assert_eq!(policy.user, RolePolicy::BUILTIN.user);
+        assert_eq!(policy.admin, RolePolicy::BUILTIN.admin);
+    }
 }
@@ -0,0 +1,500 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! Telegram Login Widget verification.
+//!
+//! Enabled with the `telegram` feature. The widget redirects back with
+//! the user's profile and a `hash` signed by the bot token;
+//! [`verify_telegram_login`] recomputes that signature as described in
+//! the [Telegram docs](https://core.telegram.org/widgets/login#checking-authorization).
+//!
+//! # Algorithm
+//!
+//! 1. Build the data-check-string: every present field except `hash`, as
+//!    `key=value`, sorted by key and joined with `\n`
+//! 2. Derive the secret key as `SHA256(bot_token)`
+//! 3. Compare `HMAC-SHA256(secret_key, data_check_string)` with `hash`
+//! 4. Reject payloads whose `auth_date` is older than the maximum age
+//!    ([`DEFAULT_TELEGRAM_MAX_AGE`] unless configured), so a captured payload
+//!    cannot be replayed indefinitely
+//!
+//! # Examples
+//!
+//! ```rust
+//! use revelation_user::{TelegramLoginData, verify_telegram_login};
+//!
+//! let data: TelegramLoginData = serde_json::from_str(
+//!     r#"{"id":123456789,"first_name":"John","auth_date":1700000000,"hash":"00"}"#
+//! )
+//! .unwrap();
+//!
+//! assert!(verify_telegram_login(&data, "123456:TEST-bot-token").is_err());
+//! ```
+
+use std::time::Duration;
+
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use masterror::AppError;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Maximum age of `auth_date` accepted by [`verify_telegram_login`]: one
+/// day.
+pub const DEFAULT_TELEGRAM_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Payload sent by the Telegram Login Widget.
+///
+/// Field names match the widget's query parameters, independent of the
+/// `camel` feature. `id` also accepts a numeric string, see
+/// [`telegram_id`](crate::telegram_id).
+///
+/// # Fields
+///
+/// | Field | Type | Description |
+/// |-------|------|-------------|
+/// | `id` | `i64` | Telegram user ID |
+/// | `first_name` | `String` | First name |
+/// | `last_name` | `Option<String>` | Last name |
+/// | `username` | `Option<String>` | Username without `@` |
+/// | `photo_url` | `Option<String>` | Profile picture URL |
+/// | `auth_date` | `i64` | Unix time of the login |
+/// | `hash` | `String` | Hex HMAC-SHA256 signature |
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "api", derive(utoipa::ToSchema))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TelegramLoginData {
+    /// Telegram user ID.
+    #[serde(with = "crate::telegram_id")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
+    pub id: i64,
+
+    /// User's first name.
+    pub first_name: String,
+
+    /// User's last name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+
+    /// Telegram username.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Profile picture URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<String>,
+
+    /// Unix time when the user authenticated.
+    pub auth_date: i64,
+
+    /// Hex-encoded signature to verify.
+    pub hash: String
+}
+
+impl TelegramLoginData {
+    /// Build the data-check-string signed by Telegram.
+    ///
+    /// Present fields except `hash`, as `key=value`, sorted by key and
+    /// joined with `\n`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::TelegramLoginData;
+    ///
+    /// let data = TelegramLoginData {
+    ///     id:         42,
+    ///     first_name: "John".into(),
+    ///     last_name:  None,
+    ///     username:   Some("john".into()),
+    ///     photo_url:  None,
+    ///     auth_date:  1700000000,
+    ///     hash:       String::new()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     data.data_check_string(),
+    ///     "auth_date=1700000000\nfirst_name=John\nid=42\nusername=john"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn data_check_string(&self) -> String {
+        // Already in alphabetical key order.
+        let fields = [
+            ("auth_date", Some(self.auth_date.to_string())),
+            ("first_name", Some(self.first_name.clone())),
+            ("id", Some(self.id.to_string())),
+            ("last_name", self.last_name.clone()),
+            ("photo_url", self.photo_url.clone()),
+            ("username", self.username.clone())
+        ];
+
+        fields
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| format!("{key}={value}")))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Verify a Telegram Login Widget payload against the bot token.
+///
+/// Checks the signature in constant time, then rejects payloads whose
+/// `auth_date` is more than [`DEFAULT_TELEGRAM_MAX_AGE`] (one day) before
+/// the current time, as the Telegram docs require. Use
+/// [`verify_telegram_login_with_max_age`] for a different window.
+///
+/// # Arguments
+///
+/// * `data` - Payload received from the widget
+/// * `bot_token` - Token of the bot the widget is configured for
+///
+/// # Errors
+///
+/// - [`TelegramAuthError::MalformedHash`] if `hash` is not 64 hex digits
+/// - [`TelegramAuthError::HashMismatch`] if the signature does not match
+/// - [`TelegramAuthError::Expired`] if `auth_date` is more than a day old
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{TelegramAuthError, TelegramLoginData, verify_telegram_login};
+///
+/// let data = TelegramLoginData {
+///     id:         123456789,
+///     first_name: "John".into(),
+///     last_name:  None,
+///     username:   None,
+///     photo_url:  None,
+///     auth_date:  1700000000,
+///     hash:       "not-hex".into()
+/// };
+///
+/// assert_eq!(
+///     verify_telegram_login(&data, "123456:TEST-bot-token"),
+///     Err(TelegramAuthError::MalformedHash)
+/// );
+/// ```
+pub fn verify_telegram_login(
+    data: &TelegramLoginData,
+    bot_token: &str
+) -> Result<(), TelegramAuthError> {
+    verify_telegram_login_with_max_age(data, bot_token, DEFAULT_TELEGRAM_MAX_AGE)
+}
+
+/// Like [`verify_telegram_login`], accepting `auth_date` up to `max_age`
+/// old.
+///
+/// # Arguments
+///
+/// * `data` - Payload received from the widget
+/// * `bot_token` - Token of the bot the widget is configured for
+/// * `max_age` - Oldest `auth_date` accepted
+///
+/// # Errors
+///
+/// Same as [`verify_telegram_login`], with [`TelegramAuthError::Expired`]
+/// if `auth_date` is older than `max_age`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use revelation_user::{
+///     TelegramAuthError, TelegramLoginData, verify_telegram_login_with_max_age
+/// };
+///
+/// let data = TelegramLoginData {
+///     id:         123456789,
+///     first_name: "John".into(),
+///     last_name:  Some("Doe".into()),
+///     username:   Some("johndoe".into()),
+///     photo_url:  None,
+///     auth_date:  1700000000,
+///     hash:       "9c7e6cf7f0fda4ffcdc746bdaa4a7d9390dfd9f9516000e19a3758750dd09063".into()
+/// };
+///
+/// assert_eq!(
+///     verify_telegram_login_with_max_age(
+///         &data,
+///         "123456:TEST-bot-token",
+///         Duration::from_secs(600)
+///     ),
+///     Err(TelegramAuthError::Expired)
+/// );
+/// ```
+pub fn verify_telegram_login_with_max_age(
+    data: &TelegramLoginData,
+    bot_token: &str,
+    max_age: Duration
+) -> Result<(), TelegramAuthError> {
+    verify_telegram_login_at(data, bot_token, max_age, Utc::now().timestamp())
+}
+
+/// Like [`verify_telegram_login_with_max_age`], with the current time
+/// given as Unix seconds.
+///
+/// # Arguments
+///
+/// * `data` - Payload received from the widget
+/// * `bot_token` - Token of the bot the widget is configured for
+/// * `max_age` - Oldest `auth_date` accepted
+/// * `now` - Current Unix time in seconds
+///
+/// # Errors
+///
+/// Same as [`verify_telegram_login_with_max_age`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use revelation_user::{TelegramAuthError, TelegramLoginData, verify_telegram_login_at};
+///
+/// let data = TelegramLoginData {
+///     id:         123456789,
+///     first_name: "John".into(),
+///     last_name:  Some("Doe".into()),
+///     username:   Some("johndoe".into()),
+///     photo_url:  None,
+///     auth_date:  1700000000,
+///     hash:       "9c7e6cf7f0fda4ffcdc746bdaa4a7d9390dfd9f9516000e19a3758750dd09063".into()
+/// };
+/// let token = "123456:TEST-bot-token";
+/// let day = Duration::from_secs(86400);
+///
+/// assert_eq!(
+///     verify_telegram_login_at(&data, token, day, 1700000600),
+///     Ok(())
+/// );
+/// assert_eq!(
+///     verify_telegram_login_at(&data, token, day, 1700000000 + 2 * 86400),
+///     Err(TelegramAuthError::Expired)
+/// );
+/// ```
+pub fn verify_telegram_login_at(
+    data: &TelegramLoginData,
+    bot_token: &str,
+    max_age: Duration,
+    now: i64
+) -> Result<(), TelegramAuthError> {
+    let expected = decode_hex(&data.hash).ok_or(TelegramAuthError::MalformedHash)?;
+
+    let secret_key = Sha256::digest(bot_token.as_bytes());
+    let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(&secret_key)
+        .expect("HMAC accepts keys of any length");
+    mac.update(data.data_check_string().as_bytes());
+
+    mac.verify_slice(&expected)
+        .map_err(|_| TelegramAuthError::HashMismatch)?;
+
+    let max_age = i64::try_from(max_age.as_secs()).unwrap_or(i64::MAX);
+    if now.saturating_sub(data.auth_date) > max_age {
+        return Err(TelegramAuthError::Expired);
+    }
+    Ok(())
+}
+
+/// Decode a 64-digit hex string into the 32-byte signature.
+fn decode_hex(hex: &str) -> Option<[u8; 32]> {
+    let digits = hex.as_bytes();
+    if digits.len() != 64 {
+        return None;
+    }
+
+    let mut bytes = [0u8; 32];
+    for (byte, pair) in bytes.iter_mut().zip(digits.chunks_exact(2)) {
+        let high = char::from(pair[0]).to_digit(16)?;
+        let low = char::from(pair[1]).to_digit(16)?;
+        *byte = (high * 16 + low) as u8;
+    }
+    Some(bytes)
+}
+
+/// Error returned when a Telegram login payload fails verification.
+///
+/// # Conversions
+///
+/// - `Into<AppError>` - 401 Unauthorized for HTTP responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TelegramAuthError {
+    /// `hash` is not a 64-digit hex string.
+    MalformedHash,
+
+    /// `hash` does not match the payload signed with the bot token.
+    HashMismatch,
+
+    /// `auth_date` is older than the accepted maximum age.
+    Expired
+}
+
+impl core::fmt::Display for TelegramAuthError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MalformedHash => f.write_str("Telegram login hash is malformed"),
+            Self::HashMismatch => f.write_str("Telegram login hash does not match"),
+            Self::Expired => f.write_str("Telegram login has expired")
+        }
+    }
+}
+
+impl std::error::Error for TelegramAuthError {}
+
+impl From<TelegramAuthError> for AppError {
+    /// Converts into a 401 Unauthorized error.
+    fn from(err: TelegramAuthError) -> Self {
+        AppError::unauthorized(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use masterror::AppErrorKind;
+
+    use super::*;
+
+    const BOT_TOKEN: &str = "123456:TEST-bot-token";
+    const DAY: Duration = Duration::from_secs(86400);
+
+    /// Verify as of one minute after the payload was signed.
+    fn verify(data: &TelegramLoginData, bot_token: &str) -> Result<(), TelegramAuthError> {
+        verify_telegram_login_at(data, bot_token, DAY, 1700000060)
+    }
+
+    fn signed_payload() -> TelegramLoginData {
+        TelegramLoginData {
+            id:         123456789,
+            first_name: "John".into(),
+            last_name:  Some("Doe".into()),
+            username:   Some("johndoe".into()),
+            photo_url:  None,
+            auth_date:  1700000000,
+            hash:       "9c7e6cf7f0fda4ffcdc746bdaa4a7d9390dfd9f9516000e19a3758750dd09063".into()
+        }
+    }
+
+    #[test]
+    fn accepts_known_good_payload() {
+        assert_eq!(verify(&signed_payload(), BOT_TOKEN), Ok(()));
+    }
+
+    #[test]
+    fn accepts_uppercase_hash() {
+        let mut data = signed_payload();
+        data.hash = data.hash.to_uppercase();
+
+        assert_eq!(verify(&data, BOT_TOKEN), Ok(()));
+    }
+
+    #[test]
+    fn rejects_tampered_payload() {
+        let mut data = signed_payload();
+        data.id = 987654321;
+
+        assert_eq!(
+            verify(&data, BOT_TOKEN),
+            Err(TelegramAuthError::HashMismatch)
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_bot_token() {
+        assert_eq!(
+            verify(&signed_payload(), "654321:OTHER-bot-token"),
+            Err(TelegramAuthError::HashMismatch)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_hash() {
+        let non_hex = "zz".repeat(32);
+        let too_long = "a".repeat(66);
+
+        for hash in ["", "abc", non_hex.as_str(), too_long.as_str()] {
+            let mut data = signed_payload();
+            data.hash = hash.to_owned();
+
+            assert_eq!(
+                verify(&data, BOT_TOKEN),
+                Err(TelegramAuthError::MalformedHash)
+            );
+        }
+    }
+
+    #[test]
+    fn data_check_string_skips_absent_fields_and_hash() {
+        assert_eq!(
+            signed_payload().data_check_string(),
+            concat!(
+                "auth_date=1700000000\nfirst_name=John\nid=123456789\n",
+                "last_name=Doe\nusername=johndoe"
+            )
+        );
+    }
+
+    #[test]
+    fn deserializes_widget_payload_with_string_id() {
+        let data: TelegramLoginData = serde_json::from_value(serde_json::json!({
+            "id": "123456789",
+            "first_name": "John",
+            "last_name": "Doe",
+            "username": "johndoe",
+            "auth_date": 1700000000,
+            "hash": signed_payload().hash
+        }))
+        .unwrap();
+
+        assert_eq!(data, signed_payload());
+    }
+
+    #[test]
+    fn rejects_stale_auth_date() {
+        let data = signed_payload();
+
+        assert_eq!(
+            verify_telegram_login_at(&data, BOT_TOKEN, DAY, 1700000000 + 86400),
+            Ok(())
+        );
+        assert_eq!(
+            verify_telegram_login_at(&data, BOT_TOKEN, DAY, 1700000000 + 86401),
+            Err(TelegramAuthError::Expired)
+        );
+    }
+
+    #[test]
+    fn checks_hash_before_auth_date() {
+        let mut data = signed_payload();
+        data.id = 987654321;
+
+        assert_eq!(
+            verify_telegram_login_at(&data, BOT_TOKEN, DAY, i64::MAX),
+            Err(TelegramAuthError::HashMismatch)
+        );
+    }
+
+    #[test]
+    fn current_time_rejects_old_payload() {
+        assert_eq!(
+            verify_telegram_login(&signed_payload(), BOT_TOKEN),
+            Err(TelegramAuthError::Expired)
+        );
+        assert_eq!(
+            verify_telegram_login_with_max_age(
+                &signed_payload(),
+                BOT_TOKEN,
+                Duration::from_secs(u64::MAX)
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn converts_into_unauthorized() {
+        for err in [TelegramAuthError::HashMismatch, TelegramAuthError::Expired] {
+            let err: AppError = err.into();
+            assert_eq!(err.kind, AppErrorKind::Unauthorized);
+        }
+    }
+}
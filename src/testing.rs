@@ -0,0 +1,247 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! Deterministic fixtures for tests.
+//!
+//! Enabled with the `testing` feature. Regular constructors such as
+//! [`RUser::from_telegram`] use `Uuid::now_v7()` and `Utc::now()`, which
+//! makes golden JSON assertions flaky. [`RUser::fixture`] instead yields
+//! a nil id and Unix-epoch timestamps, and the `with_*` setters fill in
+//! only the fields a test cares about.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use revelation_user::RUser;
+//!
+//! let user = RUser::fixture()
+//!     .with_telegram_id(123456789)
+//!     .with_name("Alice");
+//!
+//! assert!(user.id.is_nil());
+//! assert_eq!(user.created_at.timestamp(), 0);
+//! assert_eq!(user.telegram_id, Some(123456789));
+//! ```
+//!
+//!
+//! [`Claims::dummy`] and [`Claims::test_token_payload`] help fabricate
+//! token payloads for mock JWT validators.
+//!
+//! [`RUser::from_telegram`]: crate::RUser::from_telegram
+//! [`RUser::fixture`]: crate::RUser::fixture
+//! [`Claims::dummy`]: crate::Claims::dummy
+//! [`Claims::test_token_payload`]: crate::Claims::test_token_payload
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, NaiveDate};
+
+use crate::{Claims, Gender, RUser, RUserRole, UserId};
+
+impl RUser {
+    /// Create a deterministic user for tests.
+    ///
+    /// Uses a nil UUID, Unix-epoch timestamps and leaves every
+    /// optional field unset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::RUser;
+    ///
+    /// let a = RUser::fixture();
+    /// let b = RUser::fixture();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_string(&a).unwrap(),
+    ///     serde_json::to_string(&b).unwrap()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn fixture() -> Self {
+        Self {
+            id: UserId::nil(),
+            created_at: DateTime::UNIX_EPOCH,
+            updated_at: DateTime::UNIX_EPOCH,
+            ..Self::empty()
+        }
+    }
+
+    /// Set the display name.
+    #[must_use]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the email address.
+    #[must_use]
+    pub fn with_email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// Set the phone number.
+    #[must_use]
+    pub fn with_phone(mut self, phone: impl Into<String>) -> Self {
+        self.phone = Some(phone.into());
+        self
+    }
+
+    /// Set the Telegram user ID.
+    #[must_use]
+    pub fn with_telegram_id(mut self, telegram_id: i64) -> Self {
+        self.telegram_id = Some(telegram_id);
+        self
+    }
+
+    /// Set the gender.
+    #[must_use]
+    pub fn with_gender(mut self, gender: Gender) -> Self {
+        self.gender = Some(gender);
+        self
+    }
+
+    /// Set the date of birth.
+    #[must_use]
+    pub fn with_birth_date(mut self, birth_date: NaiveDate) -> Self {
+        self.birth_date = Some(birth_date);
+        self
+    }
+}
+
+impl Claims {
+    /// Expiry used by [`Claims::dummy`]: 2100-01-01T00:00:00Z.
+    pub const DUMMY_EXP: usize = 4_102_444_800;
+
+    /// Create claims for tests with a nil subject and a far-future expiry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Claims, RUserRole};
+    ///
+    /// let claims = Claims::dummy(RUserRole::Admin);
+    ///
+    /// assert!(claims.sub.is_nil());
+    /// assert_eq!(claims.exp, Claims::DUMMY_EXP);
+    /// ```
+    #[must_use]
+    pub fn dummy(role: RUserRole) -> Self {
+        Self::new(UserId::nil(), role, Self::DUMMY_EXP)
+    }
+
+    /// Encode these claims as a base64url JWT payload segment.
+    ///
+    /// No header or signature is produced; combine the segment with
+    /// placeholders when a mock validator only inspects the payload.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use revelation_user::{Claims, RUserRole};
+    ///
+    /// let payload = Claims::dummy(RUserRole::User).test_token_payload();
+    /// let token = format!("e30.{payload}.sig");
+    ///
+    /// assert_eq!(token.split('.').count(), 3);
+    /// assert!(!payload.contains('='));
+    /// ```
+    #[must_use]
+    pub fn test_token_payload(&self) -> String {
+        let json = serde_json::to_vec(self).expect("claims serialize to JSON");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_payload_decodes_back_into_claims() {
+        let mut claims = Claims::dummy(RUserRole::Premium);
+        claims.permissions = Some(crate::Permissions::EXPORT);
+
+        let payload = claims.test_token_payload();
+        let bytes = URL_SAFE_NO_PAD.decode(&payload).unwrap();
+        let decoded: Claims = serde_json::from_slice(&bytes).unwrap();
+
+        assert!(!payload.contains('='));
+        assert_eq!(decoded.sub, claims.sub);
+        assert_eq!(decoded.role, RUserRole::Premium);
+        assert_eq!(decoded.exp, Claims::DUMMY_EXP);
+        assert_eq!(decoded.permissions, claims.permissions);
+    }
+
+    #[test]
+    fn fixture_is_deterministic() {
+        let user = RUser::fixture();
+
+        assert!(user.id.is_nil());
+        assert_eq!(user.created_at, DateTime::UNIX_EPOCH);
+        assert_eq!(user.updated_at, DateTime::UNIX_EPOCH);
+        assert!(user.telegram_id.is_none());
+        assert!(user.email.is_none());
+    }
+
+    #[test]
+    #[cfg(not(feature = "camel"))]
+    fn fixture_serializes_to_golden_json() {
+        let json = serde_json::to_string(&RUser::fixture()).unwrap();
+
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"id":"00000000-0000-0000-0000-000000000000","name":null,"gender":null,"#,
+                r#""birth_date":null,"confession_id":null,"email":null,"phone":null,"#,
+                r#""telegram_id":null,"email_verified":false,"phone_verified":false,"#,
+                r#""avatar_url":null,"#,
+                r#""notification_prefs":{"telegram":true,"email":true,"push":false},"#,
+                r#""external_ids":{},"signup_source":"unknown","metadata":null,"#,
+                r#""created_at":"1970-01-01T00:00:00.000Z","#,
+                r#""updated_at":"1970-01-01T00:00:00.000Z","#,
+                r#""deleted_at":null,"version":1}"#
+            )
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "camel")]
+    fn fixture_serializes_to_golden_camel_json() {
+        let json = serde_json::to_string(&RUser::fixture()).unwrap();
+
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"id":"00000000-0000-0000-0000-000000000000","name":null,"gender":null,"#,
+                r#""birthDate":null,"confessionId":null,"email":null,"phone":null,"#,
+                r#""telegramId":null,"emailVerified":false,"phoneVerified":false,"#,
+                r#""avatarUrl":null,"#,
+                r#""notificationPrefs":{"telegram":true,"email":true,"push":false},"#,
+                r#""externalIds":{},"signupSource":"unknown","metadata":null,"#,
+                r#""createdAt":"1970-01-01T00:00:00.000Z","#,
+                r#""updatedAt":"1970-01-01T00:00:00.000Z","#,
+                r#""deletedAt":null,"version":1}"#
+            )
+        );
+    }
+
+    #[test]
+    fn setters_fill_fields() {
+        let birth_date = NaiveDate::from_ymd_opt(1990, 1, 15).unwrap();
+        let user = RUser::fixture()
+            .with_name("Alice")
+            .with_email("alice@example.com")
+            .with_phone("+14155551234")
+            .with_telegram_id(123)
+            .with_gender(Gender::Female)
+            .with_birth_date(birth_date);
+
+        assert_eq!(user.name.as_deref(), Some("Alice"));
+        assert_eq!(user.email.as_deref(), Some("alice@example.com"));
+        assert_eq!(user.phone.as_deref(), Some("+14155551234"));
+        assert_eq!(user.telegram_id, Some(123));
+        assert_eq!(user.gender, Some(Gender::Female));
+        assert_eq!(user.birth_date, Some(birth_date));
+    }
+}
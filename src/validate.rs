@@ -0,0 +1,98 @@
+// SPDX-FileCopyrightText: 2025 Revelation Team
+// SPDX-License-Identifier: MIT
+
+//! Validation helpers bridging `validator` and `masterror`.
+//!
+//! Handlers usually want DTO validation failures as an [`AppError`]
+//! so they can be returned with `?`. [`validate_into_app`] runs
+//! validation and performs that mapping in one call.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use masterror::AppResult;
+//! use revelation_user::{UpdateProfileRequest, validate_into_app};
+//!
+//! fn update_profile(req: UpdateProfileRequest) -> AppResult<()> {
+//!     validate_into_app(&req)?;
+//!     // Persist changes...
+//!     Ok(())
+//! }
+//!
+//! assert!(update_profile(UpdateProfileRequest::empty()).is_ok());
+//! ```
+//!
+//! [`AppError`]: masterror::AppError
+
+use masterror::AppResult;
+use validator::Validate;
+
+use crate::UserValidationError;
+
+/// Validate a value and map failures into a 422 [`AppError`].
+///
+/// The error message lists every invalid field, formatted by
+/// [`UserValidationError`].
+///
+/// # Errors
+///
+/// Returns a validation [`AppError`] (HTTP 422) when `value.validate()`
+/// fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use revelation_user::{BindEmail, validate_into_app};
+///
+/// let valid = BindEmail {
+///     email: "user@example.com".into()
+/// };
+/// assert!(validate_into_app(&valid).is_ok());
+///
+/// let invalid = BindEmail {
+///     email: "not-an-email".into()
+/// };
+/// assert!(validate_into_app(&invalid).is_err());
+/// ```
+///
+/// [`AppError`]: masterror::AppError
+pub fn validate_into_app<T: Validate>(value: &T) -> AppResult<()> {
+    value
+        .validate()
+        .map_err(|errors| UserValidationError::from(errors).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use masterror::AppErrorKind;
+
+    use super::*;
+    use crate::{BindTelegram, UpdateProfileRequest};
+
+    #[test]
+    fn passes_valid_dto() {
+        let req = UpdateProfileRequest {
+            name: Some("Valid Name".into()),
+            ..Default::default()
+        };
+
+        assert!(validate_into_app(&req).is_ok());
+    }
+
+    #[test]
+    fn fails_invalid_dto_with_field_name() {
+        let bind = BindTelegram {
+            telegram_id: 0
+        };
+
+        let err = validate_into_app(&bind).unwrap_err();
+
+        assert_eq!(err.kind, AppErrorKind::Validation);
+        assert!(
+            err.message
+                .as_deref()
+                .unwrap_or_default()
+                .contains("telegram_id")
+        );
+    }
+}